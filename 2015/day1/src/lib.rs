@@ -0,0 +1,510 @@
+use aoc_common::rand::Rng;
+use aoc_common::{Configurable, Generate, Kind, Params, ParseError, Solution, StdRng, Streaming};
+
+// where the instructions read so far lead: the floor reached and the position of the
+// first instruction that enters the basement (0 if it isn't entered yet)
+#[derive(Default)]
+pub struct Floors {
+    floor: i64,
+    read: usize,
+    basement: usize,
+}
+
+impl Floors {
+    pub fn follow(&mut self, instructions: &str) {
+        for char in instructions.chars() {
+            self.step(char);
+        }
+    }
+
+    fn step(&mut self, char: char) {
+        self.read += 1;
+        self.move_at(char, self.read);
+    }
+
+    // follows an instruction found at the given position of the input
+    fn move_at(&mut self, char: char, position: usize) {
+        match char {
+            '(' => self.floor += 1,
+            ')' => self.floor -= 1,
+            _ => (),
+        }
+        if self.floor <= -1 && self.basement == 0 {
+            self.basement = position;
+        }
+    }
+}
+
+// returns the final floor and the position of the first instruction that enters the
+// basement (0 if it is never entered)
+pub fn follow_instructions(input: &str) -> (i64, usize) {
+    let mut floors = Floors::default();
+    floors.follow(input);
+    (floors.floor, floors.basement)
+}
+
+// In strict mode, the input must only have parentheses and the characters `also` allows,
+// up to the line ending of the file. Every other character is reported with its position.
+pub fn check_instructions(
+    instructions: &str,
+    also: impl Fn(char) -> bool,
+) -> Result<(), ParseError> {
+    let body = instructions.trim_end_matches(['\n', '\r']);
+    let unknown: Vec<(usize, &str)> = body
+        .char_indices()
+        .enumerate()
+        .filter(|(_, (_, c))| !matches!(c, '(' | ')') && !also(*c))
+        .map(|(n, (i, c))| (n + 1, &body[i..i + c.len_utf8()]))
+        .collect();
+    let Some(&(position, first)) = unknown.first() else {
+        return Ok(());
+    };
+    let message = if unknown.len() == 1 {
+        format!("unknown instruction at position {}", position)
+    } else {
+        let mut positions: Vec<String> = unknown
+            .iter()
+            .take(10)
+            .map(|(p, _)| p.to_string())
+            .collect();
+        if unknown.len() > 10 {
+            positions.push(format!("... ({} in all)", unknown.len()));
+        }
+        format!(
+            "unknown instructions at positions {}, the first is",
+            positions.join(", ")
+        )
+    };
+    Err(ParseError::new(instructions, first, message).at_line(1))
+}
+
+// How several elevators share the instructions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dispatch {
+    // each instruction goes to the next elevator in turn, like the santas of day 3, other
+    // characters taking no turn
+    Interleaved(usize),
+    // a letter names the elevator following the instructions after it, up to the next
+    // letter, e.g. "a((b)a)"
+    Tagged,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Elevator {
+    // its number from 1 when interleaved, its letter when tagged
+    pub name: String,
+    pub floor: i64,
+    // position in the whole input of the instruction entering the basement, 0 if it is
+    // never entered
+    pub basement: usize,
+}
+
+// the elevators in the order of their numbers or of their first tag
+pub fn run_elevators(
+    instructions: &str,
+    dispatch: Dispatch,
+    strict: bool,
+) -> Result<Vec<Elevator>, ParseError> {
+    let is_tag = |c: char| dispatch == Dispatch::Tagged && c.is_ascii_alphabetic();
+    if strict {
+        check_instructions(instructions, is_tag)?;
+    }
+    let mut names: Vec<String> = match dispatch {
+        Dispatch::Interleaved(0) => return Ok(vec![]),
+        Dispatch::Interleaved(count) => (1..=count).map(|n| n.to_string()).collect(),
+        Dispatch::Tagged => vec![],
+    };
+    let mut elevators: Vec<Floors> = names.iter().map(|_| Floors::default()).collect();
+    let mut current = None;
+    let mut turns = 0;
+    for (i, (offset, char)) in instructions.char_indices().enumerate() {
+        let elevator = match dispatch {
+            Dispatch::Interleaved(_) if !matches!(char, '(' | ')') => continue,
+            Dispatch::Interleaved(count) => {
+                turns += 1;
+                (turns - 1) % count
+            }
+            Dispatch::Tagged if is_tag(char) => {
+                let name = char.to_string();
+                let index = names.iter().position(|n| *n == name).unwrap_or_else(|| {
+                    names.push(name);
+                    elevators.push(Floors::default());
+                    names.len() - 1
+                });
+                current = Some(index);
+                continue;
+            }
+            Dispatch::Tagged => match current {
+                Some(index) => index,
+                None if matches!(char, '(' | ')') => {
+                    let token = &instructions[offset..offset + 1];
+                    return Err(ParseError::new(
+                        instructions,
+                        token,
+                        "instruction before any elevator tag",
+                    )
+                    .at_line(1));
+                }
+                None => continue,
+            },
+        };
+        elevators[elevator].move_at(char, i + 1);
+    }
+    Ok(names
+        .into_iter()
+        .zip(elevators)
+        .map(|(name, floors)| Elevator {
+            name,
+            floor: floors.floor,
+            basement: floors.basement,
+        })
+        .collect())
+}
+
+// Every floor the instructions go through, indexed for queries. Position k is the floor
+// after the first k instructions, 0 being the ground floor before any of them, and
+// characters other than parentheses keep the floor.
+pub struct FloorTrace {
+    floors: Vec<i64>,
+    lowest: i64,
+    // positions at each floor from the lowest up, in order
+    visits: Vec<Vec<usize>>,
+}
+
+impl FloorTrace {
+    pub fn new(instructions: &str) -> Self {
+        let mut floors = Vec::with_capacity(instructions.len() + 1);
+        floors.push(0);
+        let mut reached = Floors::default();
+        for char in instructions.chars() {
+            reached.step(char);
+            floors.push(reached.floor);
+        }
+        let lowest = *floors.iter().min().unwrap();
+        let highest = *floors.iter().max().unwrap();
+        let mut visits = vec![vec![]; (highest - lowest) as usize + 1];
+        for (position, floor) in floors.iter().enumerate() {
+            visits[(floor - lowest) as usize].push(position);
+        }
+        FloorTrace {
+            floors,
+            lowest,
+            visits,
+        }
+    }
+
+    // number of instructions followed
+    pub fn instructions(&self) -> usize {
+        self.floors.len() - 1
+    }
+
+    pub fn floor_after(&self, position: usize) -> Option<i64> {
+        self.floors.get(position).copied()
+    }
+
+    pub fn final_floor(&self) -> i64 {
+        self.floors[self.instructions()]
+    }
+
+    pub fn lowest(&self) -> i64 {
+        self.lowest
+    }
+
+    pub fn highest(&self) -> i64 {
+        self.lowest + self.visits.len() as i64 - 1
+    }
+
+    // positions at the floor, in order, none if it is never reached
+    pub fn visits(&self, floor: i64) -> &[usize] {
+        usize::try_from(floor - self.lowest)
+            .ok()
+            .and_then(|i| self.visits.get(i))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    // the first position at the floor, e.g. -1 for the first instruction entering the
+    // basement
+    pub fn first_reaching(&self, floor: i64) -> Option<usize> {
+        self.visits(floor).first().copied()
+    }
+
+    // the number of positions at each floor from the lowest to the highest, adding up to
+    // the number of instructions plus one for the ground floor at the start
+    pub fn histogram(&self) -> impl Iterator<Item = (i64, usize)> + '_ {
+        (self.lowest..).zip(self.visits.iter().map(Vec::len))
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+    type Part1 = i64;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = "()())";
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> i64 {
+        follow_instructions(input).0
+    }
+
+    fn part2(input: &String) -> usize {
+        follow_instructions(input).1
+    }
+}
+
+// in strict mode, inputs with other characters than parentheses are rejected instead of
+// those characters being skipped
+impl Configurable for Puzzle {
+    type Settings = bool;
+
+    const PARAMS: &'static [(&'static str, Kind)] = &[("strict", Kind::Flag)];
+
+    fn settings(params: &Params) -> Result<bool, String> {
+        Ok(params.flag("strict").unwrap_or(false))
+    }
+
+    fn check(instructions: &String, strict: &bool) -> Result<(), ParseError> {
+        if *strict {
+            check_instructions(instructions, |_| false)?;
+        }
+        Ok(())
+    }
+
+    fn part1_with(instructions: &String, _strict: &bool) -> i64 {
+        follow_instructions(instructions).0
+    }
+
+    fn part2_with(instructions: &String, _strict: &bool) -> usize {
+        follow_instructions(instructions).1
+    }
+}
+
+// the instructions are one long line, followed a piece at a time
+impl Streaming for Puzzle {
+    type State = Floors;
+
+    const WHOLE_LINES: bool = false;
+
+    fn feed(floors: &mut Floors, instructions: &str) -> Result<(), ParseError> {
+        floors.follow(instructions);
+        Ok(())
+    }
+
+    fn answer1(floors: &Floors) -> i64 {
+        floors.floor
+    }
+
+    fn answer2(floors: &Floors) -> usize {
+        floors.basement
+    }
+}
+
+// a random walk of `size` steps up and down the floors
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| if rng.gen_bool(0.5) { '(' } else { ')' })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve, solve_configured, solve_stream, Answer, Param};
+    use proptest::prelude::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn test_puzzle() {
+        assert_eq!(Puzzle::part1(&Puzzle::parse("(())").unwrap()), 0);
+        assert_eq!(Puzzle::part1(&Puzzle::parse(")())())").unwrap()), -3);
+        assert_eq!(Puzzle::part2(&Puzzle::parse(")").unwrap()), 1);
+        assert_eq!(Puzzle::part2(&Puzzle::parse("()())").unwrap()), 5);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&input), -1);
+        assert_eq!(Puzzle::part2(&input), 5);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut Puzzle::EXAMPLE.as_bytes(), &[1, 2]),
+            solve::<Puzzle>(Puzzle::EXAMPLE, &[1, 2])
+        );
+        // fed a byte at a time
+        let mut reader = BufReader::with_capacity(1, ")())())".as_bytes());
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut reader, &[1, 2]),
+            Ok(vec![Answer::Number(-3), Answer::Number(1)])
+        );
+    }
+
+    #[test]
+    fn test_floor_trace() {
+        let trace = FloorTrace::new("(()))())");
+        assert_eq!(trace.instructions(), 8);
+        assert_eq!(trace.floor_after(0), Some(0));
+        assert_eq!(trace.floor_after(2), Some(2));
+        assert_eq!(trace.floor_after(9), None);
+        assert_eq!(trace.final_floor(), -2);
+        assert_eq!((trace.lowest(), trace.highest()), (-2, 2));
+        assert_eq!(trace.first_reaching(-1), Some(5));
+        assert_eq!(trace.first_reaching(3), None);
+        assert_eq!(trace.visits(0), &[0, 4, 6]);
+        assert!(trace.visits(-3).is_empty());
+        assert_eq!(
+            trace.histogram().collect::<Vec<_>>(),
+            [(-2, 1), (-1, 2), (0, 3), (1, 2), (2, 1)]
+        );
+        let empty = FloorTrace::new("");
+        assert_eq!(empty.histogram().collect::<Vec<_>>(), [(0, 1)]);
+    }
+
+    #[test]
+    fn test_check_instructions() {
+        assert_eq!(check_instructions("(()\n", |_| false), Ok(()));
+        let error = |input| {
+            check_instructions(input, |_| false)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("(x"),
+            "line 1, column 2: unknown instruction at position 2 \"x\""
+        );
+        assert_eq!(
+            error("(x)y\n"),
+            "line 1, column 2: unknown instructions at positions 2, 4, the first is \"x\""
+        );
+        assert_eq!(
+            error(&"-".repeat(12)),
+            "line 1, column 1: unknown instructions at positions 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, \
+             ... (12 in all), the first is \"-\""
+        );
+    }
+
+    #[test]
+    fn test_strict() {
+        let mut params = Params::default();
+        params.set("strict", Param::Flag(true));
+        assert!(solve_configured::<Puzzle>("(()x", &[1], &params).is_err());
+        let outputs = solve_configured::<Puzzle>("(()\n", &[1], &params).unwrap();
+        assert_eq!(outputs[0].answer, Answer::Number(1));
+        params.set("strict", Param::Flag(false));
+        assert!(solve_configured::<Puzzle>("(()x", &[1], &params).is_ok());
+    }
+
+    fn elevator(name: &str, floor: i64, basement: usize) -> Elevator {
+        Elevator {
+            name: name.to_string(),
+            floor,
+            basement,
+        }
+    }
+
+    #[test]
+    fn test_run_elevators() {
+        assert_eq!(
+            run_elevators("())(", Dispatch::Interleaved(2), false),
+            Ok(vec![elevator("1", 0, 0), elevator("2", 0, 2)])
+        );
+        // other characters don't take a turn, but count in positions
+        assert_eq!(
+            run_elevators("(x)", Dispatch::Interleaved(2), false),
+            Ok(vec![elevator("1", 1, 0), elevator("2", -1, 3)])
+        );
+        assert_eq!(
+            run_elevators(" ( \n)(\n", Dispatch::Interleaved(2), false),
+            Ok(vec![elevator("1", 2, 0), elevator("2", -1, 5)])
+        );
+        assert_eq!(
+            run_elevators("a((b)a)b)", Dispatch::Tagged, true),
+            Ok(vec![elevator("a", 1, 0), elevator("b", -2, 5)])
+        );
+        assert_eq!(
+            run_elevators("()", Dispatch::Interleaved(0), false),
+            Ok(vec![])
+        );
+        assert_eq!(
+            run_elevators(" (a", Dispatch::Tagged, false)
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 2: instruction before any elevator tag \"(\""
+        );
+        assert_eq!(
+            run_elevators("a(1", Dispatch::Tagged, true)
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 3: unknown instruction at position 3 \"1\""
+        );
+        assert!(run_elevators("a(1", Dispatch::Tagged, false).is_ok());
+        assert!(run_elevators("a(", Dispatch::Interleaved(2), true).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_instructions(seed: u64, size in 0..200usize) {
+            let input = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            let ups = input.matches('(').count() as i64;
+            prop_assert_eq!(Puzzle::part1(&input), 2 * ups - size as i64);
+            // the basement is entered on the position given and not before
+            let basement = Puzzle::part2(&input);
+            if basement > 0 {
+                prop_assert_eq!(follow_instructions(&input[..basement]), (-1, basement));
+                prop_assert_eq!(follow_instructions(&input[..basement - 1]).1, 0);
+            } else {
+                prop_assert!((1..=size).all(|n| follow_instructions(&input[..n]).0 >= 0));
+            }
+        }
+
+        #[test]
+        fn prop_stream_same_answers(seed: u64, size in 0..100usize) {
+            let input = generate::<Puzzle>(seed, size);
+            prop_assert_eq!(
+                solve_stream::<Puzzle>(&mut input.as_bytes(), &[1, 2]),
+                solve::<Puzzle>(&input, &[1, 2])
+            );
+        }
+
+        #[test]
+        fn prop_floor_trace(seed: u64, size in 0..200usize) {
+            let input = generate::<Puzzle>(seed, size);
+            let trace = FloorTrace::new(&input);
+            let (floor, basement) = follow_instructions(&input);
+            prop_assert_eq!(trace.final_floor(), floor);
+            prop_assert_eq!(trace.first_reaching(-1).unwrap_or(0), basement);
+            for position in 0..=size {
+                let floor = follow_instructions(&input[..position]).0;
+                prop_assert_eq!(trace.floor_after(position), Some(floor));
+                prop_assert!(trace.visits(floor).contains(&position));
+                prop_assert!((trace.lowest()..=trace.highest()).contains(&floor));
+            }
+            prop_assert_eq!(trace.histogram().map(|(_, n)| n).sum::<usize>(), size + 1);
+        }
+
+        #[test]
+        fn prop_interleaved_elevators(seed: u64, size in 0..200usize, count in 1..5usize) {
+            let input = generate::<Puzzle>(seed, size);
+            let elevators = run_elevators(&input, Dispatch::Interleaved(count), true).unwrap();
+            let (floor, basement) = follow_instructions(&input);
+            prop_assert_eq!(elevators.iter().map(|e| e.floor).sum::<i64>(), floor);
+            if count == 1 {
+                prop_assert_eq!(elevators[0].basement, basement);
+            }
+            for (n, elevator) in (0..).zip(&elevators) {
+                let own: String = input.chars().skip(n).step_by(count).collect();
+                prop_assert_eq!(elevator.floor, follow_instructions(&own).0);
+            }
+        }
+    }
+}
@@ -0,0 +1,972 @@
+use aoc_common::rand::Rng;
+use aoc_common::{
+    parse_lines, parse_token, Configurable, Generate, Kind, Params, ParseError, Solution, StdRng,
+    Streaming,
+};
+use itertools::sorted;
+use itertools::Itertools;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+use std::fmt;
+use std::str::FromStr;
+
+// the amounts of paper and ribbon must fit a u64, whichever slack policy is chosen
+const TOO_LARGE: &str = "present too large for its paper and ribbon to be counted";
+const TOO_MUCH: &str = "presents too large for the total paper and ribbon to be counted";
+
+// 2^64, the first amount that doesn't fit a u64
+const U64_LIMIT: f64 = 18446744073709551616.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct Present {
+    pub length: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+// Presents are expected to be small enough for their paper with the most slack, twice
+// their surface, and their ribbon to fit a u64, as checked when they are parsed
+impl Present {
+    pub fn new(length: u32, width: u32, height: u32) -> Self {
+        Present {
+            length,
+            width,
+            height,
+        }
+    }
+
+    // the same box turned so that its sides are from the shortest to the longest
+    pub fn normalized(&self) -> Self {
+        let (length, width, height) = sorted([self.length, self.width, self.height])
+            .collect_tuple()
+            .unwrap();
+        Present::new(length, width, height)
+    }
+
+    fn dimensions(&self) -> (u64, u64, u64) {
+        (
+            u64::from(self.length),
+            u64::from(self.width),
+            u64::from(self.height),
+        )
+    }
+
+    fn sides(&self) -> [u64; 3] {
+        let (length, width, height) = self.dimensions();
+        [length * width, width * height, height * length]
+    }
+
+    pub fn surface(&self) -> u64 {
+        2 * self.sides().iter().sum::<u64>()
+    }
+
+    // extra paper, the area of the smallest side
+    pub fn slack(&self) -> u64 {
+        *self.sides().iter().min().unwrap()
+    }
+
+    pub fn paper(&self) -> u64 {
+        self.surface() + self.slack()
+    }
+
+    // ribbon around the smallest perimeter
+    pub fn wrap(&self) -> u64 {
+        let (shortest, middle, _) = self.normalized().dimensions();
+        2 * (shortest + middle)
+    }
+
+    // ribbon for the bow, as many feet as cubic feet of volume
+    pub fn bow(&self) -> u64 {
+        let (length, width, height) = self.dimensions();
+        length * width * height
+    }
+
+    pub fn ribbon(&self) -> u64 {
+        self.wrap() + self.bow()
+    }
+
+    // the paper with the most slack and the ribbon, unless they don't fit a u64
+    fn most_material(&self) -> Option<(u64, u64)> {
+        let (length, width, height) = self.dimensions();
+        let sides = [
+            length.checked_mul(width)?,
+            width.checked_mul(height)?,
+            height.checked_mul(length)?,
+        ];
+        let surface = sides
+            .iter()
+            .try_fold(0u64, |sum, &side| sum.checked_add(side))?
+            .checked_mul(2)?;
+        let bow = length.checked_mul(width)?.checked_mul(height)?;
+        Some((surface.checked_mul(2)?, bow.checked_add(self.wrap())?))
+    }
+}
+
+// the dimensions separated by "x", in the order of their `names`, `format` being how
+// they are written
+fn parse_dimensions<const N: usize>(
+    text: &str,
+    names: [&str; N],
+    format: &str,
+) -> Result<[u32; N], ParseError> {
+    let tokens: Vec<&str> = text.split('x').collect();
+    if tokens.len() != N {
+        let message = format!("expected dimensions as {}, got", format);
+        return Err(ParseError::new(text, text, message));
+    }
+    let mut dimensions = [0; N];
+    for ((dimension, token), name) in dimensions.iter_mut().zip(tokens).zip(names) {
+        *dimension = parse_token(text, token, &format!("invalid {}", name))?;
+    }
+    Ok(dimensions)
+}
+
+// parses a "LxWxH" line
+impl FromStr for Present {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        let [l, w, h] = parse_dimensions(line, ["length", "width", "height"], "LxWxH")?;
+        let present = Present::new(l, w, h);
+        present
+            .most_material()
+            .ok_or_else(|| ParseError::new(line, line, TOO_LARGE))?;
+        Ok(present)
+    }
+}
+
+impl fmt::Display for Present {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}x{}", self.length, self.width, self.height)
+    }
+}
+
+pub fn paper_for_present(l: u32, w: u32, h: u32) -> u64 {
+    Present::new(l, w, h).paper()
+}
+
+pub fn ribbon_for_present(l: u32, w: u32, h: u32) -> u64 {
+    Present::new(l, w, h).ribbon()
+}
+
+// parse a "LxWxH" line into the dimensions of a present
+pub fn parse_present(line: &str) -> Result<(u32, u32, u32), ParseError> {
+    let present: Present = line.parse()?;
+    Ok((present.length, present.width, present.height))
+}
+
+// How much extra paper a present needs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Slack {
+    // the area of the smallest flat face
+    #[default]
+    SmallestSide,
+    // a percentage of the surface, up to 100
+    Percent(u32),
+    None,
+}
+
+// "smallest", "none" or a percentage like "5%", up to "100%"
+impl FromStr for Slack {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, String> {
+        match text {
+            "smallest" => Ok(Slack::SmallestSide),
+            "none" => Ok(Slack::None),
+            _ => text
+                .strip_suffix('%')
+                .and_then(|n| n.parse().ok())
+                .filter(|&percent| percent <= 100)
+                .map(Slack::Percent)
+                .ok_or_else(|| {
+                    format!(
+                        "slack must be smallest, none or a percentage up to 100% like 5%, \
+                         got \"{}\"",
+                        text
+                    )
+                }),
+        }
+    }
+}
+
+// A present of any shape, with its sides in feet. In reports, its shape is tagged along
+// its dimensions, e.g. {"shape":"cube","side":3}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(tag = "shape", rename_all = "lowercase")]
+pub enum Shape {
+    Box(Present),
+    Cube { side: u32 },
+    Cylinder { radius: u32, height: u32 },
+    // with equilateral triangles at its ends
+    Prism { side: u32, length: u32 },
+}
+
+impl Shape {
+    fn triangle(side: f64) -> f64 {
+        3f64.sqrt() / 4.0 * side * side
+    }
+
+    // the same present turned so that its sides are from the shortest to the longest
+    pub fn normalized(&self) -> Self {
+        match *self {
+            Shape::Box(present) => Shape::Box(present.normalized()),
+            shape => shape,
+        }
+    }
+
+    // dimensions are converted before multiplying them, as their products may not fit
+    // their own type
+    pub fn surface(&self) -> f64 {
+        match *self {
+            Shape::Box(present) => present.surface() as f64,
+            Shape::Cube { side } => 6.0 * f64::from(side) * f64::from(side),
+            Shape::Cylinder { radius, height } => {
+                2.0 * PI * f64::from(radius) * (f64::from(radius) + f64::from(height))
+            }
+            Shape::Prism { side, length } => {
+                let side = f64::from(side);
+                2.0 * Shape::triangle(side) + 3.0 * side * f64::from(length)
+            }
+        }
+    }
+
+    // the smallest flat face, the ends for a cylinder
+    pub fn smallest_side(&self) -> f64 {
+        match *self {
+            Shape::Box(present) => present.slack() as f64,
+            Shape::Cube { side } => f64::from(side) * f64::from(side),
+            Shape::Cylinder { radius, .. } => PI * f64::from(radius) * f64::from(radius),
+            Shape::Prism { side, length } => {
+                let side = f64::from(side);
+                Shape::triangle(side).min(side * f64::from(length))
+            }
+        }
+    }
+
+    // the shortest way around the present
+    pub fn wrap(&self) -> f64 {
+        match *self {
+            Shape::Box(present) => present.wrap() as f64,
+            Shape::Cube { side } => 4.0 * f64::from(side),
+            // around its side or over its ends
+            Shape::Cylinder { radius, height } => {
+                let radius = f64::from(radius);
+                (2.0 * PI * radius).min(2.0 * (2.0 * radius + f64::from(height)))
+            }
+            // around the triangle or over the ends along their heights
+            Shape::Prism { side, length } => {
+                let side = f64::from(side);
+                (3.0 * side).min(2.0 * f64::from(length) + 3f64.sqrt() * side)
+            }
+        }
+    }
+
+    pub fn volume(&self) -> f64 {
+        match *self {
+            Shape::Box(present) => present.bow() as f64,
+            Shape::Cube { side } => f64::from(side).powi(3),
+            Shape::Cylinder { radius, height } => {
+                PI * f64::from(radius) * f64::from(radius) * f64::from(height)
+            }
+            Shape::Prism { side, length } => Shape::triangle(f64::from(side)) * f64::from(length),
+        }
+    }
+
+    // in whole square feet, rounded up. Boxes are counted exactly, other shapes as
+    // closely as an f64 allows
+    pub fn paper(&self, slack: Slack) -> u64 {
+        if let Shape::Box(present) = self {
+            let surface = present.surface();
+            return match slack {
+                Slack::SmallestSide => present.paper(),
+                // the slack is at most the surface, so the paper fits as checked
+                Slack::Percent(percent) => {
+                    let extra = (u128::from(surface) * u128::from(percent)).div_ceil(100);
+                    surface + extra as u64
+                }
+                Slack::None => surface,
+            };
+        }
+        let surface = self.surface();
+        let extra = match slack {
+            Slack::SmallestSide => self.smallest_side(),
+            Slack::Percent(percent) => surface * f64::from(percent) / 100.0,
+            Slack::None => 0.0,
+        };
+        (surface + extra).ceil() as u64
+    }
+
+    // the paper added by the slack policy, beyond what covers the surface
+    pub fn slack(&self, slack: Slack) -> u64 {
+        self.paper(slack) - self.paper(Slack::None)
+    }
+
+    // in whole feet, rounded up, with as many feet for the bow as cubic feet of volume
+    pub fn ribbon(&self) -> u64 {
+        match self {
+            Shape::Box(present) => present.ribbon(),
+            _ => (self.wrap() + self.volume()).ceil() as u64,
+        }
+    }
+
+    // the paper with the most slack, twice the surface, and the ribbon, unless they don't
+    // fit a u64
+    fn most_material(&self) -> Option<(u64, u64)> {
+        if let Shape::Box(present) = self {
+            return present.most_material();
+        }
+        let paper = (2.0 * self.surface()).ceil();
+        let ribbon = (self.wrap() + self.volume()).ceil();
+        (paper < U64_LIMIT && ribbon < U64_LIMIT).then_some((paper as u64, ribbon as u64))
+    }
+
+    // the ribbon added for the bow, beyond what goes around the present
+    pub fn bow(&self) -> u64 {
+        self.ribbon() - self.wrap().ceil() as u64
+    }
+}
+
+// parses a "LxWxH" line, or one tagged with its shape: "box LxWxH", "cube S",
+// "cylinder RxH" or "prism SxL"
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        let Some((tag, dimensions)) = line.split_once(' ') else {
+            return Ok(Shape::Box(line.parse()?));
+        };
+        let shifted = |e: ParseError| e.shifted(line, dimensions);
+        let shape = match tag {
+            "box" => return Ok(Shape::Box(dimensions.parse().map_err(shifted)?)),
+            "cube" => Shape::Cube {
+                side: parse_token(line, dimensions, "invalid side")?,
+            },
+            "cylinder" => {
+                let [radius, height] =
+                    parse_dimensions(dimensions, ["radius", "height"], "RxH").map_err(shifted)?;
+                Shape::Cylinder { radius, height }
+            }
+            "prism" => {
+                let [side, length] =
+                    parse_dimensions(dimensions, ["side", "length"], "SxL").map_err(shifted)?;
+                Shape::Prism { side, length }
+            }
+            _ => {
+                return Err(ParseError::new(
+                    line,
+                    tag,
+                    "expected a shape among box, cube, cylinder and prism, got",
+                ))
+            }
+        };
+        shape
+            .most_material()
+            .ok_or_else(|| ParseError::new(line, line, TOO_LARGE))?;
+        Ok(shape)
+    }
+}
+
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Shape::Box(present) => write!(f, "{}", present),
+            Shape::Cube { side } => write!(f, "cube {}", side),
+            Shape::Cylinder { radius, height } => write!(f, "cylinder {}x{}", radius, height),
+            Shape::Prism { side, length } => write!(f, "prism {}x{}", side, length),
+        }
+    }
+}
+
+// What a present of the list takes with the slack policy of the report, a row of the
+// report
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Line {
+    // 1-based line of the present in the input
+    pub line: usize,
+    #[serde(flatten)]
+    pub shape: Shape,
+    pub paper: u64,
+    pub slack: u64,
+    pub ribbon: u64,
+    pub bow: u64,
+}
+
+impl Line {
+    fn new(line: usize, shape: Shape, slack: Slack) -> Self {
+        Line {
+            line,
+            shape,
+            paper: shape.paper(slack),
+            slack: shape.slack(slack),
+            ribbon: shape.ribbon(),
+            bow: shape.bow(),
+        }
+    }
+}
+
+pub fn report(shapes: &[Shape], slack: Slack) -> Vec<Line> {
+    (1..)
+        .zip(shapes)
+        .map(|(n, &shape)| Line::new(n, shape, slack))
+        .collect()
+}
+
+// the presents as they are written in the input, e.g. "2x3x4" or "cube 3"
+pub fn report_csv(shapes: &[Shape], slack: Slack) -> String {
+    let mut csv = String::from("line,present,paper,slack,ribbon,bow\n");
+    for line in report(shapes, slack) {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            line.line, line.shape, line.paper, line.slack, line.ribbon, line.bow
+        ));
+    }
+    csv
+}
+
+// an array of the rows of the report, as objects
+pub fn report_json(shapes: &[Shape], slack: Slack) -> String {
+    // plain data always serializes
+    serde_json::to_string(&report(shapes, slack)).unwrap()
+}
+
+// The presents of the same shape and dimensions, whichever way they are turned
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Group {
+    pub present: Shape,
+    pub count: usize,
+    pub paper: u64,
+    pub ribbon: u64,
+}
+
+// Where the material of a bulk order goes
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Summary {
+    pub presents: usize,
+    pub paper: u64,
+    pub slack: u64,
+    pub ribbon: u64,
+    pub bow: u64,
+    // the largest groups first, then the smallest presents
+    pub groups: Vec<Group>,
+    // the presents taking the most paper and ribbon together, the first in the input
+    // among equals
+    pub top: Vec<Line>,
+    // number of presents by slack
+    pub slack_distribution: BTreeMap<u64, usize>,
+}
+
+impl Summary {
+    // with the given slack policy and the `top` presents taking the most material
+    pub fn new(shapes: &[Shape], slack: Slack, top: usize) -> Self {
+        let lines = report(shapes, slack);
+        let mut groups: HashMap<Shape, Group> = HashMap::new();
+        let mut slack_distribution = BTreeMap::new();
+        for line in &lines {
+            let present = line.shape.normalized();
+            let group = groups.entry(present).or_insert(Group {
+                present,
+                count: 0,
+                paper: 0,
+                ribbon: 0,
+            });
+            group.count += 1;
+            group.paper += line.paper;
+            group.ribbon += line.ribbon;
+            *slack_distribution.entry(line.slack).or_insert(0) += 1;
+        }
+        let groups = groups
+            .into_values()
+            .sorted_by_key(|g| (Reverse(g.count), g.present))
+            .collect();
+        Summary {
+            presents: lines.len(),
+            paper: lines.iter().map(|l| l.paper).sum(),
+            slack: lines.iter().map(|l| l.slack).sum(),
+            ribbon: lines.iter().map(|l| l.ribbon).sum(),
+            bow: lines.iter().map(|l| l.bow).sum(),
+            groups,
+            top: lines
+                .iter()
+                .sorted_by_key(|l| (Reverse(l.paper + l.ribbon), l.line))
+                .take(top)
+                .cloned()
+                .collect(),
+            slack_distribution,
+        }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} presents: {} square feet of paper ({} of slack), {} feet of ribbon ({} for bows)",
+            self.presents, self.paper, self.slack, self.ribbon, self.bow
+        )?;
+        writeln!(
+            f,
+            "{} different presents, the most common:",
+            self.groups.len()
+        )?;
+        for group in self.groups.iter().take(5) {
+            writeln!(
+                f,
+                "  {} x {}: {} of paper, {} of ribbon",
+                group.count, group.present, group.paper, group.ribbon
+            )?;
+        }
+        writeln!(f, "Taking the most material:")?;
+        for line in &self.top {
+            writeln!(
+                f,
+                "  line {}, {}: {} of paper, {} of ribbon",
+                line.line, line.shape, line.paper, line.ribbon
+            )?;
+        }
+        writeln!(f, "Presents by slack:")?;
+        for (slack, count) in &self.slack_distribution {
+            writeln!(f, "  {}: {}", slack, count)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Shape>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const EXAMPLE: &'static str = "2x3x4
+1x1x10";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let shapes: Vec<Shape> = parse_lines(input, str::parse)?;
+        // each shape fits as checked, so the totals are the only ones left to check
+        shapes
+            .iter()
+            .map(|shape| shape.most_material().unwrap())
+            .try_fold((0u64, 0u64), |(paper, ribbon), (p, r)| {
+                Some((paper.checked_add(p)?, ribbon.checked_add(r)?))
+            })
+            .ok_or_else(|| ParseError::input(TOO_MUCH))?;
+        Ok(shapes)
+    }
+
+    fn part1(shapes: &Self::Input) -> u64 {
+        Puzzle::part1_with(shapes, &Slack::default())
+    }
+
+    fn part2(shapes: &Self::Input) -> u64 {
+        Puzzle::part2_with(shapes, &Slack::default())
+    }
+}
+
+impl Configurable for Puzzle {
+    type Settings = Slack;
+
+    const PARAMS: &'static [(&'static str, Kind)] = &[("slack", Kind::Text)];
+
+    fn settings(params: &Params) -> Result<Slack, String> {
+        params
+            .text("slack")
+            .map_or(Ok(Slack::default()), str::parse)
+    }
+
+    fn part1_with(shapes: &Vec<Shape>, slack: &Slack) -> u64 {
+        shapes.iter().map(|shape| shape.paper(*slack)).sum()
+    }
+
+    fn part2_with(shapes: &Vec<Shape>, _slack: &Slack) -> u64 {
+        shapes.iter().map(Shape::ribbon).sum()
+    }
+}
+
+// The paper and ribbon needed for the presents read so far, with the puzzle's slack
+// policy unless started with another one
+#[derive(Default)]
+pub struct Totals {
+    slack: Slack,
+    paper: u64,
+    ribbon: u64,
+}
+
+impl Totals {
+    pub fn new(slack: Slack) -> Self {
+        Totals {
+            slack,
+            ..Totals::default()
+        }
+    }
+}
+
+impl Streaming for Puzzle {
+    type State = Totals;
+
+    fn feed(totals: &mut Totals, line: &str) -> Result<(), ParseError> {
+        let shape: Shape = line.parse()?;
+        totals.paper = totals
+            .paper
+            .checked_add(shape.paper(totals.slack))
+            .ok_or_else(|| ParseError::input(TOO_MUCH))?;
+        totals.ribbon = totals
+            .ribbon
+            .checked_add(shape.ribbon())
+            .ok_or_else(|| ParseError::input(TOO_MUCH))?;
+        Ok(())
+    }
+
+    fn answer1(totals: &Totals) -> u64 {
+        totals.paper
+    }
+
+    fn answer2(totals: &Totals) -> u64 {
+        totals.ribbon
+    }
+}
+
+// `size` presents of up to 30 feet on each side
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut side = || rng.gen_range(1..=30);
+                format!("{}x{}x{}\n", side(), side(), side())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{
+        check_params, generate, solve, solve_configured, solve_stream, solve_stream_from, Answer,
+        Param,
+    };
+    use proptest::prelude::*;
+
+    fn boxes(input: &str) -> Vec<Present> {
+        parse_lines(input, str::parse).unwrap()
+    }
+
+    #[test]
+    fn test_paper_for_present() {
+        assert_eq!(paper_for_present(2, 3, 4), 58);
+        assert_eq!(paper_for_present(1, 1, 10), 43);
+    }
+
+    #[test]
+    fn test_ribbon_for_present() {
+        assert_eq!(ribbon_for_present(2, 3, 4), 34);
+        assert_eq!(ribbon_for_present(2, 4, 3), 34);
+        assert_eq!(ribbon_for_present(3, 2, 4), 34);
+        assert_eq!(ribbon_for_present(3, 4, 2), 34);
+        assert_eq!(ribbon_for_present(4, 2, 3), 34);
+        assert_eq!(ribbon_for_present(4, 3, 2), 34);
+        assert_eq!(ribbon_for_present(1, 1, 10), 14);
+        assert_eq!(ribbon_for_present(1, 10, 1), 14);
+        assert_eq!(ribbon_for_present(10, 1, 1), 14);
+    }
+
+    #[test]
+    fn test_parse_present() {
+        assert_eq!(parse_present("2x3x4"), Ok((2, 3, 4)));
+        assert_eq!(
+            parse_present("2x3").unwrap_err().to_string(),
+            "column 1: expected dimensions as LxWxH, got \"2x3\""
+        );
+        assert_eq!(
+            Puzzle::parse("2x3x4\n1x1x10\n1xax10")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 3: invalid width \"a\""
+        );
+    }
+
+    #[test]
+    fn test_present() {
+        let present: Present = "4x2x3".parse().unwrap();
+        assert_eq!(present, Present::new(4, 2, 3));
+        assert_eq!(present.normalized(), Present::new(2, 3, 4));
+        assert_eq!(present.to_string(), "4x2x3");
+        assert_eq!(present.surface(), 52);
+        assert_eq!(present.slack(), 6);
+        assert_eq!(present.wrap(), 10);
+        assert_eq!(present.bow(), 24);
+    }
+
+    #[test]
+    fn test_report() {
+        let shapes = Puzzle::parse("2x3x4\n1x1x10\ncylinder 1x2").unwrap();
+        assert_eq!(
+            report_csv(&shapes, Slack::SmallestSide),
+            "line,present,paper,slack,ribbon,bow\n\
+             1,2x3x4,58,6,34,24\n\
+             2,1x1x10,43,1,14,10\n\
+             3,cylinder 1x2,22,3,13,6\n"
+        );
+        assert_eq!(
+            report_csv(&shapes[..1], Slack::None),
+            "line,present,paper,slack,ribbon,bow\n1,2x3x4,52,0,34,24\n"
+        );
+        assert_eq!(
+            report_json(&shapes[1..], Slack::Percent(10)),
+            r#"[{"line":1,"shape":"box","length":1,"width":1,"height":10,"paper":47,"slack":5,"ribbon":14,"bow":10},"#
+                .to_string()
+                + r#"{"line":2,"shape":"cylinder","radius":1,"height":2,"paper":21,"slack":2,"ribbon":13,"bow":6}]"#
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let input = "2x3x4\n1x1x10\n4x3x2\n10x1x1\n3x2x4\n5x5x5";
+        let shapes = Puzzle::parse(input).unwrap();
+        let summary = Summary::new(&shapes, Slack::SmallestSide, 2);
+        assert_eq!(summary.presents, 6);
+        assert_eq!(summary.paper, Puzzle::part1(&shapes));
+        assert_eq!(summary.ribbon, Puzzle::part2(&shapes));
+        let groups: Vec<(Shape, usize)> = summary
+            .groups
+            .iter()
+            .map(|g| (g.present, g.count))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (Shape::Box(Present::new(2, 3, 4)), 3),
+                (Shape::Box(Present::new(1, 1, 10)), 2),
+                (Shape::Box(Present::new(5, 5, 5)), 1)
+            ]
+        );
+        assert_eq!(summary.groups[0].paper, 3 * 58);
+        let top: Vec<usize> = summary.top.iter().map(|l| l.line).collect();
+        assert_eq!(top, vec![6, 1]);
+        assert_eq!(
+            summary.slack_distribution,
+            BTreeMap::from([(1, 2), (6, 3), (25, 1)])
+        );
+        assert!(summary
+            .to_string()
+            .starts_with("6 presents: 435 square feet of paper (45 of slack)"));
+        // the slack of the policy, and presents of other shapes grouped apart
+        let shapes = Puzzle::parse("2x3x4\ncube 2\n4x3x2\ncube 2").unwrap();
+        let summary = Summary::new(&shapes, Slack::None, 1);
+        assert_eq!(summary.paper, Puzzle::part1_with(&shapes, &Slack::None));
+        assert_eq!(summary.slack, 0);
+        assert_eq!(summary.groups.len(), 2);
+        assert_eq!(summary.groups[1].present, Shape::Cube { side: 2 });
+        assert_eq!(summary.top[0].line, 1);
+    }
+
+    #[test]
+    fn test_parse_shape() {
+        let shapes = Puzzle::parse("2x3x4\nbox 2x3x4\ncube 3\ncylinder 1x2\nprism 2x5").unwrap();
+        assert_eq!(shapes[0], Shape::Box(Present::new(2, 3, 4)));
+        assert_eq!(shapes[1], shapes[0]);
+        assert_eq!(shapes[2], Shape::Cube { side: 3 });
+        assert_eq!(
+            shapes[3],
+            Shape::Cylinder {
+                radius: 1,
+                height: 2
+            }
+        );
+        assert_eq!(shapes[4], Shape::Prism { side: 2, length: 5 });
+        let formatted: Vec<String> = shapes.iter().map(Shape::to_string).collect();
+        assert_eq!(
+            formatted,
+            ["2x3x4", "2x3x4", "cube 3", "cylinder 1x2", "prism 2x5"]
+        );
+        assert_eq!(
+            Puzzle::parse("2x3x4\ncylinder 1xb")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 12: invalid height \"b\""
+        );
+        assert_eq!(
+            Puzzle::parse("prism 2").unwrap_err().to_string(),
+            "line 1, column 7: expected dimensions as SxL, got \"2\""
+        );
+        assert_eq!(
+            Puzzle::parse("sphere 2").unwrap_err().to_string(),
+            "line 1, column 1: expected a shape among box, cube, cylinder and prism, got \"sphere\""
+        );
+    }
+
+    #[test]
+    fn test_shapes() {
+        let cube = Shape::Cube { side: 2 };
+        assert_eq!(cube.paper(Slack::SmallestSide), 24 + 4);
+        assert_eq!(cube.ribbon(), ribbon_for_present(2, 2, 2));
+        // 2π(1 + 2) = 18.85 and π for an end
+        let cylinder = Shape::Cylinder {
+            radius: 1,
+            height: 2,
+        };
+        assert_eq!(cylinder.paper(Slack::None), 19);
+        assert_eq!(cylinder.paper(Slack::SmallestSide), 22);
+        // around its side, 6.28, and 2π of volume
+        assert_eq!(cylinder.ribbon(), 13);
+        // flat enough to be tied over its ends
+        let disc = Shape::Cylinder {
+            radius: 10,
+            height: 1,
+        };
+        assert!((disc.wrap() - 42.0).abs() < 1e-9);
+        // ends of 1.73, sides of 10
+        let prism = Shape::Prism { side: 2, length: 5 };
+        assert_eq!(prism.paper(Slack::None), 34);
+        assert_eq!(prism.paper(Slack::SmallestSide), 36);
+        assert_eq!(prism.ribbon(), 6 + 9);
+        // dimensions whose products don't fit a u32
+        let cylinder = Shape::Cylinder {
+            radius: 2000,
+            height: 2000,
+        };
+        assert_eq!(cylinder.volume().round(), (PI * 8e9).round());
+        assert_eq!(Shape::Cube { side: 2000 }.volume(), 8e9);
+        assert_eq!(Shape::Cube { side: 40000 }.surface(), 9.6e9);
+        assert_eq!(Present::new(2000, 2000, 2000).bow(), 8_000_000_000);
+    }
+
+    #[test]
+    fn test_large_presents() {
+        // boxes are counted exactly, beyond what an f64 holds
+        let shapes = Puzzle::parse("2000000x2000000x2000000").unwrap();
+        assert_eq!(Puzzle::part2(&shapes), 8_000_000_000_008_000_000);
+        assert_eq!(shapes[0].paper(Slack::Percent(100)), 48_000_000_000_000);
+        assert_eq!(
+            Puzzle::parse("1x1x1\n3000000x3000000x3000000")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: present too large for its paper and ribbon to be counted \
+             \"3000000x3000000x3000000\""
+        );
+        assert!(Puzzle::parse("box 3000000x3000000x3000000").is_err());
+        assert!(Puzzle::parse("cube 4000000000").is_err());
+        assert!(Puzzle::parse("cylinder 1000000x1000000").is_ok());
+        let total = "2000000x2000000x2000000\n".repeat(3);
+        assert_eq!(
+            Puzzle::parse(&total).unwrap_err().to_string(),
+            "presents too large for the total paper and ribbon to be counted"
+        );
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut total.as_bytes(), &[2])
+                .unwrap_err()
+                .to_string(),
+            "line 3, presents too large for the total paper and ribbon to be counted"
+        );
+    }
+
+    #[test]
+    fn test_slack() {
+        assert_eq!("smallest".parse(), Ok(Slack::SmallestSide));
+        assert_eq!("none".parse(), Ok(Slack::None));
+        assert_eq!("15%".parse(), Ok(Slack::Percent(15)));
+        assert_eq!(
+            "15".parse::<Slack>(),
+            Err(
+                "slack must be smallest, none or a percentage up to 100% like 5%, got \"15\""
+                    .to_string()
+            )
+        );
+        assert!("101%".parse::<Slack>().is_err());
+        let present = Shape::Box(Present::new(2, 3, 4));
+        assert_eq!(
+            present.paper(Slack::SmallestSide),
+            paper_for_present(2, 3, 4)
+        );
+        assert_eq!(present.paper(Slack::None), 52);
+        // 52 + 5.2
+        assert_eq!(present.paper(Slack::Percent(10)), 58);
+    }
+
+    #[test]
+    fn test_configured() {
+        let answers = |input: &str, slack: &str| {
+            let mut params = Params::default();
+            params.set("slack", Param::Text(slack.to_string()));
+            solve_configured::<Puzzle>(input, &[1, 2], &params)
+                .map(|outputs| outputs.into_iter().map(|o| o.answer).collect::<Vec<_>>())
+        };
+        assert_eq!(
+            answers(Puzzle::EXAMPLE, "none"),
+            Ok(vec![Answer::Number(52 + 42), Answer::Number(34 + 14)])
+        );
+        assert_eq!(
+            answers("cube 1", "100%"),
+            Ok(vec![Answer::Number(12), Answer::Number(5)])
+        );
+        let mut params = Params::default();
+        params.set("slack", Param::Text("all".to_string()));
+        assert_eq!(
+            check_params::<Puzzle>(&params),
+            Err(
+                "slack must be smallest, none or a percentage up to 100% like 5%, got \"all\""
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let presents = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&presents), 58 + 43);
+        assert_eq!(Puzzle::part2(&presents), 34 + 14);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut Puzzle::EXAMPLE.as_bytes(), &[1, 2]),
+            solve::<Puzzle>(Puzzle::EXAMPLE, &[1, 2])
+        );
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut "2x3x4\n1x1\n".as_bytes(), &[1])
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected dimensions as LxWxH, got \"1x1\""
+        );
+        // the slack policy of the totals started with
+        assert_eq!(
+            solve_stream_from::<Puzzle>(
+                &mut Puzzle::EXAMPLE.as_bytes(),
+                Totals::new(Slack::None),
+                &[1]
+            ),
+            Ok(vec![Answer::Number(52 + 42)])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_presents(seed: u64, size in 1..100usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let shapes = Puzzle::parse(&text).unwrap();
+            let presents = boxes(&text);
+            prop_assert_eq!(presents.len(), size);
+            let formatted: String = presents.iter().map(|p| format!("{}\n", p)).collect();
+            prop_assert_eq!(formatted, text);
+            // there is always more paper than the surface of the presents, and more
+            // ribbon than their volume
+            let surface: u64 = presents.iter().map(Present::surface).sum();
+            let volume: u64 = presents.iter().map(Present::bow).sum();
+            prop_assert!(Puzzle::part1(&shapes) > surface);
+            prop_assert!(Puzzle::part2(&shapes) > volume);
+        }
+
+        #[test]
+        fn prop_stream_same_answers(seed: u64, size in 0..100usize) {
+            let input = generate::<Puzzle>(seed, size);
+            prop_assert_eq!(
+                solve_stream::<Puzzle>(&mut input.as_bytes(), &[1, 2]),
+                solve::<Puzzle>(&input, &[1, 2])
+            );
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use aoc_common::rand::seq::SliceRandom;
+use aoc_common::{Direction, Generate, ParseError, Point, Solution, StdRng};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+// Which santa makes each move of the plan, santas being numbered from 0
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Schedule {
+    // the santas take turns, one move each
+    RoundRobin(usize),
+    // the moves are cut in as many blocks in a row as santas, the first blocks one move
+    // longer when they don't divide evenly
+    Blocks(usize),
+    // a digit names the santa making the moves after it, up to the next digit, e.g.
+    // "0^^1v0>"
+    Tagged,
+    // the santas take turns making as many moves in a row as their weight, e.g. [2, 1]
+    // for a santa making two moves for each of the other, see `Schedule::weighted`
+    Weighted(Vec<usize>),
+}
+
+impl Schedule {
+    // a weighted schedule where some santa makes moves: with every weight at 0 the moves
+    // would be left undone
+    pub fn weighted(weights: Vec<usize>) -> Result<Self, String> {
+        if weights.iter().all(|&weight| weight == 0) {
+            return Err("weighted schedule without any santa of weight above 0".to_string());
+        }
+        Ok(Schedule::Weighted(weights))
+    }
+}
+
+// whose turn it is on a weighted schedule and how many moves are left in it
+struct Turn {
+    santa: usize,
+    left: usize,
+}
+
+impl Turn {
+    fn first(weights: &[usize]) -> Self {
+        Turn {
+            santa: 0,
+            left: weights.first().copied().unwrap_or(0),
+        }
+    }
+
+    // the santa making the next move, None when no santa has a weight above 0
+    fn next(&mut self, weights: &[usize]) -> Option<usize> {
+        if self.left == 0 {
+            let next = (1..=weights.len())
+                .map(|n| (self.santa + n) % weights.len())
+                .find(|&santa| weights[santa] > 0)?;
+            *self = Turn {
+                santa: next,
+                left: weights[next],
+            };
+        }
+        self.left -= 1;
+        Some(self.santa)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct House {
+    pub presents: u32,
+    pub santas: BTreeSet<usize>,
+}
+
+// The houses that got presents, every santa delivering one at the start
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Deliveries {
+    pub houses: HashMap<Point, House>,
+    santas: BTreeMap<usize, Point>,
+}
+
+impl Deliveries {
+    fn deliver(&mut self, santa: usize, house: Point) {
+        let house = self.houses.entry(house).or_default();
+        house.presents += 1;
+        house.santas.insert(santa);
+    }
+
+    fn arrive(&mut self, santa: usize) {
+        if let Entry::Vacant(entry) = self.santas.entry(santa) {
+            entry.insert(Point::ORIGIN);
+            self.deliver(santa, Point::ORIGIN);
+        }
+    }
+
+    fn step(&mut self, santa: usize, direction: Direction) {
+        let pos = self.santas.get_mut(&santa).unwrap();
+        *pos += direction.offset();
+        let house = *pos;
+        self.deliver(santa, house);
+    }
+
+    pub fn presents(&self) -> u32 {
+        self.houses.values().map(|h| h.presents).sum()
+    }
+
+    // the houses visited by more than one santa
+    pub fn shared(&self) -> usize {
+        self.houses.values().filter(|h| h.santas.len() > 1).count()
+    }
+}
+
+// the santa making the move `i` of `moves` in all, if any can
+fn scheduled(schedule: &Schedule, turn: &mut Turn, i: usize, moves: usize) -> Option<usize> {
+    match *schedule {
+        Schedule::RoundRobin(0) | Schedule::Blocks(0) => None,
+        Schedule::RoundRobin(count) => Some(i % count),
+        Schedule::Blocks(count) => {
+            let (short, longer) = (moves / count, moves % count);
+            let in_longer = longer * (short + 1);
+            Some(if i < in_longer {
+                i / (short + 1)
+            } else {
+                longer + (i - in_longer) / short
+            })
+        }
+        Schedule::Weighted(ref weights) => turn.next(weights),
+        Schedule::Tagged => None,
+    }
+}
+
+// follows the arrows of the plan, other characters being skipped; moves are left undone
+// when there are no santas to make them
+pub fn deliver(plan: &str, schedule: &Schedule) -> Result<Deliveries, ParseError> {
+    let moves = plan.chars().filter_map(Direction::from_arrow).count();
+    let (count, mut turn) = match schedule {
+        Schedule::RoundRobin(count) | Schedule::Blocks(count) => (*count, Turn::first(&[])),
+        Schedule::Tagged => (0, Turn::first(&[])),
+        Schedule::Weighted(weights) => (weights.len(), Turn::first(weights)),
+    };
+    let mut deliveries = Deliveries::default();
+    (0..count).for_each(|santa| deliveries.arrive(santa));
+    let mut current = None;
+    let mut i = 0;
+    for (offset, char) in plan.char_indices() {
+        if *schedule == Schedule::Tagged {
+            if let Some(santa) = char.to_digit(10) {
+                deliveries.arrive(santa as usize);
+                current = Some(santa as usize);
+                continue;
+            }
+        }
+        let Some(direction) = Direction::from_arrow(char) else {
+            continue;
+        };
+        let santa = match schedule {
+            Schedule::Tagged => {
+                let token = &plan[offset..offset + char.len_utf8()];
+                Some(current.ok_or_else(|| {
+                    ParseError::new(plan, token, "move before any santa tag").at_line(1)
+                })?)
+            }
+            _ => scheduled(schedule, &mut turn, i, moves),
+        };
+        if let Some(santa) = santa {
+            deliveries.step(santa, direction);
+        }
+        i += 1;
+    }
+    Ok(deliveries)
+}
+
+pub fn deliver_presents(plan: &str, num_santas: usize) -> u32 {
+    // only tagged plans can be invalid
+    let deliveries = deliver(plan, &Schedule::RoundRobin(num_santas)).unwrap();
+    deliveries.houses.len() as u32
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    const EXAMPLE: &'static str = "^v^v^v^v^v";
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(plan: &String) -> u32 {
+        deliver_presents(plan, 1)
+    }
+
+    fn part2(plan: &String) -> u32 {
+        deliver_presents(plan, 2)
+    }
+}
+
+// `size` moves in random directions
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| *['^', '>', 'v', '<'].choose(rng).unwrap())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_santa_alone() {
+        assert_eq!(deliver_presents("^v", 1), 2);
+        assert_eq!(deliver_presents("^>v<", 1), 4);
+        assert_eq!(deliver_presents("^v^v^v^v^v", 1), 2);
+    }
+
+    #[test]
+    fn test_santa_and_robot() {
+        assert_eq!(deliver_presents("^v", 2), 3);
+        assert_eq!(deliver_presents("^>v<", 2), 3);
+        assert_eq!(deliver_presents("^v^v^v^v^v", 2), 11);
+    }
+
+    fn house(presents: u32, santas: &[usize]) -> House {
+        House {
+            presents,
+            santas: santas.iter().copied().collect(),
+        }
+    }
+
+    #[test]
+    fn test_deliver() {
+        let deliveries = deliver("^>v<\n", &Schedule::RoundRobin(2)).unwrap();
+        assert_eq!(deliveries.houses.len(), 3);
+        assert_eq!(deliveries.houses[&Point::ORIGIN], house(4, &[0, 1]));
+        assert_eq!(deliveries.houses[&Point::new(1, 0)], house(1, &[1]));
+        assert_eq!(deliveries.presents(), 6);
+        assert_eq!(deliveries.shared(), 1);
+        assert_eq!(
+            deliver("^v", &Schedule::RoundRobin(0)),
+            Ok(Deliveries::default())
+        );
+    }
+
+    #[test]
+    fn test_schedules() {
+        let houses = |schedule: Schedule| deliver("^^^>>", &schedule).unwrap().houses.len();
+        // "^^^" then ">>" from the start
+        assert_eq!(houses(Schedule::Blocks(2)), 6);
+        assert_eq!(houses(Schedule::Blocks(5)), 3);
+        assert_eq!(houses(Schedule::RoundRobin(2)), 5);
+        // "^^>>" and "^", to a house already visited
+        assert_eq!(houses(Schedule::weighted(vec![2, 1]).unwrap()), 5);
+        assert_eq!(houses(Schedule::weighted(vec![1, 0]).unwrap()), 6);
+        assert_eq!(houses(Schedule::weighted(vec![0, 1, 0, 2]).unwrap()), 5);
+        // huge weights are walked through, the first santa making every move here
+        let huge = Schedule::weighted(vec![usize::MAX / 2, 1]).unwrap();
+        assert_eq!(houses(huge), 6);
+        for weights in [vec![0, 0], vec![]] {
+            assert_eq!(
+                Schedule::weighted(weights.clone()),
+                Err("weighted schedule without any santa of weight above 0".to_string())
+            );
+            // left undone, like with no santas at all
+            let deliveries = deliver("^^^>>", &Schedule::Weighted(weights.clone())).unwrap();
+            assert_eq!(deliveries.presents() as usize, weights.len());
+        }
+        let tagged = deliver("0^^1>3v0>", &Schedule::Tagged).unwrap();
+        assert_eq!(tagged.houses[&Point::ORIGIN], house(3, &[0, 1, 3]));
+        assert_eq!(tagged.houses.len(), 6);
+        assert_eq!(
+            deliver("^0^", &Schedule::Tagged).unwrap_err().to_string(),
+            "line 1, column 1: move before any santa tag \"^\""
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let directions = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&directions), 2);
+        assert_eq!(Puzzle::part2(&directions), 11);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_moves(seed: u64, size in 0..200usize) {
+            let plan = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(plan.chars().count(), size);
+            // the starting house and at most one more for each move
+            for houses in [Puzzle::part1(&plan), Puzzle::part2(&plan)] {
+                prop_assert!((1..=size as u32 + 1).contains(&houses));
+            }
+        }
+
+        #[test]
+        fn prop_every_schedule_delivers_every_move(
+            seed: u64,
+            size in 0..200usize,
+            weights in prop::collection::vec(1..4usize, 1..5),
+        ) {
+            let plan = generate::<Puzzle>(seed, size);
+            let count = weights.len();
+            for schedule in [
+                Schedule::RoundRobin(count),
+                Schedule::Blocks(count),
+                Schedule::weighted(weights.clone()).unwrap(),
+            ] {
+                let deliveries = deliver(&plan, &schedule).unwrap();
+                prop_assert_eq!(deliveries.presents() as usize, count + size);
+            }
+        }
+    }
+}
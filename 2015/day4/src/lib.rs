@@ -0,0 +1,139 @@
+use aoc_common::rand::distributions::Alphanumeric;
+use aoc_common::rand::Rng;
+use aoc_common::{Configurable, Generate, Kind, Params, ParseError, Solution, StdRng};
+
+#[derive(Debug)]
+pub struct Mined {
+    pub nonce: u64,
+    pub attempt: String,
+    pub digest: md5::Digest,
+}
+
+// true if the hexadecimal representation of the digest starts with the given number of
+// zeros
+pub fn has_leading_zeros(digest: &md5::Digest, zeros: usize) -> bool {
+    (0..zeros).all(|i| {
+        let byte = digest.0[i / 2];
+        if i % 2 == 0 {
+            byte & 0xf0 == 0
+        } else {
+            byte & 0x0f == 0
+        }
+    })
+}
+
+pub fn find_solution(secret: &str, zeros: usize) -> Mined {
+    let mut n: u64 = 1;
+    loop {
+        let attempt = secret.to_string() + &n.to_string();
+        let digest = md5::compute(attempt.as_bytes());
+        if has_leading_zeros(&digest, zeros) {
+            return Mined {
+                nonce: n,
+                attempt,
+                digest,
+            };
+        }
+        n += 1;
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const EXAMPLE: &'static str = "abcdef";
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let secret = input.trim();
+        if secret.is_empty() {
+            return Err(ParseError::input("Missing secret key"));
+        }
+        Ok(secret.to_string())
+    }
+
+    fn part1(secret: &String) -> u64 {
+        Puzzle::part1_with(secret, &None)
+    }
+
+    fn part2(secret: &String) -> u64 {
+        Puzzle::part2_with(secret, &None)
+    }
+}
+
+// the secret key can be given instead of the one of the input
+impl Configurable for Puzzle {
+    type Settings = Option<String>;
+
+    const PARAMS: &'static [(&'static str, Kind)] = &[("secret", Kind::Text)];
+
+    fn settings(params: &Params) -> Result<Option<String>, String> {
+        Ok(params.text("secret").map(String::from))
+    }
+
+    fn part1_with(secret: &String, configured: &Option<String>) -> u64 {
+        find_solution(configured.as_deref().unwrap_or(secret), 5).nonce
+    }
+
+    fn part2_with(secret: &String, configured: &Option<String>) -> u64 {
+        find_solution(configured.as_deref().unwrap_or(secret), 6).nonce
+    }
+}
+
+// a secret key of `size` letters and digits, at least one
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        rng.sample_iter(Alphanumeric)
+            .take(size.max(1))
+            .map(char::from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use aoc_common::{solve_configured, Answer, Param};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_find_solution() {
+        assert_eq!(find_solution("abcdef", 5).nonce, 609043);
+        assert_eq!(find_solution("pqrstuv", 5).nonce, 1048970);
+    }
+
+    #[test]
+    fn test_example() {
+        let secret = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&secret), 609043);
+    }
+
+    #[test]
+    fn test_configured() {
+        let mut params = Params::default();
+        params.set("secret", Param::Text("abcdef".to_string()));
+        let outputs = solve_configured::<Puzzle>("pqrstuv", &[1], &params).unwrap();
+        assert_eq!(outputs[0].answer, Answer::Number(609043));
+    }
+
+    proptest! {
+        // mining with fewer zeros than the puzzle asks, which would take too long
+        #[test]
+        fn prop_random_secrets(seed: u64, size in 1..20usize) {
+            let secret = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(secret.len(), size);
+            let mined = find_solution(&secret, 2);
+            prop_assert_eq!(&mined.attempt, &format!("{}{}", secret, mined.nonce));
+            prop_assert_eq!(mined.digest, md5::compute(&mined.attempt));
+            prop_assert!(has_leading_zeros(&mined.digest, 2));
+            // and it is the first one
+            let earlier = (1..mined.nonce)
+                .find(|n| has_leading_zeros(&md5::compute(format!("{}{}", secret, n)), 2));
+            prop_assert_eq!(earlier, None);
+        }
+    }
+}
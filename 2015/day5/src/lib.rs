@@ -0,0 +1,180 @@
+// regex crate doesn't support backreferences, which are used in the part 2 rules
+use aoc_common::rand::Rng;
+use aoc_common::{Generate, ParseError, Solution, StdRng, Streaming};
+use fancy_regex::Regex;
+use lazy_static::lazy_static;
+
+pub fn is_nice_part1(s: &str) -> bool {
+    let mut num_vowels = 0;
+    let mut double_letter = false;
+    let mut iter = s.chars().peekable();
+    while let Some(c) = iter.next() {
+        let next_c = iter.peek();
+        if c == 'a' || c == 'e' || c == 'i' || c == 'o' || c == 'u' {
+            num_vowels += 1;
+        }
+        if let Some(nc) = next_c {
+            if c == *nc {
+                double_letter = true;
+            }
+        }
+        match (c, next_c) {
+            (_, None) => (),
+            ('a', Some('b')) => {
+                return false;
+            }
+            ('c', Some('d')) => {
+                return false;
+            }
+            ('p', Some('q')) => {
+                return false;
+            }
+            ('x', Some('y')) => {
+                return false;
+            }
+            (_, _) => (),
+        }
+    }
+    num_vowels >= 3 && double_letter
+}
+
+pub fn is_nice_part2(s: &str) -> bool {
+    lazy_static! {
+        // contains a pair of any two letters that appears at least twice in the string without
+        // overlapping, like xyxy (xy) or aabcdefgaa (aa), but not like aaa (aa, but it overlaps).
+        static ref RULE1_RE: Regex = Regex::new(r"(..).*\1").unwrap();
+        // contains at least one letter which repeats with exactly one letter between them, like
+        // xyx, abcdefeghi (efe), or even aaa.
+        static ref RULE2_RE: Regex = Regex::new(r"(.).\1").unwrap();
+    }
+    RULE1_RE.is_match(s).unwrap() && RULE2_RE.is_match(s).unwrap()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = "ugknbfddgicrmopn
+aaa
+jchzalrnumimnmhp
+haegwjzuvuyypxyu
+dvszwmarrgswjxmb
+qjhvhtzxzqqjkmpb
+xxyxx
+uurcxstgmygtbstg
+ieodomkazucvgmuy";
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(strings: &Vec<String>) -> usize {
+        strings.iter().filter(|s| is_nice_part1(s)).count()
+    }
+
+    fn part2(strings: &Vec<String>) -> usize {
+        strings.iter().filter(|s| is_nice_part2(s)).count()
+    }
+}
+
+// the nice strings read so far by the rules of each part
+impl Streaming for Puzzle {
+    type State = (usize, usize);
+
+    fn feed((nice1, nice2): &mut (usize, usize), line: &str) -> Result<(), ParseError> {
+        *nice1 += usize::from(is_nice_part1(line));
+        *nice2 += usize::from(is_nice_part2(line));
+        Ok(())
+    }
+
+    fn answer1(counts: &(usize, usize)) -> usize {
+        counts.0
+    }
+
+    fn answer2(counts: &(usize, usize)) -> usize {
+        counts.1
+    }
+}
+
+// `size` strings of 16 lowercase letters, drawn from the first few letters so that
+// some of them have the pairs and repeats the rules look for
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut s: String = (0..16).map(|_| rng.gen_range('a'..='j')).collect();
+                s.push('\n');
+                s
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve, solve_stream};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_is_nice_part1() {
+        assert!(!is_nice_part1(""));
+        assert!(is_nice_part1("aaa"));
+        assert!(is_nice_part1("ugknbfddgicrmopn"));
+        assert!(!is_nice_part1("jchzalrnumimnmhp"));
+        assert!(!is_nice_part1("haegwjzuvuyypxyu"));
+        assert!(!is_nice_part1("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn test_is_nice_part2() {
+        assert!(!is_nice_part2(""));
+        assert!(is_nice_part2("qjhvhtzxzqqjkmpb"));
+        assert!(is_nice_part2("xxyxx"));
+        assert!(!is_nice_part2("uurcxstgmygtbstg"));
+        assert!(!is_nice_part2("ieodomkazucvgmuy"));
+    }
+
+    #[test]
+    fn test_example() {
+        let strings = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&strings), 2);
+        assert_eq!(Puzzle::part2(&strings), 2);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut Puzzle::EXAMPLE.as_bytes(), &[1, 2]),
+            solve::<Puzzle>(Puzzle::EXAMPLE, &[1, 2])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_strings(seed: u64, size in 0..100usize) {
+            let strings = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(strings.len(), size);
+            prop_assert!(Puzzle::part1(&strings) <= size);
+            prop_assert!(Puzzle::part2(&strings) <= size);
+            // strings with a forbidden pair are never nice
+            let forbidden = strings
+                .iter()
+                .filter(|s| ["ab", "cd", "pq", "xy"].iter().any(|p| s.contains(p)))
+                .count();
+            prop_assert!(Puzzle::part1(&strings) <= size - forbidden);
+        }
+
+        #[test]
+        fn prop_stream_same_answers(seed: u64, size in 0..100usize) {
+            let input = generate::<Puzzle>(seed, size);
+            prop_assert_eq!(
+                solve_stream::<Puzzle>(&mut input.as_bytes(), &[1, 2]),
+                solve::<Puzzle>(&input, &[1, 2])
+            );
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use aoc_common::rand::Rng;
+use aoc_common::{
+    parse_lines, Animation, Generate, Grid, Palette, ParseError, Point, Rgb, Simulation, Solution,
+    StdRng, Visual,
+};
+use std::ops::RangeInclusive;
+
+const MAX_X: usize = 1000;
+const MAX_Y: usize = 1000;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Operation {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Instruction {
+    op: Operation,
+    from: Point,
+    to: Point,
+}
+
+impl Instruction {
+    // the columns and rows of the lights, coordinates were checked to be inside the grid
+    // when parsing
+    fn area(&self) -> (RangeInclusive<usize>, RangeInclusive<usize>) {
+        let (from_x, from_y) = self.from.to_position().unwrap();
+        let (to_x, to_y) = self.to.to_position().unwrap();
+        (from_x..=to_x, from_y..=to_y)
+    }
+
+    // Part 1: lights are on/off binary
+    pub fn execute_part1(&self, grid: &mut Grid<bool>) {
+        let (columns, rows) = self.area();
+        for y in rows {
+            let row = &mut grid.row_mut(y)[columns.clone()];
+            match self.op {
+                Operation::TurnOn => row.fill(true),
+                Operation::TurnOff => row.fill(false),
+                Operation::Toggle => row.iter_mut().for_each(|b| *b = !*b),
+            }
+        }
+    }
+
+    // Part 2: lights have integer brightness
+    pub fn execute_part2(&self, grid: &mut Grid<u8>) {
+        let (columns, rows) = self.area();
+        for y in rows {
+            let slice = grid.row_mut(y)[columns.clone()].iter_mut();
+            match self.op {
+                Operation::TurnOn => slice.for_each(|v| *v += 1),
+                Operation::TurnOff => slice.for_each(|v| {
+                    if *v > 0 {
+                        *v -= 1;
+                    }
+                }),
+                Operation::Toggle => slice.for_each(|v| *v += 2),
+            }
+        }
+    }
+}
+
+// The grid of lights following the instructions one by one, each step executing the
+// next instruction with the meaning given to it
+pub struct Lights<'a, T> {
+    pub grid: Grid<T>,
+    instructions: std::slice::Iter<'a, Instruction>,
+    execute: fn(&Instruction, &mut Grid<T>),
+}
+
+impl<'a, T: Clone> Lights<'a, T> {
+    pub fn new(
+        off: T,
+        instructions: &'a [Instruction],
+        execute: fn(&Instruction, &mut Grid<T>),
+    ) -> Self {
+        Lights {
+            grid: Grid::new(MAX_X, MAX_Y, off),
+            instructions: instructions.iter(),
+            execute,
+        }
+    }
+}
+
+impl<T> Simulation for Lights<'_, T> {
+    fn step(&mut self) {
+        if let Some(instruction) = self.instructions.next() {
+            (self.execute)(instruction, &mut self.grid);
+        }
+    }
+}
+
+pub fn count_lit(grid: &Grid<bool>) -> usize {
+    grid.iter().filter(|&&on| on).count()
+}
+
+pub fn total_brightness(grid: &Grid<u8>) -> u32 {
+    grid.iter().map(|&n| u32::from(n)).sum()
+}
+
+pub fn parse_into_instruction(s: &str) -> Result<Instruction, ParseError> {
+    let mut iter = s.split(' ').peekable();
+    let op = match (iter.next(), iter.peek()) {
+        (Some("turn"), Some(&"on")) => {
+            iter.next();
+            Operation::TurnOn
+        }
+        (Some("turn"), Some(&"off")) => {
+            iter.next();
+            Operation::TurnOff
+        }
+        (Some("toggle"), _) => Operation::Toggle,
+        (Some(word), _) => return Err(ParseError::new(s, word, "Invalid operation")),
+        (None, _) => unreachable!("split always returns at least one item"),
+    };
+    let end = &s[s.len()..];
+    let from = iter
+        .next()
+        .ok_or_else(|| ParseError::new(s, end, "Missing first coordinate"))?;
+    let from = parse_coordinates(from).map_err(|e| e.shifted(s, from))?;
+    let inter = iter
+        .next()
+        .ok_or_else(|| ParseError::new(s, end, "Missing through"))?;
+    if inter != "through" {
+        return Err(ParseError::new(
+            s,
+            inter,
+            "Expected \"through\" between coordinates, got",
+        ));
+    }
+    let to = iter
+        .next()
+        .ok_or_else(|| ParseError::new(s, end, "Missing second coordinate"))?;
+    let to = parse_coordinates(to).map_err(|e| e.shifted(s, to))?;
+    if let Some(extra) = iter.next() {
+        return Err(ParseError::new(s, extra, "Unexpected trailing text"));
+    }
+    Ok(Instruction { op, from, to })
+}
+
+// parse a string slice (e.g. "12,34") into a Point of the grid
+pub fn parse_coordinates(s: &str) -> Result<Point, ParseError> {
+    let point: Point = s.parse()?;
+    match point.to_position() {
+        Some((x, y)) if x < MAX_X && y < MAX_Y => Ok(point),
+        _ => Err(ParseError::new(s, s, "Coordinate outside of the grid")),
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    const EXAMPLE: &'static str = "turn on 0,0 through 999,999
+toggle 0,0 through 999,0
+turn off 499,499 through 500,500";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_into_instruction)
+    }
+
+    fn part1(instructions: &Self::Input) -> usize {
+        let mut lights = Lights::new(false, instructions, Instruction::execute_part1);
+        lights.run(instructions.len());
+        count_lit(&lights.grid)
+    }
+
+    fn part2(instructions: &Self::Input) -> u32 {
+        let mut lights = Lights::new(0, instructions, Instruction::execute_part2);
+        lights.run(instructions.len());
+        total_brightness(&lights.grid)
+    }
+}
+
+// the brightness of the lights every 10 instructions
+impl Visual for Puzzle {
+    fn frames(instructions: &Vec<Instruction>) -> Animation {
+        let palette = Palette::gradient(Rgb::BLACK, Rgb(255, 220, 120), 32);
+        let mut lights = Lights::new(0, instructions, Instruction::execute_part2);
+        Animation::record(&mut lights, instructions.len(), 10, |lights| {
+            palette.paint(&lights.grid)
+        })
+    }
+}
+
+// `size` instructions on random rectangles of the grid
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let op = ["turn on", "turn off", "toggle"][rng.gen_range(0..3)];
+                let (x1, x2) = (rng.gen_range(0..MAX_X), rng.gen_range(0..MAX_X));
+                let (y1, y2) = (rng.gen_range(0..MAX_Y), rng.gen_range(0..MAX_Y));
+                format!(
+                    "{} {},{} through {},{}\n",
+                    op,
+                    x1.min(x2),
+                    y1.min(y2),
+                    x1.max(x2),
+                    y1.max(y2)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_instruction_execute_part1() {
+        let mut grid = Grid::new(MAX_X, MAX_Y, false);
+        Instruction {
+            op: Operation::TurnOff,
+            from: Point { x: 0, y: 0 },
+            to: Point { x: 10, y: 10 },
+        }
+        .execute_part1(&mut grid);
+        assert_eq!(count_lit(&grid), 0);
+        Instruction {
+            op: Operation::TurnOn,
+            from: Point { x: 50, y: 100 },
+            to: Point { x: 550, y: 600 },
+        }
+        .execute_part1(&mut grid);
+        assert_eq!(count_lit(&grid), 251_001);
+        assert!(grid[(50, 100)] && grid[(550, 600)] && !grid[(551, 600)]);
+        Instruction {
+            op: Operation::Toggle,
+            from: Point { x: 0, y: 0 },
+            to: Point { x: 999, y: 999 },
+        }
+        .execute_part1(&mut grid);
+        assert_eq!(count_lit(&grid), 748_999);
+    }
+
+    #[test]
+    fn test_instruction_execute_part2() {
+        let mut grid = Grid::new(MAX_X, MAX_Y, 0);
+        Instruction {
+            op: Operation::TurnOff,
+            from: Point { x: 0, y: 0 },
+            to: Point { x: 10, y: 10 },
+        }
+        .execute_part2(&mut grid);
+        assert_eq!(total_brightness(&grid), 0);
+        Instruction {
+            op: Operation::TurnOn,
+            from: Point { x: 50, y: 100 },
+            to: Point { x: 550, y: 600 },
+        }
+        .execute_part2(&mut grid);
+        assert_eq!(total_brightness(&grid), 251001);
+        Instruction {
+            op: Operation::Toggle,
+            from: Point { x: 0, y: 0 },
+            to: Point { x: 499, y: 499 },
+        }
+        .execute_part2(&mut grid);
+        assert_eq!(total_brightness(&grid), 751001);
+    }
+
+    #[test]
+    fn test_lights() {
+        let instructions = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        let mut lights = Lights::new(false, &instructions, Instruction::execute_part1);
+        lights.step();
+        assert_eq!(count_lit(&lights.grid), 1_000_000);
+        lights.step();
+        assert_eq!(count_lit(&lights.grid), 1_000_000 - 1000);
+        // nothing changes once all the instructions are followed
+        lights.run(5);
+        assert_eq!(count_lit(&lights.grid), 1_000_000 - 1000 - 4);
+    }
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(parse_coordinates("0,0").unwrap(), Point { x: 0, y: 0 });
+        assert_eq!(
+            parse_coordinates("123,456").unwrap(),
+            Point { x: 123, y: 456 }
+        );
+        assert!(parse_coordinates("123456789,987654321").is_err());
+        assert!(parse_coordinates("12,ab").is_err());
+        assert!(parse_coordinates("ab,12").is_err());
+        assert!(parse_coordinates("abc,def").is_err());
+        assert!(parse_coordinates("").is_err());
+    }
+
+    #[test]
+    fn test_parse_into_instructions() {
+        assert_eq!(
+            parse_into_instruction("turn on 0,0 through 999,999").unwrap(),
+            Instruction {
+                op: Operation::TurnOn,
+                from: Point { x: 0, y: 0 },
+                to: Point { x: 999, y: 999 }
+            }
+        );
+        assert_eq!(
+            parse_into_instruction("turn off 42,123 through 27,456").unwrap(),
+            Instruction {
+                op: Operation::TurnOff,
+                from: Point { x: 42, y: 123 },
+                to: Point { x: 27, y: 456 }
+            }
+        );
+        assert_eq!(
+            parse_into_instruction("toggle 2,4 through 6,8").unwrap(),
+            Instruction {
+                op: Operation::Toggle,
+                from: Point { x: 2, y: 4 },
+                to: Point { x: 6, y: 8 }
+            }
+        );
+        assert!(parse_into_instruction("turn 2,4 through 6,8").is_err());
+        assert!(parse_into_instruction("random junk").is_err());
+        assert!(parse_into_instruction("").is_err());
+        assert!(parse_into_instruction("toggle 0,0 through 1000,0").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_into_instruction("turn on 0,0 thru 9,9")
+                .unwrap_err()
+                .to_string(),
+            "column 13: Expected \"through\" between coordinates, got \"thru\""
+        );
+        assert_eq!(
+            parse_into_instruction("toggle 0,0 through 9,x")
+                .unwrap_err()
+                .to_string(),
+            "column 22: Invalid coordinate, expected an integer \"x\""
+        );
+        assert_eq!(
+            Puzzle::parse("toggle 0,0 through 9,9\ntoggle 0,0")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 11: Missing through"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let instructions = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&instructions), 1_000_000 - 1000 - 4);
+        assert_eq!(Puzzle::part2(&instructions), 1_000_000 + 2000 - 4);
+    }
+
+    proptest! {
+        // a few cases only, each one lights a million lights twice
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn prop_random_instructions(seed: u64, size in 1..10usize) {
+            let instructions = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(instructions.len(), size);
+            // lit lights have a brightness of at least 1
+            let lit = Puzzle::part1(&instructions);
+            prop_assert!(lit as u32 <= Puzzle::part2(&instructions));
+            prop_assert!(lit <= MAX_X * MAX_Y);
+        }
+    }
+}
@@ -0,0 +1,553 @@
+use aoc_common::rand::seq::SliceRandom;
+use aoc_common::rand::Rng;
+use aoc_common::{parse_lines, parse_token, Generate, ParseError, Solution, StdRng, Unsolved};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+pub type WireName = String;
+pub type State = HashMap<WireName, i32>;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ValueOrWire {
+    Wire(WireName),
+    Value(i32),
+}
+
+impl ValueOrWire {
+    fn input_ready(&self, state: &State) -> bool {
+        match self {
+            ValueOrWire::Value(_) => true,
+            ValueOrWire::Wire(w) if state.contains_key(w) => true,
+            _ => false,
+        }
+    }
+
+    fn value(&self, state: &State) -> Result<i32, &str> {
+        match self {
+            ValueOrWire::Value(n) => Ok(*n),
+            ValueOrWire::Wire(w) => {
+                if let Some(v) = state.get(w) {
+                    Ok(*v)
+                } else {
+                    Err("Input wire not in current state")
+                }
+            }
+        }
+    }
+}
+
+// wires are named with lowercase letters
+fn is_wire_name(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+impl FromStr for ValueOrWire {
+    type Err = ();
+    fn from_str(s: &str) -> std::result::Result<Self, <Self as std::str::FromStr>::Err> {
+        match s.parse::<i32>() {
+            Ok(n) => Ok(ValueOrWire::Value(n)),
+            Err(_) if is_wire_name(s) => Ok(ValueOrWire::Wire(s.to_string())),
+            Err(_) => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for ValueOrWire {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValueOrWire::Wire(w) => write!(f, "{}", w),
+            ValueOrWire::Value(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Op1 {
+    source: ValueOrWire,
+    target: WireName,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Op2 {
+    source1: ValueOrWire,
+    source2: ValueOrWire,
+    target: WireName,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct OpShift {
+    source: ValueOrWire,
+    amount: i32,
+    target: WireName,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Operation {
+    Assign(Op1),
+    And(Op2),
+    Or(Op2),
+    Not(Op1),
+    LShift(OpShift),
+    RShift(OpShift),
+}
+
+// the instruction as written in the input
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operation::Assign(op) => write!(f, "{} -> {}", op.source, op.target),
+            Operation::Not(op) => write!(f, "NOT {} -> {}", op.source, op.target),
+            Operation::And(op) => write!(f, "{} AND {} -> {}", op.source1, op.source2, op.target),
+            Operation::Or(op) => write!(f, "{} OR {} -> {}", op.source1, op.source2, op.target),
+            Operation::LShift(op) => {
+                write!(f, "{} LSHIFT {} -> {}", op.source, op.amount, op.target)
+            }
+            Operation::RShift(op) => {
+                write!(f, "{} RSHIFT {} -> {}", op.source, op.amount, op.target)
+            }
+        }
+    }
+}
+
+pub trait Executable {
+    fn execute(&self, state: &mut State) -> Result<(), &str>;
+    fn is_executable(&self, state: &State) -> bool;
+}
+
+impl Executable for Operation {
+    fn execute(&self, state: &mut State) -> Result<(), &str> {
+        if !self.is_executable(state) {
+            return Err("Operation is not executable: not all inputs are ready");
+        }
+        match self {
+            Operation::Assign(op) => {
+                state.insert(op.target.clone(), op.source.value(state)?);
+                Ok(())
+            }
+            Operation::And(op) => {
+                state.insert(
+                    op.target.clone(),
+                    op.source1.value(state)? & op.source2.value(state)?,
+                );
+                Ok(())
+            }
+            Operation::Or(op) => {
+                state.insert(
+                    op.target.clone(),
+                    op.source1.value(state)? | op.source2.value(state)?,
+                );
+                Ok(())
+            }
+            Operation::Not(op) => {
+                state.insert(op.target.clone(), !op.source.value(state)?);
+                Ok(())
+            }
+            Operation::LShift(op) => {
+                state.insert(op.target.clone(), op.source.value(state)? << op.amount);
+                Ok(())
+            }
+            Operation::RShift(op) => {
+                state.insert(op.target.clone(), op.source.value(state)? >> op.amount);
+                Ok(())
+            }
+        }
+    }
+
+    // return true if can be executed, false if inputs are missing
+    fn is_executable(&self, state: &State) -> bool {
+        match self {
+            Operation::Assign(op) | Operation::Not(op) => op.source.input_ready(state),
+            Operation::And(op) | Operation::Or(op) => {
+                op.source1.input_ready(state) && op.source2.input_ready(state)
+            }
+            Operation::LShift(op) | Operation::RShift(op) => op.source.input_ready(state),
+        }
+    }
+}
+
+pub fn parse_instruction(s: &str) -> Result<Operation, ParseError> {
+    let value = |token: &str| parse_token::<ValueOrWire>(s, token, "Invalid signal or wire");
+    let amount = |token: &str| parse_token::<i32>(s, token, "Invalid shift amount");
+    let wire = |token: &str| {
+        if is_wire_name(token) {
+            Ok(token.to_string())
+        } else {
+            Err(ParseError::new(s, token, "Invalid wire"))
+        }
+    };
+    let operation = match s.split(' ').collect::<Vec<&str>>()[..] {
+        [source, "->", target] => Operation::Assign(Op1 {
+            source: value(source)?,
+            target: wire(target)?,
+        }),
+        ["NOT", source, "->", target] => Operation::Not(Op1 {
+            source: value(source)?,
+            target: wire(target)?,
+        }),
+        [source1, "AND", source2, "->", target] => Operation::And(Op2 {
+            source1: value(source1)?,
+            source2: value(source2)?,
+            target: wire(target)?,
+        }),
+        [source1, "OR", source2, "->", target] => Operation::Or(Op2 {
+            source1: value(source1)?,
+            source2: value(source2)?,
+            target: wire(target)?,
+        }),
+        [source, "LSHIFT", shift, "->", target] => Operation::LShift(OpShift {
+            source: value(source)?,
+            amount: amount(shift)?,
+            target: wire(target)?,
+        }),
+        [source, "RSHIFT", shift, "->", target] => Operation::RShift(OpShift {
+            source: value(source)?,
+            amount: amount(shift)?,
+            target: wire(target)?,
+        }),
+        _ => return Err(ParseError::new(s, s, "Bad instruction")),
+    };
+    Ok(operation)
+}
+
+impl Operation {
+    fn target(&self) -> &WireName {
+        match self {
+            Operation::Assign(op) | Operation::Not(op) => &op.target,
+            Operation::And(op) | Operation::Or(op) => &op.target,
+            Operation::LShift(op) | Operation::RShift(op) => &op.target,
+        }
+    }
+}
+
+// run all instructions until every wire has a signal, failing with the wires left
+// without one when a pass over the pending instructions can't run any of them, be it
+// because some input wire is never driven or because wires feed each other in a loop
+pub fn run_circuit(operations: &[Operation]) -> Result<State, String> {
+    let mut pending: Vec<&Operation> = operations.iter().collect();
+    let mut state = State::new();
+    while !pending.is_empty() {
+        let before = pending.len();
+        pending.retain(|instruction| instruction.execute(&mut state).is_err());
+        if pending.len() == before {
+            let mut wires: Vec<&str> = pending.iter().map(|i| i.target().as_str()).collect();
+            wires.sort_unstable();
+            wires.dedup();
+            return Err(format!("no signal reaches wires {}", wires.join(", ")));
+        }
+    }
+    Ok(state)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Operation>;
+    type Part1 = Option<i32>;
+    type Part2 = Unsolved;
+
+    const EXAMPLE: &'static str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let instructions = parse_lines(input, parse_instruction)?;
+        run_circuit(&instructions).map_err(ParseError::input)?;
+        Ok(instructions)
+    }
+
+    // signal on wire a, which the example circuit doesn't have
+    fn part1(instructions: &Self::Input) -> Option<i32> {
+        run_circuit(instructions).ok()?.get("a").copied()
+    }
+
+    fn part2(_instructions: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+}
+
+// name of the nth wire: a, b, ..., z, aa, ab, ...
+fn wire_name(mut n: usize) -> WireName {
+    let mut name = vec![];
+    loop {
+        name.push(b'a' + (n % 26) as u8);
+        if n < 26 {
+            break;
+        }
+        n = n / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+// a circuit of `size` wires in random order, each wire's signal coming from 16-bit
+// values and wires defined before it so that there are no loops
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        let mut lines: Vec<String> = (0..size)
+            .map(|n| {
+                let target = wire_name(n);
+                let source = |rng: &mut StdRng| match rng.gen_range(0..n + 1) {
+                    0 => ValueOrWire::Value(rng.gen_range(0..=u16::MAX).into()),
+                    wire => ValueOrWire::Wire(wire_name(wire - 1)),
+                };
+                let operation = match rng.gen_range(0..6) {
+                    _ if n == 0 => Operation::Assign(Op1 {
+                        source: ValueOrWire::Value(rng.gen_range(0..=u16::MAX).into()),
+                        target,
+                    }),
+                    0 => Operation::Assign(Op1 {
+                        source: source(rng),
+                        target,
+                    }),
+                    1 => Operation::Not(Op1 {
+                        source: source(rng),
+                        target,
+                    }),
+                    2 => Operation::And(Op2 {
+                        source1: source(rng),
+                        source2: source(rng),
+                        target,
+                    }),
+                    3 => Operation::Or(Op2 {
+                        source1: source(rng),
+                        source2: source(rng),
+                        target,
+                    }),
+                    4 => Operation::LShift(OpShift {
+                        source: source(rng),
+                        amount: rng.gen_range(0..16),
+                        target,
+                    }),
+                    _ => Operation::RShift(OpShift {
+                        source: source(rng),
+                        amount: rng.gen_range(0..16),
+                        target,
+                    }),
+                };
+                operation.to_string()
+            })
+            .collect();
+        lines.shuffle(rng);
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_operation_execute() {
+        let mut state = State::new();
+        Operation::Assign(Op1 {
+            source: ValueOrWire::Value(123),
+            target: "x".to_string(),
+        })
+        .execute(&mut state)
+        .unwrap();
+        assert_eq!(state.get("x"), Some(&123));
+        Operation::Assign(Op1 {
+            source: ValueOrWire::Wire("x".to_string()),
+            target: "y".to_string(),
+        })
+        .execute(&mut state)
+        .unwrap();
+        assert_eq!(state.get("y"), Some(&123));
+    }
+
+    #[test]
+    fn test_operation_is_executable() {
+        let mut state = State::new();
+        let op1 = Operation::Assign(Op1 {
+            source: ValueOrWire::Value(123),
+            target: "x".to_string(),
+        });
+        assert!(op1.is_executable(&state));
+        let op2 = Operation::Assign(Op1 {
+            source: ValueOrWire::Wire("x".to_string()),
+            target: "y".to_string(),
+        });
+        assert!(!op2.is_executable(&state));
+        op1.execute(&mut state).unwrap();
+        assert!(op2.is_executable(&state));
+    }
+
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(
+            parse_instruction("123 -> x").unwrap(),
+            Operation::Assign(Op1 {
+                source: ValueOrWire::Value(123),
+                target: "x".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("x AND y -> d").unwrap(),
+            Operation::And(Op2 {
+                source1: ValueOrWire::Wire("x".to_string()),
+                source2: ValueOrWire::Wire("y".to_string()),
+                target: "d".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("42 AND y -> d").unwrap(),
+            Operation::And(Op2 {
+                source1: ValueOrWire::Value(42),
+                source2: ValueOrWire::Wire("y".to_string()),
+                target: "d".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("x AND 42 -> d").unwrap(),
+            Operation::And(Op2 {
+                source1: ValueOrWire::Wire("x".to_string()),
+                source2: ValueOrWire::Value(42),
+                target: "d".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("x OR y -> d").unwrap(),
+            Operation::Or(Op2 {
+                source1: ValueOrWire::Wire("x".to_string()),
+                source2: ValueOrWire::Wire("y".to_string()),
+                target: "d".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("42 OR y -> d").unwrap(),
+            Operation::Or(Op2 {
+                source1: ValueOrWire::Value(42),
+                source2: ValueOrWire::Wire("y".to_string()),
+                target: "d".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("x OR 42 -> d").unwrap(),
+            Operation::Or(Op2 {
+                source1: ValueOrWire::Wire("x".to_string()),
+                source2: ValueOrWire::Value(42),
+                target: "d".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("x LSHIFT 2 -> f").unwrap(),
+            Operation::LShift(OpShift {
+                source: ValueOrWire::Wire("x".to_string()),
+                amount: 2,
+                target: "f".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("y RSHIFT 2 -> g").unwrap(),
+            Operation::RShift(OpShift {
+                source: ValueOrWire::Wire("y".to_string()),
+                amount: 2,
+                target: "g".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("NOT x -> h").unwrap(),
+            Operation::Not(Op1 {
+                source: ValueOrWire::Wire("x".to_string()),
+                target: "h".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_instruction("NOT 42 -> i").unwrap(),
+            Operation::Not(Op1 {
+                source: ValueOrWire::Value(42),
+                target: "i".to_string(),
+            })
+        );
+        assert!(parse_instruction("").is_err());
+        assert!(parse_instruction("-> x").is_err());
+        assert!(parse_instruction("123 ->").is_err());
+        assert!(parse_instruction("123 -> 123 123").is_err());
+        assert_eq!(
+            parse_instruction("x LSHIFT two -> f")
+                .unwrap_err()
+                .to_string(),
+            "column 10: Invalid shift amount \"two\""
+        );
+        assert_eq!(
+            Puzzle::parse("123 -> x\nx XOR y -> z")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: Bad instruction \"x XOR y -> z\""
+        );
+        assert_eq!(
+            parse_instruction("x AND y! -> d").unwrap_err().to_string(),
+            "column 7: Invalid signal or wire \"y!\""
+        );
+        assert_eq!(
+            parse_instruction("1 -> D").unwrap_err().to_string(),
+            "column 6: Invalid wire \"D\""
+        );
+    }
+
+    #[test]
+    fn test_value_or_wire_parse() {
+        assert_eq!("123".parse::<ValueOrWire>(), Ok(ValueOrWire::Value(123)));
+        assert_eq!(
+            "a".parse::<ValueOrWire>(),
+            Ok(ValueOrWire::Wire("a".to_string()))
+        );
+        assert_eq!("".parse::<ValueOrWire>(), Err(()));
+        assert_eq!("A1".parse::<ValueOrWire>(), Err(()));
+    }
+
+    #[test]
+    fn test_undriven_wires() {
+        assert_eq!(
+            Puzzle::parse("x -> a").unwrap_err().to_string(),
+            "no signal reaches wires a"
+        );
+        assert_eq!(
+            Puzzle::parse("1 -> c\nb AND c -> a\na -> b\nc -> d")
+                .unwrap_err()
+                .to_string(),
+            "no signal reaches wires a, b"
+        );
+        assert_eq!(run_circuit(&[]).unwrap(), State::new());
+    }
+
+    #[test]
+    fn test_example() {
+        let instructions = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        let state = run_circuit(&instructions).unwrap();
+        let signals: Vec<i32> = ["d", "e", "f", "g", "x", "y"]
+            .iter()
+            .map(|wire| state[*wire])
+            .collect();
+        assert_eq!(signals, [72, 507, 492, 114, 123, 456]);
+        assert_eq!(Puzzle::part1(&instructions), None);
+    }
+
+    #[test]
+    fn test_wire_name() {
+        let names: Vec<WireName> = [0, 1, 25, 26, 27, 701, 702].map(wire_name).into();
+        assert_eq!(names, ["a", "b", "z", "aa", "ab", "zz", "aaa"]);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_circuits(seed: u64, size in 1..100usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let instructions = Puzzle::parse(&text).unwrap();
+            let formatted: Vec<String> = instructions.iter().map(|i| i.to_string()).collect();
+            prop_assert_eq!(formatted.join("\n"), text);
+            // every wire gets a signal
+            prop_assert_eq!(run_circuit(&instructions).unwrap().len(), size);
+            prop_assert!(Puzzle::part1(&instructions).is_some());
+        }
+    }
+}
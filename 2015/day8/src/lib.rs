@@ -0,0 +1,216 @@
+use aoc_common::rand::Rng;
+use aoc_common::{parse_lines, Generate, ParseError, Solution, StdRng, Streaming};
+
+pub fn count_code_chars(s: &str) -> usize {
+    s.len()
+}
+
+pub fn count_memory_chars(s: &str) -> usize {
+    let mut char_iter = s
+        .trim_start_matches('"')
+        .trim_end_matches('"')
+        .chars()
+        .peekable();
+    let mut count = 0;
+    while let Some(c) = char_iter.next() {
+        count += match c {
+            '\\' => match char_iter.peek() {
+                Some('\\') | Some('"') => {
+                    char_iter.next();
+                    1
+                }
+                Some('x') => {
+                    char_iter.next();
+                    char_iter.next();
+                    char_iter.next();
+                    1
+                }
+                _ => 1,
+            },
+            _ => 1,
+        }
+    }
+    count
+}
+
+pub fn count_escaped_chars(s: &str) -> usize {
+    let mut escaped = String::with_capacity(s.len() * 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '\\' => {
+                escaped.push('\\');
+                escaped.push('\\');
+            }
+            '\"' => {
+                escaped.push('\\');
+                escaped.push('\"');
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped.len()
+}
+
+// each line must be a string literal, enclosed in double quotes
+pub fn parse_literal(line: &str) -> Result<String, ParseError> {
+    if line.len() < 2 || !line.starts_with('"') || !line.ends_with('"') {
+        return Err(ParseError::new(
+            line,
+            line,
+            "Expected a string enclosed in double quotes, got",
+        ));
+    }
+    Ok(line.to_string())
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = "\"\"
+\"abc\"
+\"aaa\\\"aaa\"
+\"\\x27\"";
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_lines(input, parse_literal)
+    }
+
+    fn part1(lines: &Vec<String>) -> usize {
+        lines
+            .iter()
+            .map(|line| count_code_chars(line) - count_memory_chars(line))
+            .sum()
+    }
+
+    fn part2(lines: &Vec<String>) -> usize {
+        lines
+            .iter()
+            .map(|line| count_escaped_chars(line) - count_code_chars(line))
+            .sum()
+    }
+}
+
+// the extra characters of the literals read so far, in code and once escaped
+impl Streaming for Puzzle {
+    type State = (usize, usize);
+
+    fn feed((code, escaped): &mut (usize, usize), line: &str) -> Result<(), ParseError> {
+        parse_literal(line)?;
+        *code += count_code_chars(line) - count_memory_chars(line);
+        *escaped += count_escaped_chars(line) - count_code_chars(line);
+        Ok(())
+    }
+
+    fn answer1(counts: &(usize, usize)) -> usize {
+        counts.0
+    }
+
+    fn answer2(counts: &(usize, usize)) -> usize {
+        counts.1
+    }
+}
+
+// `size` string literals of letters and escape sequences
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut literal = String::from("\"");
+                for _ in 0..rng.gen_range(0..20) {
+                    match rng.gen_range(0..10) {
+                        0 => literal.push_str("\\\\"),
+                        1 => literal.push_str("\\\""),
+                        2 => literal.push_str(&format!("\\x{:02x}", rng.gen::<u8>())),
+                        _ => literal.push(rng.gen_range('a'..='z')),
+                    }
+                }
+                literal.push_str("\"\n");
+                literal
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve, solve_stream};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_count_code_chars() {
+        assert_eq!(count_code_chars("\"\""), 2);
+        assert_eq!(count_code_chars("\"abc\""), 5);
+        assert_eq!(count_code_chars("\"aaa\\\"aaa\""), 10);
+        assert_eq!(count_code_chars("\"\\x27\""), 6);
+    }
+
+    #[test]
+    fn test_count_escaped_chars() {
+        assert_eq!(count_escaped_chars("\"\""), 6);
+        assert_eq!(count_escaped_chars("\"abc\""), 9);
+        assert_eq!(count_escaped_chars("\"aaa\\\"aaa\""), 16);
+        assert_eq!(count_escaped_chars("\"\\x27\""), 11);
+    }
+
+    #[test]
+    fn test_count_memory_chars() {
+        assert_eq!(count_memory_chars("\"\""), 0);
+        assert_eq!(count_memory_chars("\"abc\""), 3);
+        assert_eq!(count_memory_chars("\"aaa\\\"aaa\""), 7);
+        assert_eq!(count_memory_chars("\"\\x27\""), 1);
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        assert_eq!(parse_literal("\"abc\""), Ok("\"abc\"".to_string()));
+        assert!(parse_literal("\"").is_err());
+        assert_eq!(
+            Puzzle::parse("\"\"\nabc\"").unwrap_err().to_string(),
+            "line 2, column 1: Expected a string enclosed in double quotes, got \"abc\"\""
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let literals = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&literals), 12);
+        assert_eq!(Puzzle::part2(&literals), 19);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut Puzzle::EXAMPLE.as_bytes(), &[1, 2]),
+            solve::<Puzzle>(Puzzle::EXAMPLE, &[1, 2])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_literals(seed: u64, size in 0..100usize) {
+            let lines = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(lines.len(), size);
+            // decoding removes the quotes at least, encoding adds the new quotes and a
+            // backslash before each backslash and quote
+            prop_assert!(Puzzle::part1(&lines) >= 2 * size);
+            let escaped = lines.iter().map(|l| l.matches(['\\', '"']).count()).sum::<usize>();
+            prop_assert_eq!(Puzzle::part2(&lines), 2 * size + escaped);
+        }
+
+        #[test]
+        fn prop_stream_same_answers(seed: u64, size in 0..100usize) {
+            let input = generate::<Puzzle>(seed, size);
+            prop_assert_eq!(
+                solve_stream::<Puzzle>(&mut input.as_bytes(), &[1, 2]),
+                solve::<Puzzle>(&input, &[1, 2])
+            );
+        }
+    }
+}
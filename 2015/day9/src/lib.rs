@@ -0,0 +1,179 @@
+use aoc_common::rand::Rng;
+use aoc_common::{
+    parse_lines, parse_token, Generate, Graph, Mode, ParseError, Path, Solution, StdRng,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParsedLine {
+    from: String,
+    to: String,
+    weight: i32,
+}
+
+pub fn parse_line(line: &str) -> Result<ParsedLine, ParseError> {
+    match line.split(' ').collect::<Vec<&str>>()[..] {
+        [from, "to", to, "=", weight] => Ok(ParsedLine {
+            from: from.to_string(),
+            to: to.to_string(),
+            weight: parse_token(line, weight, "Bad weight")?,
+        }),
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "Bad line format, expected \"A to B = distance\", got",
+        )),
+    }
+}
+
+// the distances between every two cities
+pub fn parse_graph(input: &str) -> Result<Graph<i32>, ParseError> {
+    let mut graph = Graph::undirected();
+    for parsed_line in parse_lines(input, parse_line)? {
+        graph.add_edge(&parsed_line.from, &parsed_line.to, parsed_line.weight);
+    }
+    if graph.is_empty() {
+        return Err(ParseError::input("No distances between cities"));
+    }
+    for from in 0..graph.len() {
+        for to in from + 1..graph.len() {
+            if graph.weight(from, to).is_none() {
+                return Err(ParseError::input(format!(
+                    "Missing distance from {} to {}",
+                    graph.label(from),
+                    graph.label(to)
+                )));
+            }
+        }
+    }
+    Ok(graph)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Graph<i32>;
+    type Part1 = Path<i32>;
+    type Part2 = Path<i32>;
+
+    const EXAMPLE: &'static str = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
+    fn parse(input: &str) -> Result<Graph<i32>, ParseError> {
+        parse_graph(input)
+    }
+
+    // the route is reported along with its distance
+    fn part1(graph: &Graph<i32>) -> Path<i32> {
+        graph
+            .hamiltonian_path(Mode::Shortest)
+            .expect("every city is connected to the others")
+    }
+
+    fn part2(graph: &Graph<i32>) -> Path<i32> {
+        graph
+            .hamiltonian_path(Mode::Longest)
+            .expect("every city is connected to the others")
+    }
+}
+
+const CITIES: [&str; 8] = [
+    "AlphaCentauri",
+    "Snowdin",
+    "Tambi",
+    "Faerun",
+    "Norrath",
+    "Straylight",
+    "Tristram",
+    "Arbre",
+];
+
+// the distances between `size` cities, from 2 to 8 like the puzzle's so that every route
+// can be tried
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let cities = &CITIES[..size.clamp(2, CITIES.len())];
+        let mut lines = vec![];
+        for (i, from) in cities.iter().enumerate() {
+            for to in &cities[i + 1..] {
+                lines.push(format!("{} to {} = {}", from, to, rng.gen_range(1..=150)));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Output;
+    use aoc_common::{generate, NodeId};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line("London to Dublin = 464"),
+            Ok(ParsedLine {
+                from: "London".to_string(),
+                to: "Dublin".to_string(),
+                weight: 464
+            })
+        );
+        assert_eq!(
+            parse_line("London to Dublin = far")
+                .unwrap_err()
+                .to_string(),
+            "column 20: Bad weight \"far\""
+        );
+        assert_eq!(
+            parse_graph("London to Dublin = 464\nLondon - Belfast")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: Bad line format, expected \"A to B = distance\", got \"London - Belfast\""
+        );
+        assert_eq!(
+            parse_graph("London to Dublin = 464\nLondon to Belfast = 518")
+                .unwrap_err()
+                .to_string(),
+            "Missing distance from Dublin to Belfast"
+        );
+        assert_eq!(
+            parse_graph("").unwrap_err().to_string(),
+            "No distances between cities"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let graph = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&graph).total_weight, 605);
+        let longest = Output::from(Puzzle::part2(&graph));
+        assert_eq!(longest.answer, aoc_common::Answer::Number(982));
+        assert_eq!(
+            longest.extra.unwrap().to_string(),
+            r#"{"nodes":["Dublin","London","Belfast"]}"#
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn prop_random_distances(seed: u64, size in 2..=8usize) {
+            let graph = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(graph.len(), size);
+            let (shortest, longest) = (Puzzle::part1(&graph), Puzzle::part2(&graph));
+            prop_assert!(shortest.total_weight <= longest.total_weight);
+            // routes go through every city once
+            for route in [shortest, longest] {
+                let mut cities = route.nodes.clone();
+                cities.sort();
+                cities.dedup();
+                prop_assert_eq!(cities.len(), size);
+                let ids: Vec<NodeId> = route.nodes.iter().map(|c| graph.id(c).unwrap()).collect();
+                prop_assert_eq!(graph.path_weight(&ids), Some(route.total_weight));
+            }
+        }
+    }
+}
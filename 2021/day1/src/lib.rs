@@ -0,0 +1,169 @@
+use aoc_common::rand::Rng;
+use aoc_common::{parse_lines, parse_token, Generate, ParseError, Solution, StdRng, Streaming};
+use std::collections::VecDeque;
+
+pub fn count_increases(measures: &[i32], size: usize) -> u64 {
+    let mut count = 0;
+    let mut prev: Option<i32> = None;
+    for w in measures.windows(size) {
+        let current: i32 = w.iter().sum();
+        if let Some(p) = prev {
+            if current > p {
+                count += 1;
+            }
+        }
+        prev = Some(current);
+    }
+    count
+}
+
+pub fn parse_measure(line: &str) -> Result<i32, ParseError> {
+    parse_token(line, line, "Expected an integer measure")
+}
+
+pub fn parse_measures(input: &str) -> Result<Vec<i32>, ParseError> {
+    parse_lines(input, parse_measure)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<i32>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const EXAMPLE: &'static str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse_measures(input)
+    }
+
+    fn part1(measures: &Vec<i32>) -> u64 {
+        count_increases(measures, 1)
+    }
+
+    fn part2(measures: &Vec<i32>) -> u64 {
+        count_increases(measures, 3)
+    }
+}
+
+// The increases among the measures read so far, keeping the last three. Two windows of
+// three measures next to each other share two of them, so the later window is larger
+// exactly when its last measure is larger than the first one of the earlier window.
+#[derive(Default)]
+pub struct Increases {
+    last: VecDeque<i32>,
+    single: u64,
+    windows: u64,
+}
+
+impl Streaming for Puzzle {
+    type State = Increases;
+
+    fn feed(increases: &mut Increases, line: &str) -> Result<(), ParseError> {
+        let measure = parse_measure(line)?;
+        if increases.last.back().is_some_and(|&last| measure > last) {
+            increases.single += 1;
+        }
+        if increases.last.len() == 3
+            && increases
+                .last
+                .pop_front()
+                .is_some_and(|first| measure > first)
+        {
+            increases.windows += 1;
+        }
+        increases.last.push_back(measure);
+        Ok(())
+    }
+
+    fn answer1(increases: &Increases) -> u64 {
+        increases.single
+    }
+
+    fn answer2(increases: &Increases) -> u64 {
+        increases.windows
+    }
+}
+
+// `size` depths going mostly down, like the sea floor
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let mut depth: i32 = rng.gen_range(100..200);
+        (0..size)
+            .map(|_| {
+                depth = (depth + rng.gen_range(-20..=30)).max(0);
+                format!("{}\n", depth)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve, solve_stream};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_count_increases() {
+        // window of size 1, for Part 1
+        assert_eq!(count_increases(&Vec::<i32>::new(), 1), 0);
+        assert_eq!(count_increases(&[1, 2, 3, 2, 2, 3, 1, 3], 1), 4);
+        assert_eq!(count_increases(&[1, 1, 1, 1], 1), 0);
+        assert_eq!(count_increases(&[4, 3, 2, 1], 1), 0);
+
+        // window of size 3, for Part 2
+        assert_eq!(count_increases(&Vec::<i32>::new(), 3), 0);
+        assert_eq!(count_increases(&[1, 2, 3, 2, 2, 3, 1, 3], 3), 2);
+        assert_eq!(count_increases(&[1, 1, 1, 1], 3), 0);
+        assert_eq!(count_increases(&[4, 3, 2, 1], 3), 0);
+    }
+
+    #[test]
+    fn test_example() {
+        let measures = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&measures), 7);
+        assert_eq!(Puzzle::part2(&measures), 5);
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut Puzzle::EXAMPLE.as_bytes(), &[1, 2]),
+            solve::<Puzzle>(Puzzle::EXAMPLE, &[1, 2])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_measures(seed: u64, size in 0..200usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let measures = Puzzle::parse(&text).unwrap();
+            let formatted: String = measures.iter().map(|m| format!("{}\n", m)).collect();
+            prop_assert_eq!(formatted, text);
+            // each window is compared with the previous one, if any
+            let (part1, part2) = (Puzzle::part1(&measures), Puzzle::part2(&measures));
+            prop_assert!(part1 as usize <= size.saturating_sub(1));
+            prop_assert!(part2 as usize <= size.saturating_sub(3));
+        }
+
+        #[test]
+        fn prop_stream_same_answers(seed: u64, size in 0..100usize) {
+            let input = generate::<Puzzle>(seed, size);
+            prop_assert_eq!(
+                solve_stream::<Puzzle>(&mut input.as_bytes(), &[1, 2]),
+                solve::<Puzzle>(&input, &[1, 2])
+            );
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use aoc_common::rand::Rng;
+use aoc_common::{parse_lines, Generate, ParseError, Solution, StdRng, Streaming};
+
+const CORRUPT_PAREN: u64 = 3;
+const CORRUPT_SQUARE: u64 = 57;
+const CORRUPT_CURLY: u64 = 1197;
+const CORRUPT_ANGLE: u64 = 25137;
+const INCOMPLETE_PAREN: u64 = 1;
+const INCOMPLETE_SQUARE: u64 = 2;
+const INCOMPLETE_CURLY: u64 = 3;
+const INCOMPLETE_ANGLE: u64 = 4;
+
+#[derive(Debug, PartialEq)]
+pub enum LineResult {
+    Corrupt(u64),
+    Incomplete(u64),
+    Valid,
+}
+
+pub fn score_line(line: &str) -> LineResult {
+    let mut stack = Vec::<char>::new();
+    for c in line.chars() {
+        match c {
+            '(' => stack.push(')'),
+            '[' => stack.push(']'),
+            '{' => stack.push('}'),
+            '<' => stack.push('>'),
+            ')' => {
+                if stack.pop() != Some(')') {
+                    return LineResult::Corrupt(CORRUPT_PAREN);
+                }
+            }
+            ']' => {
+                if stack.pop() != Some(']') {
+                    return LineResult::Corrupt(CORRUPT_SQUARE);
+                }
+            }
+            '}' => {
+                if stack.pop() != Some('}') {
+                    return LineResult::Corrupt(CORRUPT_CURLY);
+                }
+            }
+            '>' => {
+                if stack.pop() != Some('>') {
+                    return LineResult::Corrupt(CORRUPT_ANGLE);
+                }
+            }
+            _ => panic!("Invalid character in input: {}", c),
+        }
+    }
+    if !stack.is_empty() {
+        // line is incomplete, whatever remains in stack are the missing characters
+        let score = stack.iter().rev().fold(0, |acc, c| {
+            (acc * 5)
+                + match c {
+                    ')' => INCOMPLETE_PAREN,
+                    ']' => INCOMPLETE_SQUARE,
+                    '}' => INCOMPLETE_CURLY,
+                    '>' => INCOMPLETE_ANGLE,
+                    // could have an Enum for characters in stack to make the match
+                    // exhaustive and avoid this panic!, but I found it overkill
+                    _ => panic!("Invalid character in stack, how could this happen?!: {}", c),
+                }
+        });
+        return LineResult::Incomplete(score);
+    }
+    LineResult::Valid
+}
+
+// middle score of the incomplete lines, of which there must be an odd number, None
+// when there are none
+pub fn middle_score(mut scores: Vec<u64>) -> Option<u64> {
+    scores.sort_unstable();
+    scores.get(scores.len() / 2).copied()
+}
+
+// lines can only have chunk delimiters
+pub fn parse_line(line: &str) -> Result<String, ParseError> {
+    check_line(line)?;
+    Ok(line.to_string())
+}
+
+fn check_line(line: &str) -> Result<(), ParseError> {
+    if let Some(i) = line.find(|c| !"()[]{}<>".contains(c)) {
+        let c = &line[i..i + line[i..].chars().next().unwrap().len_utf8()];
+        return Err(ParseError::new(line, c, "Invalid character"));
+    }
+    Ok(())
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = Option<u64>;
+
+    const EXAMPLE: &'static str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part1(lines: &Vec<String>) -> u64 {
+        lines
+            .iter()
+            .map(|l| match score_line(l) {
+                LineResult::Corrupt(score) => score,
+                _ => 0,
+            })
+            .sum::<u64>()
+    }
+
+    fn part2(lines: &Vec<String>) -> Option<u64> {
+        let incomplete_scores = lines
+            .iter()
+            .filter_map(|l| match score_line(l) {
+                LineResult::Incomplete(score) => Some(score),
+                _ => None,
+            })
+            .collect();
+        middle_score(incomplete_scores)
+    }
+}
+
+// The score of the corrupted lines read so far and the scores of the incomplete ones,
+// which are all needed to find the middle one. These take much less room than the
+// lines, but still grow with the number of incomplete lines, so unlike the other
+// streamed days this one doesn't read its input in constant memory.
+#[derive(Default)]
+pub struct Scores {
+    corrupted: u64,
+    incomplete: Vec<u64>,
+}
+
+impl Streaming for Puzzle {
+    type State = Scores;
+
+    fn feed(scores: &mut Scores, line: &str) -> Result<(), ParseError> {
+        check_line(line)?;
+        match score_line(line) {
+            LineResult::Corrupt(score) => scores.corrupted += score,
+            LineResult::Incomplete(score) => scores.incomplete.push(score),
+            LineResult::Valid => {}
+        }
+        Ok(())
+    }
+
+    fn answer1(scores: &Scores) -> u64 {
+        scores.corrupted
+    }
+
+    fn answer2(scores: &Scores) -> Option<u64> {
+        middle_score(scores.incomplete.clone())
+    }
+}
+
+const CHUNKS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+// Chunks nested no deeper than 20, enough for the scores of incomplete lines to fit
+const MAX_DEPTH: usize = 20;
+
+// `size` lines, at least one, either incomplete or corrupted, with an odd number of
+// incomplete ones for their scores to have a middle one
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        let incomplete = rng.gen_range(0..size) | 1;
+        (0..size)
+            .map(|n| {
+                let mut line = String::new();
+                let mut open = vec![];
+                for _ in 0..rng.gen_range(1..60) {
+                    if open.is_empty() || (open.len() < MAX_DEPTH && rng.gen_bool(0.55)) {
+                        let (opening, closing) = CHUNKS[rng.gen_range(0..4)];
+                        line.push(opening);
+                        open.push(closing);
+                    } else {
+                        line.push(open.pop().unwrap());
+                    }
+                }
+                if n < incomplete {
+                    if open.is_empty() {
+                        line.push('(');
+                    }
+                } else {
+                    // closes the last chunk with the wrong character
+                    let expected = open.pop().unwrap_or_else(|| {
+                        line.push('[');
+                        ']'
+                    });
+                    let wrong: Vec<char> = CHUNKS
+                        .iter()
+                        .map(|&(_, closing)| closing)
+                        .filter(|&c| c != expected)
+                        .collect();
+                    line.push(wrong[rng.gen_range(0..3)]);
+                }
+                line + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve, solve_stream, Answer};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_score_line() {
+        // valid lines
+        assert_eq!(score_line(""), LineResult::Valid);
+        assert_eq!(score_line("[]"), LineResult::Valid);
+        assert_eq!(score_line("{()()()}"), LineResult::Valid);
+        assert_eq!(score_line("[<>({}){}[([])<>]]"), LineResult::Valid);
+        // incomplete lines
+        assert_eq!(
+            score_line("[({(<(())[]>[[{[]{<()<>>"),
+            LineResult::Incomplete(288957)
+        );
+        assert_eq!(
+            score_line("[(()[<>])]({[<{<<[]>>("),
+            LineResult::Incomplete(5566)
+        );
+        assert_eq!(
+            score_line("(((({<>}<{<{<>}{[]{[]{}"),
+            LineResult::Incomplete(1480781)
+        );
+        assert_eq!(
+            score_line("{<[[]]>}<{[{[{[]{()[[[]"),
+            LineResult::Incomplete(995444)
+        );
+        assert_eq!(
+            score_line("<{([{{}}[<[[[<>{}]]]>[]]"),
+            LineResult::Incomplete(294)
+        );
+        // corrupt lines
+        assert_eq!(score_line("[[<[([]))<([[{}[[()]]]"), LineResult::Corrupt(3));
+        assert_eq!(
+            score_line("[{[{({}]{}}([{[{{{}}([]"),
+            LineResult::Corrupt(57)
+        );
+        assert_eq!(
+            score_line("{([(<{}[<>[]}>{[]{[(<()>"),
+            LineResult::Corrupt(1197)
+        );
+        assert_eq!(
+            score_line("<{([([[(<>()){}]>(<<{{"),
+            LineResult::Corrupt(25137)
+        );
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("[<>]"), Ok("[<>]".to_string()));
+        assert_eq!(
+            Puzzle::parse("[<>]\n[(a)]").unwrap_err().to_string(),
+            "line 2, column 3: Invalid character \"a\""
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let lines = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&lines), 26397);
+        assert_eq!(Puzzle::part2(&lines), Some(288957));
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut Puzzle::EXAMPLE.as_bytes(), &[1, 2]),
+            solve::<Puzzle>(Puzzle::EXAMPLE, &[1, 2])
+        );
+        // no incomplete line, so no middle score
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut "<)\n".as_bytes(), &[1, 2]),
+            Ok(vec![Answer::Number(3), Answer::Unsolved])
+        );
+        let overflowing = "(>\n".repeat(200_000);
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut overflowing.as_bytes(), &[1]),
+            Ok(vec![Answer::Number(200_000 * 25137)])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_lines(seed: u64, size in 1..100usize) {
+            let lines = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(lines.len(), size);
+            let results: Vec<LineResult> = lines.iter().map(|l| score_line(l)).collect();
+            prop_assert!(!results.contains(&LineResult::Valid));
+            let incomplete = results
+                .iter()
+                .filter(|r| matches!(r, LineResult::Incomplete(_)))
+                .count();
+            prop_assert_eq!(incomplete % 2, 1);
+            // the middle score is one of the scores of incomplete lines
+            prop_assert!(results.contains(&LineResult::Incomplete(Puzzle::part2(&lines).unwrap())));
+        }
+
+        #[test]
+        fn prop_stream_same_answers(seed: u64, size in 0..100usize) {
+            let input = generate::<Puzzle>(seed, size);
+            prop_assert_eq!(
+                solve_stream::<Puzzle>(&mut input.as_bytes(), &[1, 2]),
+                solve::<Puzzle>(&input, &[1, 2])
+            );
+        }
+    }
+}
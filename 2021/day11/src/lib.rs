@@ -0,0 +1,326 @@
+use aoc_common::rand::Rng;
+use aoc_common::{
+    Animation, Configurable, Generate, Grid, Kind, Palette, Params, ParseError, Rgb, Simulation,
+    Solution, StdRng, Visual,
+};
+use std::collections::HashSet;
+
+pub type Energy = u8;
+const MAX_ENERGY: Energy = 9;
+const STEPS_PART_1: usize = 100;
+
+// the puzzle's grid is 10 x 10, larger random ones seldom synchronize
+const MAX_SIDE: usize = 10;
+const SYNC_LIMIT: usize = 1000;
+
+// energy of the octopi and those that flashed during the last step
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cavern {
+    grid: Grid<Energy>,
+    step: u32,
+    flashed: HashSet<(usize, usize)>,
+}
+
+impl Cavern {
+    fn from_str(input: &str) -> Result<Cavern, ParseError> {
+        Ok(Cavern {
+            grid: Grid::parse_digits(input)?,
+            step: 0,
+            flashed: HashSet::new(),
+        })
+    }
+
+    // make all octopus over max energy to flash, increasing the energy of adjacent octopi
+    fn flash(&mut self) {
+        let flashing: Vec<(usize, usize)> = self
+            .grid
+            .enumerate()
+            .filter(|(p, x)| **x > MAX_ENERGY && !self.flashed.contains(p))
+            .map(|(p, _)| p)
+            .collect();
+        for (x, y) in flashing {
+            // octopus flashes
+            self.flashed.insert((x, y));
+            for a in self.grid.neighbours8(x, y) {
+                self.grid[a] += 1;
+            }
+        }
+    }
+}
+
+impl Simulation for Cavern {
+    fn step(&mut self) {
+        self.flashed = HashSet::new();
+        self.grid.iter_mut().for_each(|x| *x += 1);
+        loop {
+            let n = self.flashed.len();
+            self.flash();
+            // stop when no more octopi flash
+            if n == self.flashed.len() {
+                break;
+            }
+        }
+        // reset octopus that are above max energy to 0
+        self.grid
+            .iter_mut()
+            .filter(|x| **x > MAX_ENERGY)
+            .for_each(|x| *x = 0);
+        self.step += 1;
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Cavern;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    fn parse(input: &str) -> Result<Cavern, ParseError> {
+        Cavern::from_str(input)
+    }
+
+    fn part1(cavern: &Cavern) -> usize {
+        Puzzle::part1_with(cavern, &Steps::default())
+    }
+
+    fn part2(cavern: &Cavern) -> usize {
+        Puzzle::part2_with(cavern, &Steps::default())
+    }
+}
+
+// steps counted by part 1, part 2 runs until the octopi flash together
+pub struct Steps(usize);
+
+impl Default for Steps {
+    fn default() -> Self {
+        Steps(STEPS_PART_1)
+    }
+}
+
+impl Configurable for Puzzle {
+    type Settings = Steps;
+
+    const PARAMS: &'static [(&'static str, Kind)] = &[("steps_part_1", Kind::Count)];
+
+    fn settings(params: &Params) -> Result<Steps, String> {
+        Ok(Steps(
+            params
+                .count("steps_part_1")
+                .map_or(STEPS_PART_1, |n| n as usize),
+        ))
+    }
+
+    // flashes during the given steps
+    fn part1_with(cavern: &Cavern, steps: &Steps) -> usize {
+        let mut cavern = cavern.clone();
+        (0..steps.0)
+            .map(|_| {
+                cavern.step();
+                cavern.flashed.len()
+            })
+            .sum()
+    }
+
+    // first step during which all octopi flash
+    fn part2_with(cavern: &Cavern, _steps: &Steps) -> usize {
+        cavern
+            .clone()
+            .run_until(|cavern| cavern.flashed.len() == cavern.grid.len())
+    }
+}
+
+// every step until all the octopi flash at once, flashing octopi in white and the others
+// brighter as their energy rises
+impl Visual for Puzzle {
+    fn frames(cavern: &Cavern) -> Animation {
+        let (dark, bright) = (Rgb(10, 20, 60), Rgb(90, 140, 255));
+        let mut colors = vec![Rgb::WHITE];
+        colors.extend(
+            (1..=MAX_ENERGY).map(|e| dark.mix(bright, f64::from(e) / f64::from(MAX_ENERGY))),
+        );
+        let palette = Palette::new(colors);
+        let steps = Puzzle::part2(cavern);
+        Animation::record(&mut cavern.clone(), steps, 1, |cavern| {
+            palette.paint(&cavern.grid)
+        })
+    }
+}
+
+// a `size` x `size` grid of random energies, from 2 x 2 to 10 x 10. Grids whose octopi
+// don't all flash together within SYNC_LIMIT steps are drawn again, part 2 would never
+// end on them
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let side = size.clamp(2, MAX_SIDE);
+        loop {
+            let grid = Grid::new(side, side, 0).map(|_| rng.gen_range(0..=MAX_ENERGY));
+            let mut cavern = Cavern {
+                grid: grid.clone(),
+                step: 0,
+                flashed: HashSet::new(),
+            };
+            if (0..SYNC_LIMIT).any(|_| {
+                cavern.step();
+                cavern.flashed.len() == cavern.grid.len()
+            }) {
+                return grid.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use aoc_common::{solve_configured, Answer, Param};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            Cavern::from_str(Puzzle::EXAMPLE).unwrap(),
+            Cavern {
+                grid: Grid::from_cells(
+                    10,
+                    vec![
+                        5, 4, 8, 3, 1, 4, 3, 2, 2, 3, 2, 7, 4, 5, 8, 5, 4, 7, 1, 1, 5, 2, 6, 4, 5,
+                        5, 6, 1, 7, 3, 6, 1, 4, 1, 3, 3, 6, 1, 4, 6, 6, 3, 5, 7, 3, 8, 5, 4, 7, 8,
+                        4, 1, 6, 7, 5, 2, 4, 6, 4, 5, 2, 1, 7, 6, 8, 4, 1, 7, 2, 1, 6, 8, 8, 2, 8,
+                        8, 1, 1, 3, 4, 4, 8, 4, 6, 8, 4, 8, 5, 5, 4, 5, 2, 8, 3, 7, 5, 1, 5, 2, 6,
+                    ]
+                ),
+                step: 0,
+                flashed: HashSet::new(),
+            }
+        )
+    }
+
+    // positions of the octopi at the given indices of the grid
+    fn positions(indices: &[usize]) -> HashSet<(usize, usize)> {
+        indices.iter().map(|i| (i % 10, i / 10)).collect()
+    }
+
+    #[test]
+    fn test_step() {
+        let mut sim = Cavern::from_str(Puzzle::EXAMPLE).unwrap();
+        sim.step();
+        assert_eq!(sim.step, 1);
+        assert_eq!(
+            sim.grid.cells(),
+            [
+                6, 5, 9, 4, 2, 5, 4, 3, 3, 4, 3, 8, 5, 6, 9, 6, 5, 8, 2, 2, 6, 3, 7, 5, 6, 6, 7, 2,
+                8, 4, 7, 2, 5, 2, 4, 4, 7, 2, 5, 7, 7, 4, 6, 8, 4, 9, 6, 5, 8, 9, 5, 2, 7, 8, 6, 3,
+                5, 7, 5, 6, 3, 2, 8, 7, 9, 5, 2, 8, 3, 2, 7, 9, 9, 3, 9, 9, 2, 2, 4, 5, 5, 9, 5, 7,
+                9, 5, 9, 6, 6, 5, 6, 3, 9, 4, 8, 6, 2, 6, 3, 7,
+            ]
+        );
+        assert_eq!(sim.flashed, HashSet::new());
+        sim.step();
+        assert_eq!(sim.step, 2);
+        assert_eq!(
+            sim.grid.cells(),
+            [
+                8, 8, 0, 7, 4, 7, 6, 5, 5, 5, 5, 0, 8, 9, 0, 8, 7, 0, 5, 4, 8, 5, 9, 7, 8, 8, 9, 6,
+                0, 8, 8, 4, 8, 5, 7, 6, 9, 6, 0, 0, 8, 7, 0, 0, 9, 0, 8, 8, 0, 0, 6, 6, 0, 0, 0, 8,
+                8, 9, 8, 9, 6, 8, 0, 0, 0, 0, 5, 9, 4, 3, 0, 0, 0, 0, 0, 0, 7, 4, 5, 6, 9, 0, 0, 0,
+                0, 0, 0, 8, 7, 6, 8, 7, 0, 0, 0, 0, 6, 8, 4, 8,
+            ]
+        );
+        assert_eq!(
+            sim.flashed,
+            positions(&[
+                2, 11, 14, 17, 28, 38, 39, 42, 43, 45, 48, 49, 52, 53, 54, 62, 63, 64, 65, 70, 71,
+                72, 73, 74, 75, 81, 82, 83, 84, 85, 86, 92, 93, 94, 95
+            ])
+        );
+        sim.step();
+        assert_eq!(sim.step, 3);
+        assert_eq!(
+            sim.grid.cells(),
+            [
+                0, 0, 5, 0, 9, 0, 0, 8, 6, 6, 8, 5, 0, 0, 8, 0, 0, 5, 7, 5, 9, 9, 0, 0, 0, 0, 0, 0,
+                3, 9, 9, 7, 0, 0, 0, 0, 0, 0, 4, 1, 9, 9, 3, 5, 0, 8, 0, 0, 6, 3, 7, 7, 1, 2, 3, 0,
+                0, 0, 0, 0, 7, 9, 1, 1, 2, 5, 0, 0, 0, 9, 2, 2, 1, 1, 1, 3, 0, 0, 0, 0, 0, 4, 2, 1,
+                1, 2, 5, 0, 0, 0, 0, 0, 2, 1, 1, 1, 9, 0, 0, 0,
+            ]
+        );
+        assert_eq!(
+            sim.flashed,
+            positions(&[
+                0, 1, 3, 5, 6, 12, 13, 15, 16, 22, 23, 24, 25, 26, 27, 32, 33, 34, 35, 36, 37, 44,
+                46, 47, 55, 56, 57, 58, 59, 66, 67, 68, 76, 77, 78, 79, 80, 87, 88, 89, 90, 91, 97,
+                98, 99
+            ])
+        );
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let simulation = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&simulation), 1656);
+        assert_eq!(Puzzle::part2(&simulation), 195);
+    }
+
+    #[test]
+    fn test_single_row() {
+        // flashes spread along the row, and the whole row flashes at the first step
+        let mut sim = Cavern::from_str("989").unwrap();
+        sim.step();
+        assert_eq!(sim.grid.cells(), [0, 0, 0]);
+        assert_eq!(Puzzle::part2(&Cavern::from_str("989").unwrap()), 1);
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!(
+            Cavern::from_str("54831\n2745").unwrap_err().to_string(),
+            "line 2, column 1: Grid must have columns of equal size, got \"2745\""
+        );
+        assert_eq!(
+            Cavern::from_str("5483143223\n274585471a")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 10: Expected a digit \"a\""
+        );
+    }
+
+    #[test]
+    fn test_configured() {
+        let mut params = Params::default();
+        params.set("steps_part_1", Param::Count(10));
+        let outputs = solve_configured::<Puzzle>(Puzzle::EXAMPLE, &[1], &params).unwrap();
+        assert_eq!(outputs[0].answer, Answer::Number(204));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn prop_random_caverns(seed: u64, size in 2..=MAX_SIDE) {
+            let text = generate::<Puzzle>(seed, size);
+            let cavern = Puzzle::parse(&text).unwrap();
+            prop_assert_eq!(cavern.grid.to_string(), text);
+            let octopi = size * size;
+            prop_assert!(Puzzle::part1(&cavern) <= STEPS_PART_1 * octopi);
+            // once in sync, the octopi flash together every 10 steps
+            let sync = Puzzle::part2(&cavern);
+            prop_assert!(sync <= SYNC_LIMIT);
+            let mut cavern = cavern.clone();
+            cavern.run(sync + 10);
+            prop_assert_eq!(cavern.flashed.len(), octopi);
+        }
+    }
+}
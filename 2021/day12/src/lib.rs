@@ -0,0 +1,211 @@
+use aoc_common::rand::seq::SliceRandom;
+use aoc_common::rand::Rng;
+use aoc_common::{Generate, Graph, NodeId, ParseError, Solution, StdRng};
+
+const START: &str = "start";
+const END: &str = "end";
+
+pub fn parse_edge(line: &str) -> Result<(&str, &str), ParseError> {
+    match line.split_once('-') {
+        Some((from, to)) if !from.is_empty() && !to.is_empty() && !to.contains('-') => {
+            Ok((from, to))
+        }
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "Expected an edge like \"a-b\", got",
+        )),
+    }
+}
+
+pub fn parse_graph(input: &str) -> Result<Graph, ParseError> {
+    let mut graph = Graph::undirected();
+    for (i, line) in input.lines().enumerate() {
+        let (from, to) = parse_edge(line).map_err(|e| e.at_line(i + 1))?;
+        graph.add_edge(from, to, ());
+    }
+    for cave in [START, END] {
+        if graph.id(cave).is_none() {
+            return Err(ParseError::input(format!("Missing {} cave", cave)));
+        }
+    }
+    Ok(graph)
+}
+
+// small caves have lowercase names
+fn is_small(graph: &Graph, cave: NodeId) -> bool {
+    graph.label(cave).chars().all(|c| c.is_lowercase())
+}
+
+// paths from start to end going through small caves at most once, except for a single
+// one visited twice when allowed
+pub fn paths(graph: &Graph, visit_twice: bool) -> Vec<Vec<NodeId>> {
+    let start = graph.id(START).unwrap();
+    let end = graph.id(END).unwrap();
+    let small: Vec<bool> = (0..graph.len()).map(|n| is_small(graph, n)).collect();
+    graph.paths(start, end, |_, visits, next| {
+        if next == start {
+            false
+        } else if !small[next] || visits[next] == 0 {
+            true
+        } else {
+            visit_twice && (0..visits.len()).all(|n| !small[n] || visits[n] < 2)
+        }
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Graph;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    fn parse(input: &str) -> Result<Graph, ParseError> {
+        parse_graph(input)
+    }
+
+    fn part1(graph: &Graph) -> usize {
+        paths(graph, false).len()
+    }
+
+    fn part2(graph: &Graph) -> usize {
+        paths(graph, true).len()
+    }
+}
+
+// name of a cave, two letters like the puzzle's
+fn cave_name(rng: &mut StdRng, small: bool) -> String {
+    let letters = if small { 'a'..='z' } else { 'A'..='Z' };
+    (0..2).map(|_| rng.gen_range(letters.clone())).collect()
+}
+
+// up to 5 small caves and 2 big ones depending on `size`, linked so that there is a way
+// from start to end, plus up to 8 more passages. Big caves are never linked together,
+// there would be endless paths going back and forth between them
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let mut small = vec![START.to_string()];
+        while small.len() <= size.clamp(1, 5) {
+            let name = cave_name(rng, true);
+            if name != END && !small.contains(&name) {
+                small.push(name);
+            }
+        }
+        let mut big = vec![];
+        while big.len() < (size / 2).clamp(1, 2) {
+            let name = cave_name(rng, false);
+            if !big.contains(&name) {
+                big.push(name);
+            }
+        }
+        // each cave linked to a small one before it
+        let mut edges = vec![];
+        for (i, cave) in small.iter().enumerate().skip(1) {
+            edges.push((small[rng.gen_range(0..i)].clone(), cave.clone()));
+        }
+        for cave in &big {
+            edges.push((small.choose(rng).unwrap().clone(), cave.clone()));
+        }
+        let caves = [&small[1..], &big[..]].concat();
+        edges.push((caves.choose(rng).unwrap().clone(), END.to_string()));
+        for _ in 0..size.min(8) {
+            let from = small.choose(rng).unwrap().clone();
+            let to = caves.choose(rng).unwrap().clone();
+            if from != to {
+                edges.push((from, to));
+            }
+        }
+        edges.shuffle(rng);
+        edges
+            .into_iter()
+            .map(|(from, to)| format!("{}-{}\n", from, to))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_edge() {
+        assert_eq!(parse_edge("start-A"), Ok(("start", "A")));
+        assert!(parse_edge("start-").is_err());
+        assert!(parse_edge("a-b-c").is_err());
+        assert_eq!(
+            parse_graph("start-A\nA end").unwrap_err().to_string(),
+            "line 2, column 1: Expected an edge like \"a-b\", got \"A end\""
+        );
+        assert_eq!(
+            parse_graph("start-A").unwrap_err().to_string(),
+            "Missing end cave"
+        );
+    }
+
+    #[test]
+    fn test_paths_visit_once() {
+        let graph = parse_graph(
+            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc",
+        )
+        .unwrap();
+        let paths: Vec<String> = paths(&graph, false)
+            .iter()
+            .map(|path| graph.labels(path).join(","))
+            .collect();
+        assert_eq!(
+            paths[..4],
+            [
+                "start,HN,dc,end",
+                "start,HN,dc,HN,end",
+                "start,HN,dc,HN,kj,HN,end",
+                "start,HN,dc,kj,HN,end"
+            ]
+        );
+        assert_eq!(paths.len(), 19);
+    }
+
+    #[test]
+    fn test_paths_visit_twice() {
+        let graph = parse_graph(
+            "fs-end\nhe-DX\nfs-he\nstart-DX\npj-DX\nend-zg\nzg-sl\nzg-pj\npj-he\nRW-he\nfs-DX\npj-RW\nzg-RW\nstart-pj\nhe-WI\nzg-he\npj-fs\nstart-RW",
+        )
+        .unwrap();
+        assert_eq!(paths(&graph, false).len(), 226);
+        assert_eq!(paths(&graph, true).len(), 3509);
+    }
+
+    #[test]
+    fn test_example() {
+        let graph = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&graph), 10);
+        assert_eq!(Puzzle::part2(&graph), 36);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn prop_random_caves(seed: u64, size in 1..12usize) {
+            let graph = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            let big: Vec<NodeId> = (0..graph.len()).filter(|&n| !is_small(&graph, n)).collect();
+            prop_assert!(big
+                .iter()
+                .all(|&n| graph.neighbours(n).all(|(m, _)| is_small(&graph, m))));
+            // visiting a small cave twice only adds paths
+            let part1 = Puzzle::part1(&graph);
+            prop_assert!(part1 >= 1);
+            prop_assert!(part1 <= Puzzle::part2(&graph));
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use aoc_common::rand::Rng;
+use aoc_common::{
+    parse_token, Animation, BoundingBox, Generate, Image, ParseError, Point, Rgb, Solution, StdRng,
+    Visual,
+};
+use std::collections::HashSet;
+
+pub type Dot = Point;
+
+pub type Paper = HashSet<Dot>;
+
+#[derive(Debug, PartialEq)]
+pub enum Fold {
+    Up(i64),
+    Left(i64),
+}
+
+pub fn apply_fold(paper: &mut Paper, fold: &Fold) {
+    let mut new = Paper::new();
+    for dot in paper.drain() {
+        // dots right on the folding axis are lost
+        match *fold {
+            Fold::Up(axis) if dot.y < axis => new.insert(dot),
+            Fold::Up(axis) if dot.y > axis => new.insert(Point::new(dot.x, 2 * axis - dot.y)),
+            Fold::Left(axis) if dot.x < axis => new.insert(dot),
+            Fold::Left(axis) if dot.x > axis => new.insert(Point::new(2 * axis - dot.x, dot.y)),
+            _ => false,
+        };
+    }
+    paper.extend(new);
+}
+
+pub fn display_paper(paper: &Paper) -> String {
+    let mut bounds = BoundingBox::new(Point::ORIGIN, Point::ORIGIN);
+    paper.iter().for_each(|&dot| bounds.include(dot));
+    let mut output = String::new();
+    for dot in bounds.points() {
+        output.push(if paper.contains(&dot) { '#' } else { ' ' });
+        if dot.x == bounds.max.x {
+            output.push('\n');
+        }
+    }
+    output
+}
+
+pub fn parse_fold(line: &str) -> Result<Fold, ParseError> {
+    match line.split(' ').collect::<Vec<&str>>()[..] {
+        ["fold", "along", inst] => match inst.split('=').collect::<Vec<&str>>()[..] {
+            ["x", v] => Ok(Fold::Left(parse_token(line, v, "Invalid fold axis")?)),
+            ["y", v] => Ok(Fold::Up(parse_token(line, v, "Invalid fold axis")?)),
+            _ => Err(ParseError::new(line, inst, "Invalid fold")),
+        },
+        _ => Err(ParseError::new(line, line, "Invalid line")),
+    }
+}
+
+pub fn parse_dot(line: &str) -> Result<Dot, ParseError> {
+    Point::parse(line, line)
+}
+
+pub fn parse_input(input: &str) -> Result<(Paper, Vec<Fold>), ParseError> {
+    let mut paper = Paper::new();
+    let mut folds = Vec::<Fold>::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        } else if line.starts_with("fold") {
+            folds.push(parse_fold(line).map_err(|e| e.at_line(i + 1))?);
+        } else {
+            paper.insert(parse_dot(line).map_err(|e| e.at_line(i + 1))?);
+        }
+    }
+    if folds.is_empty() {
+        return Err(ParseError::input("Missing fold instructions"));
+    }
+    Ok((paper, folds))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = (Paper, Vec<Fold>);
+    type Part1 = usize;
+    type Part2 = String;
+
+    const EXAMPLE: &'static str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    // number of dots visible after the first fold
+    fn part1((paper, folds): &Self::Input) -> usize {
+        let mut paper = paper.clone();
+        apply_fold(&mut paper, &folds[0]);
+        paper.len()
+    }
+
+    // paper after all folds are applied
+    fn part2((paper, folds): &Self::Input) -> String {
+        let mut paper = paper.clone();
+        for fold in folds.iter() {
+            apply_fold(&mut paper, fold);
+        }
+        display_paper(&paper)
+    }
+}
+
+// the paper before and after each fold, at the size of the unfolded paper
+impl Visual for Puzzle {
+    fn frames((paper, folds): &Self::Input) -> Animation {
+        let mut bounds = BoundingBox::new(Point::ORIGIN, Point::ORIGIN);
+        paper.iter().for_each(|&dot| bounds.include(dot));
+        let (width, height) = (bounds.width() as usize, bounds.height() as usize);
+        let draw = |paper: &Paper| {
+            let mut image = Image::new(width, height, Rgb::BLACK);
+            for (x, y) in paper.iter().filter_map(|dot| dot.to_position()) {
+                image.set(x, y, Rgb::WHITE);
+            }
+            image
+        };
+        let mut paper = paper.clone();
+        let mut animation = Animation::still(draw(&paper));
+        for fold in folds {
+            apply_fold(&mut paper, fold);
+            animation.push(draw(&paper));
+        }
+        animation
+    }
+}
+
+// 2 to 4 folds, alternately up and left, each right in the middle of the paper which
+// ends up at most 40 x 6 like the puzzle's, and `size` dots, at least one and up to half
+// of the places left, none of them ever on a fold where it would be lost
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let (mut width, mut height) = (rng.gen_range(5..=40), rng.gen_range(3..=6));
+        let count = rng.gen_range(2..=4);
+        // each point of the folded paper is where 2^count dots can end
+        let dots_count = size.clamp(1, (width * height) as usize * (1 << count) / 2);
+        let mut up = rng.gen_bool(0.5);
+        let mut folds = vec![];
+        for _ in 0..count {
+            // the paper before the fold is the part kept, the fold line and its mirror
+            if up {
+                folds.push(Fold::Up(height));
+                height = 2 * height + 1;
+            } else {
+                folds.push(Fold::Left(width));
+                width = 2 * width + 1;
+            }
+            up = !up;
+        }
+        folds.reverse();
+        let on_fold = |dot: Dot| {
+            let mut paper = Paper::from([dot]);
+            folds.iter().any(|fold| {
+                apply_fold(&mut paper, fold);
+                paper.is_empty()
+            })
+        };
+        let mut dots = vec![];
+        let mut paper = Paper::new();
+        while paper.len() < dots_count {
+            let dot = Point::new(rng.gen_range(0..width), rng.gen_range(0..height));
+            if !on_fold(dot) && paper.insert(dot) {
+                dots.push(format!("{},{}\n", dot.x, dot.y));
+            }
+        }
+        let folds: Vec<String> = folds
+            .iter()
+            .map(|fold| match fold {
+                Fold::Up(y) => format!("fold along y={}\n", y),
+                Fold::Left(x) => format!("fold along x={}\n", x),
+            })
+            .collect();
+        dots.concat() + "\n" + &folds.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    fn dots(dots: &[(i64, i64)]) -> Paper {
+        dots.iter().map(|&dot| Point::from(dot)).collect()
+    }
+
+    #[test]
+    fn test_fold() {
+        let mut paper = dots(&[
+            (6, 10),
+            (0, 14),
+            (9, 10),
+            (0, 3),
+            (10, 4),
+            (4, 11),
+            (6, 0),
+            (6, 12),
+            (4, 1),
+            (0, 13),
+            (10, 12),
+            (3, 4),
+            (3, 0),
+            (8, 4),
+            (1, 10),
+            (2, 14),
+            (8, 10),
+            (9, 0),
+        ]);
+        apply_fold(&mut paper, &Fold::Up(7));
+        assert_eq!(
+            paper,
+            dots(&[
+                (0, 0),
+                (0, 1),
+                (0, 3),
+                (1, 4),
+                (2, 0),
+                (3, 0),
+                (3, 4),
+                (4, 1),
+                (4, 3),
+                (6, 0),
+                (6, 2),
+                (6, 4),
+                (8, 4),
+                (9, 0),
+                (9, 4),
+                (10, 2),
+                (10, 4),
+            ])
+        );
+        apply_fold(&mut paper, &Fold::Left(5));
+        assert_eq!(
+            paper,
+            dots(&[
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (0, 3),
+                (0, 4),
+                (1, 0),
+                (1, 4),
+                (2, 0),
+                (2, 4),
+                (3, 0),
+                (3, 4),
+                (4, 0),
+                (4, 1),
+                (4, 2),
+                (4, 3),
+                (4, 4),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let (paper, folds) = parse_input("6,10\n0,14\n\nfold along y=7\nfold along x=5").unwrap();
+        assert_eq!(paper, dots(&[(6, 10), (0, 14)]));
+        assert_eq!(folds, vec![Fold::Up(7), Fold::Left(5)]);
+        assert_eq!(
+            parse_input("6,10\n0;14").unwrap_err().to_string(),
+            "line 2, column 1: Invalid point, expected x,y \"0;14\""
+        );
+        assert_eq!(
+            parse_input("6,10\n\nfold along z=3")
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 12: Invalid fold \"z=3\""
+        );
+        assert_eq!(
+            parse_input("6,10\n").unwrap_err().to_string(),
+            "Missing fold instructions"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let manual = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&manual), 17);
+        assert_eq!(
+            Puzzle::part2(&manual),
+            "#####\n#   #\n#   #\n#   #\n#####\n"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_papers(seed: u64, size in 1..300usize) {
+            let input = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            let (paper, folds) = &input;
+            prop_assert!((1..=size).contains(&paper.len()));
+            prop_assert!((2..=4).contains(&folds.len()));
+            // a fold overlaps at most two dots in one
+            let part1 = Puzzle::part1(&input);
+            prop_assert!(part1 <= paper.len() && 2 * part1 >= paper.len());
+            let code = Puzzle::part2(&input);
+            prop_assert!(code.lines().count() <= 6);
+            prop_assert!(code.lines().all(|line| line.len() <= 40));
+            prop_assert!(code.matches('#').count() <= part1);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use aoc_common::rand::seq::index;
+use aoc_common::rand::Rng;
+use aoc_common::{
+    parse_lines_from, Configurable, Generate, Kind, Output, Params, ParseError, Solution, StdRng,
+};
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+const STEPS_PART_1: usize = 10;
+const STEPS_PART_2: usize = 40;
+// steps after which the polymer grown from a template of thousands of elements, more
+// than in the puzzle's inputs, may no longer be counted in a u64
+const MAX_STEPS: usize = 50;
+
+pub type Polymer = Vec<char>;
+pub type Rules = HashMap<(char, char), char>;
+pub type Counter = HashMap<char, u64>;
+
+// recursive step with memoization
+struct MemoStep {
+    freqs: Counter,
+    rules: Rules,
+    memo: HashMap<(char, char, usize), Counter>,
+}
+
+impl MemoStep {
+    fn new(rules: &Rules) -> Self {
+        Self {
+            freqs: Counter::new(),
+            rules: rules.clone(),
+            memo: HashMap::new(),
+        }
+    }
+
+    // adds all counts from one counter into another
+    fn merge_counters(from: &Counter, to: &mut Counter) {
+        from.iter()
+            .for_each(|(&c, &n)| *to.entry(c).or_insert(0) += n);
+    }
+
+    fn step(&mut self, first: char, second: char, steps: usize) -> Counter {
+        let mut counter = Counter::new();
+        if let Some(mem) = self.memo.get(&(first, second, steps)) {
+            // memoized solution
+            return mem.clone();
+        } else if let Some(&i) = self.rules.get(&(first, second)) {
+            // rule match
+            *counter.entry(i).or_insert(0) += 1;
+            if steps > 1 {
+                MemoStep::merge_counters(&self.step(first, i, steps - 1), &mut counter);
+                MemoStep::merge_counters(&self.step(i, second, steps - 1), &mut counter);
+            }
+        }
+        self.memo.insert((first, second, steps), counter.clone());
+        counter
+    }
+}
+
+// calculate frequencies of each letter after applying the rules to the polymer over
+// the given number of steps
+pub fn step_polymer(polymer: &Polymer, rules: &Rules, steps: usize) -> Counter {
+    let mut memostep = MemoStep::new(rules);
+    let mut pairs = polymer.windows(2);
+    while let Some(&[a, b]) = pairs.next() {
+        *memostep.freqs.entry(a).or_insert(0) += 1;
+        let counter = memostep.step(a, b, steps);
+        MemoStep::merge_counters(&counter, &mut memostep.freqs);
+    }
+    // last entry in polymer is not included in the windows() iterator
+    *memostep.freqs.entry(*polymer.last().unwrap()).or_insert(0) += 1;
+    memostep.freqs
+}
+
+pub fn parse_rule(line: &str) -> Result<((char, char), char), ParseError> {
+    let (pair, insert) = line
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::new(line, line, "Invalid rule"))?;
+    let pair = match pair.chars().collect::<Vec<char>>()[..] {
+        [first, second] => (first, second),
+        _ => return Err(ParseError::new(line, pair, "Expected a pair of elements")),
+    };
+    match insert.chars().collect::<Vec<char>>()[..] {
+        [element] => Ok((pair, element)),
+        _ => Err(ParseError::new(line, insert, "Expected a single element")),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Polymer, Rules), ParseError> {
+    let (template, rules) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::input("Expected a polymer template and rules separated by a blank line")
+    })?;
+    if template.is_empty() {
+        return Err(ParseError::input("Empty polymer template").at_line(1));
+    }
+    let polymer = template.chars().collect();
+    let rules = parse_lines_from(rules, 3, parse_rule)?
+        .into_iter()
+        .collect();
+    Ok((polymer, rules))
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Element {
+    element: char,
+    count: u64,
+}
+
+// the most and least common elements of a polymer, the answer is the difference between
+// their counts
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Extremes {
+    most_common: Element,
+    least_common: Element,
+}
+
+impl From<Extremes> for Output {
+    fn from(extremes: Extremes) -> Self {
+        let difference = extremes.most_common.count - extremes.least_common.count;
+        Output::with_extra(difference, &extremes)
+    }
+}
+
+// most and least common elements after the given steps, ties go to the first element in
+// alphabetical order
+pub fn solve(polymer: &Polymer, rules: &Rules, steps: usize) -> Extremes {
+    let freqs = step_polymer(polymer, rules, steps);
+    let element = |(&element, &count): (&char, &u64)| Element { element, count };
+    let most_common = freqs
+        .iter()
+        .max_by_key(|&(&c, &n)| (n, Reverse(c)))
+        .map(element)
+        .unwrap();
+    let least_common = freqs
+        .iter()
+        .min_by_key(|&(&c, &n)| (n, c))
+        .map(element)
+        .unwrap();
+    Extremes {
+        most_common,
+        least_common,
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = (Polymer, Rules);
+    type Part1 = Extremes;
+    type Part2 = Extremes;
+
+    const EXAMPLE: &'static str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Extremes {
+        Puzzle::part1_with(input, &Steps::default())
+    }
+
+    fn part2(input: &Self::Input) -> Extremes {
+        Puzzle::part2_with(input, &Steps::default())
+    }
+}
+
+// steps of insertions of each part
+pub struct Steps {
+    part1: usize,
+    part2: usize,
+}
+
+impl Default for Steps {
+    fn default() -> Self {
+        Steps {
+            part1: STEPS_PART_1,
+            part2: STEPS_PART_2,
+        }
+    }
+}
+
+impl Configurable for Puzzle {
+    type Settings = Steps;
+
+    const PARAMS: &'static [(&'static str, Kind)] =
+        &[("steps_part_1", Kind::Count), ("steps_part_2", Kind::Count)];
+
+    fn settings(params: &Params) -> Result<Steps, String> {
+        let steps = |name, default| match params.count(name) {
+            None => Ok(default),
+            Some(n) if n <= MAX_STEPS as u64 => Ok(n as usize),
+            Some(_) => Err(format!("{} must be at most {}", name, MAX_STEPS)),
+        };
+        Ok(Steps {
+            part1: steps("steps_part_1", STEPS_PART_1)?,
+            part2: steps("steps_part_2", STEPS_PART_2)?,
+        })
+    }
+
+    fn part1_with((polymer, rules): &Self::Input, steps: &Steps) -> Extremes {
+        solve(polymer, rules, steps.part1)
+    }
+
+    fn part2_with((polymer, rules): &Self::Input, steps: &Steps) -> Extremes {
+        solve(polymer, rules, steps.part2)
+    }
+}
+
+// a template of `size` elements, at least one, among 2 to 10 different ones, and a rule
+// for every pair of them like in the puzzle
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let count = rng.gen_range(2..=10);
+        let elements: Vec<char> = index::sample(rng, 26, count)
+            .into_iter()
+            .map(|i| char::from(b'A' + i as u8))
+            .collect();
+        let mut element = || elements[rng.gen_range(0..count)];
+        let mut input: String = (0..size.max(1)).map(|_| element()).collect();
+        input.push('\n');
+        for first in &elements {
+            for second in &elements {
+                input.push_str(&format!("\n{}{} -> {}", first, second, element()));
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use aoc_common::{check_params, solve_configured, Answer, Param};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_merge_counters() {
+        let mut counter = Counter::from([('A', 3), ('B', 17), ('C', 42)]);
+        MemoStep::merge_counters(&Counter::new(), &mut counter);
+        assert_eq!(counter, Counter::from([('A', 3), ('B', 17), ('C', 42)]));
+        MemoStep::merge_counters(
+            &Counter::from([('A', 2), ('B', 5), ('D', 13)]),
+            &mut counter,
+        );
+        assert_eq!(
+            counter,
+            Counter::from([('A', 5), ('B', 22), ('C', 42), ('D', 13)])
+        );
+    }
+
+    #[test]
+    fn test_step_polymer() {
+        let polymer = Polymer::from(['N', 'N', 'C', 'B']);
+        let rules = Rules::from([
+            (('C', 'H'), 'B'),
+            (('H', 'H'), 'N'),
+            (('C', 'B'), 'H'),
+            (('N', 'H'), 'C'),
+            (('H', 'B'), 'C'),
+            (('H', 'C'), 'B'),
+            (('H', 'N'), 'C'),
+            (('N', 'N'), 'C'),
+            (('B', 'H'), 'H'),
+            (('N', 'C'), 'B'),
+            (('N', 'B'), 'B'),
+            (('B', 'N'), 'B'),
+            (('B', 'B'), 'N'),
+            (('B', 'C'), 'B'),
+            (('C', 'C'), 'N'),
+            (('C', 'N'), 'C'),
+        ]);
+        // example from Part 1
+        assert_eq!(
+            step_polymer(&polymer, &rules, 10),
+            Counter::from([('C', 298), ('N', 865), ('B', 1749), ('H', 161)])
+        );
+        // example from Part 2
+        let counter = step_polymer(&polymer, &rules, 40);
+        assert_eq!(counter.get(&'B'), Some(&2192039569602));
+        assert_eq!(counter.get(&'H'), Some(&3849876073));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let (polymer, rules) = parse_input("NNCB\n\nCH -> B\nHH -> N\n").unwrap();
+        assert_eq!(polymer, vec!['N', 'N', 'C', 'B']);
+        assert_eq!(rules, Rules::from([(('C', 'H'), 'B'), (('H', 'H'), 'N')]));
+        assert_eq!(
+            parse_input("NNCB\n\nCH -> B\nHHH -> N")
+                .unwrap_err()
+                .to_string(),
+            "line 4, column 1: Expected a pair of elements \"HHH\""
+        );
+        assert_eq!(
+            parse_input("NNCB\nCH -> B").unwrap_err().to_string(),
+            "Expected a polymer template and rules separated by a blank line"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let manual = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        let part1 = Output::from(Puzzle::part1(&manual));
+        assert_eq!(part1.answer, aoc_common::Answer::Number(1588));
+        assert_eq!(
+            part1.extra.unwrap().to_string(),
+            r#"{"least_common":{"count":161,"element":"H"},"most_common":{"count":1749,"element":"B"}}"#
+        );
+        assert_eq!(
+            Puzzle::part2(&manual),
+            Extremes {
+                most_common: Element {
+                    element: 'B',
+                    count: 2192039569602
+                },
+                least_common: Element {
+                    element: 'H',
+                    count: 3849876073
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_configured() {
+        let mut params = Params::default();
+        params.set("steps_part_2", Param::Count(10));
+        let outputs = solve_configured::<Puzzle>(Puzzle::EXAMPLE, &[2], &params).unwrap();
+        assert_eq!(outputs[0].answer, Answer::Number(1588));
+        params.set("steps_part_2", Param::Count(100));
+        assert_eq!(
+            check_params::<Puzzle>(&params),
+            Err("steps_part_2 must be at most 50".to_string())
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_polymers(seed: u64, size in 1..50usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let (polymer, rules) = Puzzle::parse(&text).unwrap();
+            prop_assert_eq!(polymer.len(), size);
+            let elements = (rules.len() as f64).sqrt() as usize;
+            prop_assert_eq!(elements * elements, rules.len());
+            // with a rule for every pair, an element is inserted between every two
+            for steps in [STEPS_PART_1, STEPS_PART_2] {
+                let counts = step_polymer(&polymer, &rules, steps);
+                let length = (size as u64 - 1) * (1 << steps) + 1;
+                prop_assert_eq!(counts.values().sum::<u64>(), length);
+            }
+            let extremes = solve(&polymer, &rules, STEPS_PART_1);
+            prop_assert!(extremes.most_common.count >= extremes.least_common.count);
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use aoc_common::rand::Rng;
+use aoc_common::{Animation, Generate, Grid, Palette, ParseError, Rgb, Solution, StdRng, Visual};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+const EXTEND: usize = 5;
+
+pub type RiskMap = Grid<u32>;
+
+pub fn parse_risk_map(input: &str) -> Result<RiskMap, ParseError> {
+    let digits = Grid::parse_digits(input)?;
+    if digits.is_empty() {
+        return Err(ParseError::input("Empty grid"));
+    }
+    Ok(digits.map(|&d| u32::from(d)))
+}
+
+// full map made of EXTEND x EXTEND tiles of the original one, risk increasing by one for
+// each tile to the right or below and wrapping back to 1 after 9
+pub fn extend(grid: &RiskMap) -> RiskMap {
+    let (orig_width, orig_height) = (grid.width(), grid.height());
+    let mut extended = Grid::new(orig_width * EXTEND, orig_height * EXTEND, 0);
+    for (x, y) in extended.positions() {
+        let tile_x = (x / orig_width) as u32;
+        let tile_y = (y / orig_height) as u32;
+        let v = grid[(x % orig_width, y % orig_height)] + tile_x + tile_y;
+        extended[(x, y)] = if v > 9 { v - 9 } else { v };
+    }
+    extended
+}
+
+// lowest total risk from a point to every other one
+pub fn lowest_risks(grid: &RiskMap, from: (usize, usize)) -> Grid<u32> {
+    // calculate minimum distances with Djikstra's Shortest Path algorithm
+    // https://brilliant.org/wiki/dijkstras-short-path-finder/
+    let mut dist = Grid::new(grid.width(), grid.height(), u32::MAX);
+    dist[from] = 0;
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut to_visit = BinaryHeap::from_iter(
+        grid.neighbours4(from.0, from.1)
+            .map(|v| Reverse((grid[v], v))),
+    );
+    to_visit.push(Reverse((0, from)));
+    while let Some(Reverse((_, v))) = to_visit.pop() {
+        if !visited.insert(v) {
+            // already visited this point
+            continue;
+        }
+        for adj in grid.neighbours4(v.0, v.1) {
+            let alt = dist[v] + grid[adj];
+            to_visit.push(Reverse((alt, adj)));
+            if alt < dist[adj] {
+                dist[adj] = alt
+            }
+        }
+    }
+    dist
+}
+
+// find shortest path between two points
+pub fn shortest_path_weight(grid: &RiskMap, from: (usize, usize), to: (usize, usize)) -> u32 {
+    lowest_risks(grid, from)[to]
+}
+
+// positions along a path of lowest risk from the top left corner to the bottom right one,
+// walked back from the end through the neighbours the lowest risk came from
+pub fn lowest_risk_path(grid: &RiskMap) -> Vec<(usize, usize)> {
+    let risks = lowest_risks(grid, (0, 0));
+    let mut path = vec![(grid.width() - 1, grid.height() - 1)];
+    while let Some(&p) = path.last().filter(|&&p| p != (0, 0)) {
+        let previous = grid
+            .neighbours4(p.0, p.1)
+            .find(|&n| risks[n].checked_add(grid[p]) == Some(risks[p]))
+            .unwrap();
+        path.push(previous);
+    }
+    path.reverse();
+    path
+}
+
+// weight of the path from the top left corner to the bottom right one
+pub fn lowest_total_risk(grid: &RiskMap) -> u32 {
+    shortest_path_weight(grid, (0, 0), (grid.width() - 1, grid.height() - 1))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = RiskMap;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    const EXAMPLE: &'static str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    fn parse(input: &str) -> Result<RiskMap, ParseError> {
+        parse_risk_map(input)
+    }
+
+    fn part1(grid: &RiskMap) -> u32 {
+        lowest_total_risk(grid)
+    }
+
+    fn part2(grid: &RiskMap) -> u32 {
+        lowest_total_risk(&extend(grid))
+    }
+}
+
+// the full map, darker where riskier, and a path of lowest risk across it in red
+impl Visual for Puzzle {
+    fn frames(grid: &RiskMap) -> Animation {
+        let grid = extend(grid);
+        let palette = Palette::gradient(Rgb(230, 230, 230), Rgb(30, 30, 30), 10);
+        let mut image = palette.paint(&grid.map(|&risk| risk as usize));
+        for (x, y) in lowest_risk_path(&grid) {
+            image.set(x, y, Rgb::RED);
+        }
+        Animation::still(image)
+    }
+}
+
+// a `size` x `size` map of risks from 1 to 9, at least 1 x 1
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let side = size.max(1);
+        Grid::new(side, side, 0)
+            .map(|_| rng.gen_range(1..=9))
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_grid() {
+        assert_eq!(
+            parse_risk_map(Puzzle::EXAMPLE).unwrap(),
+            Grid::from_cells(
+                10,
+                vec![
+                    1, 1, 6, 3, 7, 5, 1, 7, 4, 2, 1, 3, 8, 1, 3, 7, 3, 6, 7, 2, 2, 1, 3, 6, 5, 1,
+                    1, 3, 2, 8, 3, 6, 9, 4, 9, 3, 1, 5, 6, 9, 7, 4, 6, 3, 4, 1, 7, 1, 1, 1, 1, 3,
+                    1, 9, 1, 2, 8, 1, 3, 7, 1, 3, 5, 9, 9, 1, 2, 4, 2, 1, 3, 1, 2, 5, 4, 2, 1, 6,
+                    3, 9, 1, 2, 9, 3, 1, 3, 8, 5, 2, 1, 2, 3, 1, 1, 9, 4, 4, 5, 8, 1,
+                ]
+            )
+        )
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let grid = parse_risk_map(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(shortest_path_weight(&grid, (0, 0), (9, 9)), 40);
+        assert_eq!(shortest_path_weight(&grid, (0, 0), (0, 2)), 3);
+        assert_eq!(lowest_total_risk(&extend(&grid)), 315);
+        assert_eq!(lowest_total_risk(&parse_risk_map("1191").unwrap()), 11);
+        assert_eq!(Puzzle::parse("").unwrap_err().to_string(), "Empty grid");
+    }
+
+    #[test]
+    fn test_lowest_risk_path() {
+        let grid = parse_risk_map(Puzzle::EXAMPLE).unwrap();
+        let path = lowest_risk_path(&grid);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(9, 9)));
+        // the risk of the starting position isn't counted
+        assert_eq!(path[1..].iter().map(|&p| grid[p]).sum::<u32>(), 40);
+        assert_eq!(
+            lowest_risk_path(&parse_risk_map("1191").unwrap()),
+            [(0, 0), (1, 0), (2, 0), (3, 0)]
+        );
+    }
+
+    #[test]
+    fn test_extend_grid() {
+        let grid = parse_risk_map(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(
+            extend(&grid),
+            Grid::from_cells(
+                50,
+                vec![
+                    1, 1, 6, 3, 7, 5, 1, 7, 4, 2, 2, 2, 7, 4, 8, 6, 2, 8, 5, 3, 3, 3, 8, 5, 9, 7,
+                    3, 9, 6, 4, 4, 4, 9, 6, 1, 8, 4, 1, 7, 5, 5, 5, 1, 7, 2, 9, 5, 2, 8, 6, 1, 3,
+                    8, 1, 3, 7, 3, 6, 7, 2, 2, 4, 9, 2, 4, 8, 4, 7, 8, 3, 3, 5, 1, 3, 5, 9, 5, 8,
+                    9, 4, 4, 6, 2, 4, 6, 1, 6, 9, 1, 5, 5, 7, 3, 5, 7, 2, 7, 1, 2, 6, 2, 1, 3, 6,
+                    5, 1, 1, 3, 2, 8, 3, 2, 4, 7, 6, 2, 2, 4, 3, 9, 4, 3, 5, 8, 7, 3, 3, 5, 4, 1,
+                    5, 4, 6, 9, 8, 4, 4, 6, 5, 2, 6, 5, 7, 1, 9, 5, 5, 7, 6, 3, 3, 6, 9, 4, 9, 3,
+                    1, 5, 6, 9, 4, 7, 1, 5, 1, 4, 2, 6, 7, 1, 5, 8, 2, 6, 2, 5, 3, 7, 8, 2, 6, 9,
+                    3, 7, 3, 6, 4, 8, 9, 3, 7, 1, 4, 8, 4, 7, 5, 9, 1, 4, 7, 4, 6, 3, 4, 1, 7, 1,
+                    1, 1, 8, 5, 7, 4, 5, 2, 8, 2, 2, 2, 9, 6, 8, 5, 6, 3, 9, 3, 3, 3, 1, 7, 9, 6,
+                    7, 4, 1, 4, 4, 4, 2, 8, 1, 7, 8, 5, 2, 5, 5, 5, 1, 3, 1, 9, 1, 2, 8, 1, 3, 7,
+                    2, 4, 2, 1, 2, 3, 9, 2, 4, 8, 3, 5, 3, 2, 3, 4, 1, 3, 5, 9, 4, 6, 4, 3, 4, 5,
+                    2, 4, 6, 1, 5, 7, 5, 4, 5, 6, 3, 5, 7, 2, 1, 3, 5, 9, 9, 1, 2, 4, 2, 1, 2, 4,
+                    6, 1, 1, 2, 3, 5, 3, 2, 3, 5, 7, 2, 2, 3, 4, 6, 4, 3, 4, 6, 8, 3, 3, 4, 5, 7,
+                    5, 4, 5, 7, 9, 4, 4, 5, 6, 8, 6, 5, 3, 1, 2, 5, 4, 2, 1, 6, 3, 9, 4, 2, 3, 6,
+                    5, 3, 2, 7, 4, 1, 5, 3, 4, 7, 6, 4, 3, 8, 5, 2, 6, 4, 5, 8, 7, 5, 4, 9, 6, 3,
+                    7, 5, 6, 9, 8, 6, 5, 1, 7, 4, 1, 2, 9, 3, 1, 3, 8, 5, 2, 1, 2, 3, 1, 4, 2, 4,
+                    9, 6, 3, 2, 3, 4, 2, 5, 3, 5, 1, 7, 4, 3, 4, 5, 3, 6, 4, 6, 2, 8, 5, 4, 5, 6,
+                    4, 7, 5, 7, 3, 9, 6, 5, 2, 3, 1, 1, 9, 4, 4, 5, 8, 1, 3, 4, 2, 2, 1, 5, 5, 6,
+                    9, 2, 4, 5, 3, 3, 2, 6, 6, 7, 1, 3, 5, 6, 4, 4, 3, 7, 7, 8, 2, 4, 6, 7, 5, 5,
+                    4, 8, 8, 9, 3, 5, 2, 2, 7, 4, 8, 6, 2, 8, 5, 3, 3, 3, 8, 5, 9, 7, 3, 9, 6, 4,
+                    4, 4, 9, 6, 1, 8, 4, 1, 7, 5, 5, 5, 1, 7, 2, 9, 5, 2, 8, 6, 6, 6, 2, 8, 3, 1,
+                    6, 3, 9, 7, 2, 4, 9, 2, 4, 8, 4, 7, 8, 3, 3, 5, 1, 3, 5, 9, 5, 8, 9, 4, 4, 6,
+                    2, 4, 6, 1, 6, 9, 1, 5, 5, 7, 3, 5, 7, 2, 7, 1, 2, 6, 6, 8, 4, 6, 8, 3, 8, 2,
+                    3, 7, 3, 2, 4, 7, 6, 2, 2, 4, 3, 9, 4, 3, 5, 8, 7, 3, 3, 5, 4, 1, 5, 4, 6, 9,
+                    8, 4, 4, 6, 5, 2, 6, 5, 7, 1, 9, 5, 5, 7, 6, 3, 7, 6, 8, 2, 1, 6, 6, 8, 7, 4,
+                    4, 7, 1, 5, 1, 4, 2, 6, 7, 1, 5, 8, 2, 6, 2, 5, 3, 7, 8, 2, 6, 9, 3, 7, 3, 6,
+                    4, 8, 9, 3, 7, 1, 4, 8, 4, 7, 5, 9, 1, 4, 8, 2, 5, 9, 5, 8, 6, 1, 2, 5, 8, 5,
+                    7, 4, 5, 2, 8, 2, 2, 2, 9, 6, 8, 5, 6, 3, 9, 3, 3, 3, 1, 7, 9, 6, 7, 4, 1, 4,
+                    4, 4, 2, 8, 1, 7, 8, 5, 2, 5, 5, 5, 3, 9, 2, 8, 9, 6, 3, 6, 6, 6, 2, 4, 2, 1,
+                    2, 3, 9, 2, 4, 8, 3, 5, 3, 2, 3, 4, 1, 3, 5, 9, 4, 6, 4, 3, 4, 5, 2, 4, 6, 1,
+                    5, 7, 5, 4, 5, 6, 3, 5, 7, 2, 6, 8, 6, 5, 6, 7, 4, 6, 8, 3, 2, 4, 6, 1, 1, 2,
+                    3, 5, 3, 2, 3, 5, 7, 2, 2, 3, 4, 6, 4, 3, 4, 6, 8, 3, 3, 4, 5, 7, 5, 4, 5, 7,
+                    9, 4, 4, 5, 6, 8, 6, 5, 6, 8, 1, 5, 5, 6, 7, 9, 7, 6, 4, 2, 3, 6, 5, 3, 2, 7,
+                    4, 1, 5, 3, 4, 7, 6, 4, 3, 8, 5, 2, 6, 4, 5, 8, 7, 5, 4, 9, 6, 3, 7, 5, 6, 9,
+                    8, 6, 5, 1, 7, 4, 8, 6, 7, 1, 9, 7, 6, 2, 8, 5, 2, 3, 1, 4, 2, 4, 9, 6, 3, 2,
+                    3, 4, 2, 5, 3, 5, 1, 7, 4, 3, 4, 5, 3, 6, 4, 6, 2, 8, 5, 4, 5, 6, 4, 7, 5, 7,
+                    3, 9, 6, 5, 6, 7, 5, 8, 6, 8, 4, 1, 7, 6, 3, 4, 2, 2, 1, 5, 5, 6, 9, 2, 4, 5,
+                    3, 3, 2, 6, 6, 7, 1, 3, 5, 6, 4, 4, 3, 7, 7, 8, 2, 4, 6, 7, 5, 5, 4, 8, 8, 9,
+                    3, 5, 7, 8, 6, 6, 5, 9, 9, 1, 4, 6, 3, 3, 8, 5, 9, 7, 3, 9, 6, 4, 4, 4, 9, 6,
+                    1, 8, 4, 1, 7, 5, 5, 5, 1, 7, 2, 9, 5, 2, 8, 6, 6, 6, 2, 8, 3, 1, 6, 3, 9, 7,
+                    7, 7, 3, 9, 4, 2, 7, 4, 1, 8, 3, 5, 1, 3, 5, 9, 5, 8, 9, 4, 4, 6, 2, 4, 6, 1,
+                    6, 9, 1, 5, 5, 7, 3, 5, 7, 2, 7, 1, 2, 6, 6, 8, 4, 6, 8, 3, 8, 2, 3, 7, 7, 9,
+                    5, 7, 9, 4, 9, 3, 4, 8, 4, 3, 5, 8, 7, 3, 3, 5, 4, 1, 5, 4, 6, 9, 8, 4, 4, 6,
+                    5, 2, 6, 5, 7, 1, 9, 5, 5, 7, 6, 3, 7, 6, 8, 2, 1, 6, 6, 8, 7, 4, 8, 7, 9, 3,
+                    2, 7, 7, 9, 8, 5, 5, 8, 2, 6, 2, 5, 3, 7, 8, 2, 6, 9, 3, 7, 3, 6, 4, 8, 9, 3,
+                    7, 1, 4, 8, 4, 7, 5, 9, 1, 4, 8, 2, 5, 9, 5, 8, 6, 1, 2, 5, 9, 3, 6, 1, 6, 9,
+                    7, 2, 3, 6, 9, 6, 8, 5, 6, 3, 9, 3, 3, 3, 1, 7, 9, 6, 7, 4, 1, 4, 4, 4, 2, 8,
+                    1, 7, 8, 5, 2, 5, 5, 5, 3, 9, 2, 8, 9, 6, 3, 6, 6, 6, 4, 1, 3, 9, 1, 7, 4, 7,
+                    7, 7, 3, 5, 3, 2, 3, 4, 1, 3, 5, 9, 4, 6, 4, 3, 4, 5, 2, 4, 6, 1, 5, 7, 5, 4,
+                    5, 6, 3, 5, 7, 2, 6, 8, 6, 5, 6, 7, 4, 6, 8, 3, 7, 9, 7, 6, 7, 8, 5, 7, 9, 4,
+                    3, 5, 7, 2, 2, 3, 4, 6, 4, 3, 4, 6, 8, 3, 3, 4, 5, 7, 5, 4, 5, 7, 9, 4, 4, 5,
+                    6, 8, 6, 5, 6, 8, 1, 5, 5, 6, 7, 9, 7, 6, 7, 9, 2, 6, 6, 7, 8, 1, 8, 7, 5, 3,
+                    4, 7, 6, 4, 3, 8, 5, 2, 6, 4, 5, 8, 7, 5, 4, 9, 6, 3, 7, 5, 6, 9, 8, 6, 5, 1,
+                    7, 4, 8, 6, 7, 1, 9, 7, 6, 2, 8, 5, 9, 7, 8, 2, 1, 8, 7, 3, 9, 6, 3, 4, 2, 5,
+                    3, 5, 1, 7, 4, 3, 4, 5, 3, 6, 4, 6, 2, 8, 5, 4, 5, 6, 4, 7, 5, 7, 3, 9, 6, 5,
+                    6, 7, 5, 8, 6, 8, 4, 1, 7, 6, 7, 8, 6, 9, 7, 9, 5, 2, 8, 7, 4, 5, 3, 3, 2, 6,
+                    6, 7, 1, 3, 5, 6, 4, 4, 3, 7, 7, 8, 2, 4, 6, 7, 5, 5, 4, 8, 8, 9, 3, 5, 7, 8,
+                    6, 6, 5, 9, 9, 1, 4, 6, 8, 9, 7, 7, 6, 1, 1, 2, 5, 7, 4, 4, 9, 6, 1, 8, 4, 1,
+                    7, 5, 5, 5, 1, 7, 2, 9, 5, 2, 8, 6, 6, 6, 2, 8, 3, 1, 6, 3, 9, 7, 7, 7, 3, 9,
+                    4, 2, 7, 4, 1, 8, 8, 8, 4, 1, 5, 3, 8, 5, 2, 9, 4, 6, 2, 4, 6, 1, 6, 9, 1, 5,
+                    5, 7, 3, 5, 7, 2, 7, 1, 2, 6, 6, 8, 4, 6, 8, 3, 8, 2, 3, 7, 7, 9, 5, 7, 9, 4,
+                    9, 3, 4, 8, 8, 1, 6, 8, 1, 5, 1, 4, 5, 9, 5, 4, 6, 9, 8, 4, 4, 6, 5, 2, 6, 5,
+                    7, 1, 9, 5, 5, 7, 6, 3, 7, 6, 8, 2, 1, 6, 6, 8, 7, 4, 8, 7, 9, 3, 2, 7, 7, 9,
+                    8, 5, 9, 8, 1, 4, 3, 8, 8, 1, 9, 6, 6, 9, 3, 7, 3, 6, 4, 8, 9, 3, 7, 1, 4, 8,
+                    4, 7, 5, 9, 1, 4, 8, 2, 5, 9, 5, 8, 6, 1, 2, 5, 9, 3, 6, 1, 6, 9, 7, 2, 3, 6,
+                    1, 4, 7, 2, 7, 1, 8, 3, 4, 7, 1, 7, 9, 6, 7, 4, 1, 4, 4, 4, 2, 8, 1, 7, 8, 5,
+                    2, 5, 5, 5, 3, 9, 2, 8, 9, 6, 3, 6, 6, 6, 4, 1, 3, 9, 1, 7, 4, 7, 7, 7, 5, 2,
+                    4, 1, 2, 8, 5, 8, 8, 8, 4, 6, 4, 3, 4, 5, 2, 4, 6, 1, 5, 7, 5, 4, 5, 6, 3, 5,
+                    7, 2, 6, 8, 6, 5, 6, 7, 4, 6, 8, 3, 7, 9, 7, 6, 7, 8, 5, 7, 9, 4, 8, 1, 8, 7,
+                    8, 9, 6, 8, 1, 5, 4, 6, 8, 3, 3, 4, 5, 7, 5, 4, 5, 7, 9, 4, 4, 5, 6, 8, 6, 5,
+                    6, 8, 1, 5, 5, 6, 7, 9, 7, 6, 7, 9, 2, 6, 6, 7, 8, 1, 8, 7, 8, 1, 3, 7, 7, 8,
+                    9, 2, 9, 8, 6, 4, 5, 8, 7, 5, 4, 9, 6, 3, 7, 5, 6, 9, 8, 6, 5, 1, 7, 4, 8, 6,
+                    7, 1, 9, 7, 6, 2, 8, 5, 9, 7, 8, 2, 1, 8, 7, 3, 9, 6, 1, 8, 9, 3, 2, 9, 8, 4,
+                    1, 7, 4, 5, 3, 6, 4, 6, 2, 8, 5, 4, 5, 6, 4, 7, 5, 7, 3, 9, 6, 5, 6, 7, 5, 8,
+                    6, 8, 4, 1, 7, 6, 7, 8, 6, 9, 7, 9, 5, 2, 8, 7, 8, 9, 7, 1, 8, 1, 6, 3, 9, 8,
+                    5, 6, 4, 4, 3, 7, 7, 8, 2, 4, 6, 7, 5, 5, 4, 8, 8, 9, 3, 5, 7, 8, 6, 6, 5, 9,
+                    9, 1, 4, 6, 8, 9, 7, 7, 6, 1, 1, 2, 5, 7, 9, 1, 8, 8, 7, 2, 2, 3, 6, 8, 5, 5,
+                    1, 7, 2, 9, 5, 2, 8, 6, 6, 6, 2, 8, 3, 1, 6, 3, 9, 7, 7, 7, 3, 9, 4, 2, 7, 4,
+                    1, 8, 8, 8, 4, 1, 5, 3, 8, 5, 2, 9, 9, 9, 5, 2, 6, 4, 9, 6, 3, 1, 5, 7, 3, 5,
+                    7, 2, 7, 1, 2, 6, 6, 8, 4, 6, 8, 3, 8, 2, 3, 7, 7, 9, 5, 7, 9, 4, 9, 3, 4, 8,
+                    8, 1, 6, 8, 1, 5, 1, 4, 5, 9, 9, 2, 7, 9, 2, 6, 2, 5, 6, 1, 6, 5, 7, 1, 9, 5,
+                    5, 7, 6, 3, 7, 6, 8, 2, 1, 6, 6, 8, 7, 4, 8, 7, 9, 3, 2, 7, 7, 9, 8, 5, 9, 8,
+                    1, 4, 3, 8, 8, 1, 9, 6, 1, 9, 2, 5, 4, 9, 9, 2, 1, 7, 7, 1, 4, 8, 4, 7, 5, 9,
+                    1, 4, 8, 2, 5, 9, 5, 8, 6, 1, 2, 5, 9, 3, 6, 1, 6, 9, 7, 2, 3, 6, 1, 4, 7, 2,
+                    7, 1, 8, 3, 4, 7, 2, 5, 8, 3, 8, 2, 9, 4, 5, 8, 2, 8, 1, 7, 8, 5, 2, 5, 5, 5,
+                    3, 9, 2, 8, 9, 6, 3, 6, 6, 6, 4, 1, 3, 9, 1, 7, 4, 7, 7, 7, 5, 2, 4, 1, 2, 8,
+                    5, 8, 8, 8, 6, 3, 5, 2, 3, 9, 6, 9, 9, 9, 5, 7, 5, 4, 5, 6, 3, 5, 7, 2, 6, 8,
+                    6, 5, 6, 7, 4, 6, 8, 3, 7, 9, 7, 6, 7, 8, 5, 7, 9, 4, 8, 1, 8, 7, 8, 9, 6, 8,
+                    1, 5, 9, 2, 9, 8, 9, 1, 7, 9, 2, 6, 5, 7, 9, 4, 4, 5, 6, 8, 6, 5, 6, 8, 1, 5,
+                    5, 6, 7, 9, 7, 6, 7, 9, 2, 6, 6, 7, 8, 1, 8, 7, 8, 1, 3, 7, 7, 8, 9, 2, 9, 8,
+                    9, 2, 4, 8, 8, 9, 1, 3, 1, 9, 7, 5, 6, 9, 8, 6, 5, 1, 7, 4, 8, 6, 7, 1, 9, 7,
+                    6, 2, 8, 5, 9, 7, 8, 2, 1, 8, 7, 3, 9, 6, 1, 8, 9, 3, 2, 9, 8, 4, 1, 7, 2, 9,
+                    1, 4, 3, 1, 9, 5, 2, 8, 5, 6, 4, 7, 5, 7, 3, 9, 6, 5, 6, 7, 5, 8, 6, 8, 4, 1,
+                    7, 6, 7, 8, 6, 9, 7, 9, 5, 2, 8, 7, 8, 9, 7, 1, 8, 1, 6, 3, 9, 8, 9, 1, 8, 2,
+                    9, 2, 7, 4, 1, 9, 6, 7, 5, 5, 4, 8, 8, 9, 3, 5, 7, 8, 6, 6, 5, 9, 9, 1, 4, 6,
+                    8, 9, 7, 7, 6, 1, 1, 2, 5, 7, 9, 1, 8, 8, 7, 2, 2, 3, 6, 8, 1, 2, 9, 9, 8, 3,
+                    3, 4, 7, 9,
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let grid = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&grid), 40);
+        assert_eq!(Puzzle::part2(&grid), 315);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn prop_random_maps(seed: u64, size in 1..20usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let grid = Puzzle::parse(&text).unwrap();
+            prop_assert_eq!(grid.to_string(), text);
+            // the path found has the lowest risk, of at least 1 for each step
+            let path = lowest_risk_path(&grid);
+            let risk = path[1..].iter().map(|&p| grid[p]).sum::<u32>();
+            prop_assert_eq!(Puzzle::part1(&grid), risk);
+            prop_assert!(risk as usize >= 2 * (size - 1));
+            prop_assert!(Puzzle::part2(&grid) as usize >= 2 * (EXTEND * size - 1));
+        }
+    }
+}
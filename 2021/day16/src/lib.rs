@@ -0,0 +1,742 @@
+use aoc_common::rand::Rng;
+use aoc_common::{Generate, ParseError, Solution, StdRng};
+use std::str::Chars;
+
+#[derive(Debug, PartialEq)]
+pub enum Type {
+    Literal(u64),
+    OpEqual(Vec<Packet>),
+    OpGreater(Vec<Packet>),
+    OpLess(Vec<Packet>),
+    OpMax(Vec<Packet>),
+    OpMin(Vec<Packet>),
+    OpProduct(Vec<Packet>),
+    OpSum(Vec<Packet>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Packet {
+    version: u8,
+    ptype: Type,
+}
+
+impl Packet {
+    pub fn total_version(&self) -> u32 {
+        match &self.ptype {
+            Type::Literal(_) => self.version.into(),
+            Type::OpEqual(packets)
+            | Type::OpGreater(packets)
+            | Type::OpLess(packets)
+            | Type::OpMax(packets)
+            | Type::OpMin(packets)
+            | Type::OpProduct(packets)
+            | Type::OpSum(packets) => {
+                self.version as u32 + packets.iter().map(|p| p.total_version()).sum::<u32>()
+            }
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        match &self.ptype {
+            Type::Literal(v) => *v,
+            Type::OpEqual(packets) => {
+                if packets[0].value() == packets[1].value() {
+                    1
+                } else {
+                    0
+                }
+            }
+            Type::OpGreater(packets) => {
+                if packets[0].value() > packets[1].value() {
+                    1
+                } else {
+                    0
+                }
+            }
+            Type::OpLess(packets) => {
+                if packets[0].value() < packets[1].value() {
+                    1
+                } else {
+                    0
+                }
+            }
+            Type::OpMax(packets) => packets
+                .iter()
+                .map(|p| p.value())
+                .max()
+                .expect("Max operator packet with no subpackets"),
+            Type::OpMin(packets) => packets
+                .iter()
+                .map(|p| p.value())
+                .min()
+                .expect("Min operator packet with no subpackets"),
+            Type::OpProduct(packets) => packets.iter().fold(1, |acc, p| acc * p.value()),
+            Type::OpSum(packets) => packets.iter().fold(0, |acc, p| acc + p.value()),
+        }
+    }
+}
+
+// appends the lowest `count` bits of the value, highest first
+fn push_bits(bits: &mut Vec<bool>, value: u64, count: usize) {
+    bits.extend((0..count).rev().map(|i| value >> i & 1 == 1));
+}
+
+impl Packet {
+    // bits of the packet, sub-packets given by their count for versions 4 to 7 and by
+    // their total length for the others, so that both ways are used
+    fn encode(&self, bits: &mut Vec<bool>) {
+        push_bits(bits, self.version.into(), 3);
+        let (type_id, packets) = match &self.ptype {
+            Type::Literal(v) => {
+                push_bits(bits, 4, 3);
+                let groups = (u64::BITS - v.leading_zeros()).div_ceil(4).max(1);
+                for group in (0..groups).rev() {
+                    bits.push(group > 0);
+                    push_bits(bits, v >> (4 * group), 4);
+                }
+                return;
+            }
+            Type::OpSum(packets) => (0, packets),
+            Type::OpProduct(packets) => (1, packets),
+            Type::OpMin(packets) => (2, packets),
+            Type::OpMax(packets) => (3, packets),
+            Type::OpGreater(packets) => (5, packets),
+            Type::OpLess(packets) => (6, packets),
+            Type::OpEqual(packets) => (7, packets),
+        };
+        push_bits(bits, type_id, 3);
+        let mut sub_bits = vec![];
+        packets.iter().for_each(|p| p.encode(&mut sub_bits));
+        if self.version >= 4 {
+            bits.push(true);
+            push_bits(bits, packets.len() as u64, 11);
+        } else {
+            bits.push(false);
+            push_bits(bits, sub_bits.len() as u64, 15);
+        }
+        bits.extend(sub_bits);
+    }
+
+    // the transmission of the packet in hexadecimal, padded with zeros to whole bytes
+    pub fn to_hex(&self) -> String {
+        let mut bits = vec![];
+        self.encode(&mut bits);
+        bits.resize(bits.len().div_ceil(8) * 8, false);
+        bits.chunks(4)
+            .map(|nibble| {
+                let digit = nibble.iter().fold(0, |acc, &b| acc << 1 | u32::from(b));
+                char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+struct BinaryIter<'a> {
+    chars: Chars<'a>,
+    remaining: Vec<char>,
+    total: usize,
+}
+
+impl<'a> BinaryIter<'a> {
+    fn new(chars: Chars<'a>) -> Self {
+        Self {
+            chars,
+            remaining: vec![],
+            total: 0,
+        }
+    }
+
+    // ignore the remaining bits
+    fn align(&mut self) {
+        self.total += self.remaining.len();
+        self.remaining.clear();
+        // need to align by full bytes, so we might need to consume the next hex character
+        // (4 bits)
+        if !self.total.is_multiple_of(8) {
+            self.chars.next();
+            self.total += 4;
+        }
+    }
+
+    // reads the next n bits and transforms them into an u64
+    fn combine(&mut self, n: usize) -> Option<u64> {
+        if n > 64 {
+            panic!("Cannot combine more than 64-bits");
+        }
+        if let Some(bits) = self.group(n) {
+            let v = u64::from_str_radix(&bits.into_iter().collect::<String>(), 2).unwrap();
+            Some(v)
+        } else {
+            None
+        }
+    }
+
+    // reads the next n bits
+    fn group(&mut self, n: usize) -> Option<Vec<char>> {
+        let mut bits: Vec<char> = vec![];
+        let mut i = n;
+        while i > 0 {
+            if let Some(b) = self.next() {
+                bits.push(b);
+            }
+            i -= 1;
+        }
+        if bits.is_empty() {
+            None
+        } else {
+            Some(bits)
+        }
+    }
+}
+
+impl Iterator for BinaryIter<'_> {
+    type Item = char;
+    fn next(&mut self) -> Option<<Self>::Item> {
+        if self.remaining.is_empty() {
+            if let Some(c) = self.chars.next() {
+                let v = c.to_digit(16).expect("Invalid digit");
+                self.remaining.extend(format!("{:04b}", v).chars().rev());
+            }
+        }
+        if let Some(c) = self.remaining.pop() {
+            self.total += 1;
+            return Some(c);
+        }
+        None
+    }
+}
+
+struct PacketIter<'a> {
+    // iter: Box<dyn Iterator<Item = &'a str>>,
+    bits: BinaryIter<'a>,
+    main_packet: bool,
+}
+
+impl<'a> PacketIter<'a> {
+    fn new(bits: BinaryIter<'a>) -> Self {
+        Self {
+            bits,
+            main_packet: true,
+        }
+    }
+
+    fn parse_literal(&mut self) -> Type {
+        let mut literal_value: Vec<char> = vec![];
+        while let Some(group) = self.bits.group(5) {
+            literal_value.extend(&group[1..]);
+            if group[0] == '0' {
+                break;
+            }
+        }
+        if self.main_packet {
+            self.bits.align();
+        }
+        let v = u64::from_str_radix(&literal_value.into_iter().collect::<String>(), 2).unwrap();
+        Type::Literal(v)
+    }
+
+    fn parse_operator(&mut self, ptype: u8) -> Type {
+        let mut packets: Vec<Packet> = vec![];
+        let was_main_packet = self.main_packet;
+        // avoid aligning bits when parsing subpackets
+        self.main_packet = false;
+        match self.bits.next() {
+            Some('0') => {
+                let total_length = self.bits.combine(15).unwrap() as usize;
+                let orig = self.bits.total;
+                while self.bits.total - orig < total_length {
+                    packets.push(self.next().unwrap());
+                }
+            }
+            Some('1') => {
+                let num_packets = self.bits.combine(11).unwrap();
+                for _ in 0..num_packets {
+                    packets.push(self.next().unwrap());
+                }
+            }
+            Some(_) => panic!("Non-binary digit"),
+            None => panic!("Invalid operator packet: missing length type ID"),
+        }
+        self.main_packet = was_main_packet;
+        if self.main_packet {
+            self.bits.align();
+        }
+        match ptype {
+            0 => Type::OpSum(packets),
+            1 => Type::OpProduct(packets),
+            2 => Type::OpMin(packets),
+            3 => Type::OpMax(packets),
+            5 => Type::OpGreater(packets),
+            6 => Type::OpLess(packets),
+            7 => Type::OpEqual(packets),
+            _ => panic!("Invalid packet type for Operator packet"),
+        }
+    }
+}
+
+impl Iterator for PacketIter<'_> {
+    type Item = Packet;
+    fn next(&mut self) -> Option<<Self>::Item> {
+        if let Some(version) = self.bits.combine(3) {
+            let ptype = match self.bits.combine(3) {
+                Some(4) => self.parse_literal(),
+                Some(t) => self.parse_operator(t as u8),
+                _ => panic!("Invalid package type"),
+            };
+            return Some(Packet {
+                version: version as u8,
+                ptype,
+            });
+        }
+        None
+    }
+}
+
+trait Packets {
+    fn packets(&self) -> PacketIter<'_>;
+}
+
+impl Packets for &str {
+    fn packets(&self) -> PacketIter<'_> {
+        PacketIter::new(BinaryIter::new(self.chars()))
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Packet>;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    const EXAMPLE: &'static str = "9C0141080250320F1802104A08";
+
+    fn parse(input: &str) -> Result<Vec<Packet>, ParseError> {
+        let line = input.trim();
+        if let Some(i) = line.find(|c: char| !c.is_ascii_hexdigit()) {
+            let c = line[i..].chars().next().unwrap();
+            return Err(ParseError::new(
+                line,
+                &line[i..i + c.len_utf8()],
+                "Invalid hexadecimal digit",
+            )
+            .at_line(1));
+        }
+        let packets: Vec<Packet> = line.packets().collect();
+        if packets.is_empty() {
+            return Err(ParseError::input("No packets in transmission"));
+        }
+        Ok(packets)
+    }
+
+    fn part1(packets: &Vec<Packet>) -> u32 {
+        packets.iter().map(|p| p.total_version()).sum::<u32>()
+    }
+
+    fn part2(packets: &Vec<Packet>) -> u64 {
+        packets[0].value()
+    }
+}
+
+// largest number whose nth power is at most max
+fn integer_root(max: u64, n: u32) -> u64 {
+    let mut root = (max as f64).powf(1.0 / f64::from(n)) as u64;
+    while root.checked_pow(n).is_none_or(|p| p > max) {
+        root -= 1;
+    }
+    root
+}
+
+// packets nested up to `depth` levels, the value of the packet being at most `max` so
+// that sums and products don't overflow
+fn random_packet(rng: &mut StdRng, depth: usize, max: u64) -> Packet {
+    let type_id = if depth == 0 { 4 } else { rng.gen_range(0..8) };
+    let count = rng.gen_range(1..=3);
+    let mut packets = |count: usize, max: u64| -> Vec<Packet> {
+        (0..count)
+            .map(|_| random_packet(rng, depth - 1, max))
+            .collect()
+    };
+    let ptype = match type_id {
+        0 => Type::OpSum(packets(count, max / count as u64)),
+        1 => Type::OpProduct(packets(count, integer_root(max, count as u32))),
+        2 => Type::OpMin(packets(count, max)),
+        3 => Type::OpMax(packets(count, max)),
+        5 => Type::OpGreater(packets(2, max)),
+        6 => Type::OpLess(packets(2, max)),
+        7 => Type::OpEqual(packets(2, max)),
+        _ => Type::Literal(rng.gen_range(0..=max)),
+    };
+    Packet {
+        version: rng.gen_range(0..8),
+        ptype,
+    }
+}
+
+// a transmission of one packet with sub-packets nested up to `size` levels, at most 6 for
+// the lengths of the sub-packets to fit in the 15 bits of their field
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        random_packet(rng, size.min(6), 1 << 40).to_hex()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_binary_iter() {
+        let string = "0123456789ABCDEF";
+        let iter = BinaryIter::new(string.chars());
+        assert_eq!(
+            iter.collect::<Vec<char>>(),
+            vec![
+                '0', '0', '0', '0', '0', '0', '0', '1', '0', '0', '1', '0', '0', '0', '1', '1',
+                '0', '1', '0', '0', '0', '1', '0', '1', '0', '1', '1', '0', '0', '1', '1', '1',
+                '1', '0', '0', '0', '1', '0', '0', '1', '1', '0', '1', '0', '1', '0', '1', '1',
+                '1', '1', '0', '0', '1', '1', '0', '1', '1', '1', '1', '0', '1', '1', '1', '1'
+            ]
+        )
+    }
+
+    #[test]
+    fn test_binary_iter_combine() {
+        let string = "FFFF";
+        let mut iter = BinaryIter::new(string.chars());
+        assert_eq!(iter.combine(1), Some(1));
+        assert_eq!(iter.combine(2), Some(3));
+        assert_eq!(iter.combine(3), Some(7));
+        assert_eq!(iter.combine(4), Some(15));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "D2FE28".packets().collect::<Vec<Packet>>(),
+            vec![Packet {
+                version: 6,
+                ptype: Type::Literal(2021)
+            }]
+        );
+        assert_eq!(
+            "38006F45291200".packets().collect::<Vec<Packet>>(),
+            vec![Packet {
+                version: 1,
+                ptype: Type::OpLess(vec![
+                    Packet {
+                        version: 6,
+                        ptype: Type::Literal(10)
+                    },
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(20)
+                    }
+                ])
+            }]
+        );
+        assert_eq!(
+            "EE00D40C823060".packets().collect::<Vec<Packet>>(),
+            vec![Packet {
+                version: 7,
+                ptype: Type::OpMax(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1)
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2)
+                    },
+                    Packet {
+                        version: 1,
+                        ptype: Type::Literal(3)
+                    }
+                ])
+            }]
+        );
+    }
+
+    #[test]
+    fn test_total_version() {
+        let literal = Packet {
+            version: 6,
+            ptype: Type::Literal(2021),
+        };
+        assert_eq!(literal.total_version(), 6);
+        let operator = Packet {
+            version: 7,
+            ptype: Type::OpSum(vec![
+                Packet {
+                    version: 2,
+                    ptype: Type::Literal(1),
+                },
+                Packet {
+                    version: 4,
+                    ptype: Type::Literal(2),
+                },
+                Packet {
+                    version: 1,
+                    ptype: Type::Literal(3),
+                },
+            ]),
+        };
+        assert_eq!(operator.total_version(), 14);
+    }
+
+    #[test]
+    fn test_value_equal() {
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpEqual(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                ])
+            }
+            .value(),
+            0
+        );
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpEqual(vec![
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(2),
+                    },
+                ])
+            }
+            .value(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_value_greater() {
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpGreater(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                ])
+            }
+            .value(),
+            0
+        );
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpGreater(vec![
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                ])
+            }
+            .value(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_value_less() {
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpLess(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                ])
+            }
+            .value(),
+            1
+        );
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpLess(vec![
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                ])
+            }
+            .value(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_value_max() {
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpMax(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                    Packet {
+                        version: 1,
+                        ptype: Type::Literal(4),
+                    },
+                ])
+            }
+            .value(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_value_min() {
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpMin(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                    Packet {
+                        version: 1,
+                        ptype: Type::Literal(4),
+                    },
+                ])
+            }
+            .value(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_value_product() {
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpProduct(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                    Packet {
+                        version: 1,
+                        ptype: Type::Literal(4),
+                    },
+                ])
+            }
+            .value(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_value_sum() {
+        assert_eq!(
+            Packet {
+                version: 0,
+                ptype: Type::OpSum(vec![
+                    Packet {
+                        version: 2,
+                        ptype: Type::Literal(1),
+                    },
+                    Packet {
+                        version: 4,
+                        ptype: Type::Literal(2),
+                    },
+                    Packet {
+                        version: 1,
+                        ptype: Type::Literal(4),
+                    },
+                ])
+            }
+            .value(),
+            7
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Puzzle::parse("D2FG28\n").unwrap_err().to_string(),
+            "line 1, column 4: Invalid hexadecimal digit \"G\""
+        );
+        assert_eq!(
+            Puzzle::parse("\n").unwrap_err().to_string(),
+            "No packets in transmission"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        // 1 + 3 = 2 * 2
+        let transmission = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&transmission), 20);
+        assert_eq!(Puzzle::part2(&transmission), 1);
+    }
+
+    #[test]
+    fn test_to_hex() {
+        for hex in ["D2FE28", "38006F45291200", "EE00D40C823060"] {
+            assert_eq!(hex.packets().next().unwrap().to_hex(), hex);
+        }
+        assert_eq!(integer_root(1 << 40, 2), 1 << 20);
+        assert_eq!(integer_root(26, 3), 2);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_transmissions(seed: u64, size in 0..7usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let packets = Puzzle::parse(&text).unwrap();
+            prop_assert_eq!(packets.len(), 1);
+            prop_assert_eq!(packets[0].to_hex(), text);
+            prop_assert!(Puzzle::part2(&packets) <= 1 << 40);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use aoc_common::rand::Rng;
+use aoc_common::{parse_token, BoundingBox, Generate, Output, ParseError, Point, Solution, StdRng};
+use serde::Serialize;
+
+pub type Area = BoundingBox;
+
+// horizontal and vertical speed of the probe, positive towards the right and the top
+pub type Velocity = Point;
+
+// the launch reaching the highest position while still hitting the target
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Launch {
+    velocity: Velocity,
+    #[serde(skip)]
+    height: i64,
+}
+
+impl From<Launch> for Output {
+    fn from(launch: Launch) -> Self {
+        Output::with_extra(launch.height, &launch)
+    }
+}
+
+pub fn hits_target(target: &Area, mut vel: Velocity) -> bool {
+    let mut pos = Point::ORIGIN;
+    while pos.y > target.min.y {
+        pos += vel;
+        // drag slows the probe down horizontally while gravity pulls it down
+        vel -= Point::new(vel.x.signum(), 1);
+        if target.contains(pos) {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn find_velocities(target: &Area) -> Vec<Velocity> {
+    let mut vels = vec![];
+    for x in 1..100 {
+        for y in -200..500 {
+            let vel = Velocity { x, y };
+            if hits_target(target, vel) {
+                vels.push(vel);
+            }
+        }
+    }
+    vels
+}
+
+pub fn highest_position(vel: &Velocity) -> i64 {
+    let mut y = 0;
+    let mut y_vel = vel.y;
+    while y_vel > 0 {
+        y += y_vel;
+        y_vel -= 1;
+    }
+    y
+}
+
+pub fn parse_area(line: &str) -> Result<Area, ParseError> {
+    use regex::Regex;
+    let re = Regex::new(r"target area: x=(\d+)\.\.(\d+), y=(-?\d+)+\.\.(-?\d+)").unwrap();
+    let captures = re.captures(line).ok_or_else(|| {
+        ParseError::new(
+            line,
+            line,
+            "Expected \"target area: x=X1..X2, y=Y1..Y2\", got",
+        )
+    })?;
+    // coordinates far beyond what the probe can reach are rejected
+    let coordinate = |i: usize, message| parse_token::<i32>(line, &captures[i], message);
+    let x_from = coordinate(1, "Invalid x")?;
+    let x_to = coordinate(2, "Invalid x")?;
+    let y_from = coordinate(3, "Invalid y")?;
+    let y_to = coordinate(4, "Invalid y")?;
+    Ok(Area::new(
+        Point::new(x_from.into(), y_from.into()),
+        Point::new(x_to.into(), y_to.into()),
+    ))
+}
+
+// the target area is described in the first line of the input
+pub fn parse_target_area(input: &str) -> Result<Area, ParseError> {
+    let line = input.lines().next().unwrap_or("");
+    parse_area(line).map_err(|e| e.at_line(1))
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Area;
+    type Part1 = Launch;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = "target area: x=20..30, y=-10..-5";
+
+    fn parse(input: &str) -> Result<Area, ParseError> {
+        parse_target_area(input)
+    }
+
+    fn part1(target: &Area) -> Launch {
+        let velocities = find_velocities(target);
+        let best_vel = velocities
+            .into_iter()
+            .max_by(|v1, v2| v1.y.cmp(&v2.y))
+            .unwrap();
+        Launch {
+            height: highest_position(&best_vel),
+            velocity: best_vel,
+        }
+    }
+
+    fn part2(target: &Area) -> usize {
+        find_velocities(target).len()
+    }
+}
+
+// a target of up to `size` x `size` positions, at least 1 x 1, ahead of the launch and
+// below it, among the positions find_velocities reaches in a single step
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let side = size.clamp(1, 20) as i64;
+        let x_from = rng.gen_range(1..=99 - side + 1);
+        let y_to = rng.gen_range(-200 + side - 1..=-1);
+        let x_to = x_from + rng.gen_range(0..side);
+        let y_from = y_to - rng.gen_range(0..side);
+        format!(
+            "target area: x={}..{}, y={}..{}\n",
+            x_from, x_to, y_from, y_to
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    const TARGET: Area = Area {
+        min: Point::new(20, -10),
+        max: Point::new(30, -5),
+    };
+
+    #[test]
+    fn test_area_contains() {
+        assert!(TARGET.contains(Point::new(25, -7)));
+        assert!(TARGET.contains(Point::new(20, -10)));
+        assert!(TARGET.contains(Point::new(30, -5)));
+        assert!(!TARGET.contains(Point::new(0, 0)));
+        assert!(!TARGET.contains(Point::new(25, -12)));
+        assert!(!TARGET.contains(Point::new(25, -2)));
+        assert!(!TARGET.contains(Point::new(15, -7)));
+        assert!(!TARGET.contains(Point::new(35, -7)));
+    }
+
+    #[test]
+    fn test_find_velocities() {
+        assert_eq!(
+            find_velocities(&TARGET),
+            vec![
+                Velocity { x: 6, y: 0 },
+                Velocity { x: 6, y: 1 },
+                Velocity { x: 6, y: 2 },
+                Velocity { x: 6, y: 3 },
+                Velocity { x: 6, y: 4 },
+                Velocity { x: 6, y: 5 },
+                Velocity { x: 6, y: 6 },
+                Velocity { x: 6, y: 7 },
+                Velocity { x: 6, y: 8 },
+                Velocity { x: 6, y: 9 },
+                Velocity { x: 7, y: -1 },
+                Velocity { x: 7, y: 0 },
+                Velocity { x: 7, y: 1 },
+                Velocity { x: 7, y: 2 },
+                Velocity { x: 7, y: 3 },
+                Velocity { x: 7, y: 4 },
+                Velocity { x: 7, y: 5 },
+                Velocity { x: 7, y: 6 },
+                Velocity { x: 7, y: 7 },
+                Velocity { x: 7, y: 8 },
+                Velocity { x: 7, y: 9 },
+                Velocity { x: 8, y: -2 },
+                Velocity { x: 8, y: -1 },
+                Velocity { x: 8, y: 0 },
+                Velocity { x: 8, y: 1 },
+                Velocity { x: 9, y: -2 },
+                Velocity { x: 9, y: -1 },
+                Velocity { x: 9, y: 0 },
+                Velocity { x: 10, y: -2 },
+                Velocity { x: 10, y: -1 },
+                Velocity { x: 11, y: -4 },
+                Velocity { x: 11, y: -3 },
+                Velocity { x: 11, y: -2 },
+                Velocity { x: 11, y: -1 },
+                Velocity { x: 12, y: -4 },
+                Velocity { x: 12, y: -3 },
+                Velocity { x: 12, y: -2 },
+                Velocity { x: 13, y: -4 },
+                Velocity { x: 13, y: -3 },
+                Velocity { x: 13, y: -2 },
+                Velocity { x: 14, y: -4 },
+                Velocity { x: 14, y: -3 },
+                Velocity { x: 14, y: -2 },
+                Velocity { x: 15, y: -4 },
+                Velocity { x: 15, y: -3 },
+                Velocity { x: 15, y: -2 },
+                Velocity { x: 20, y: -10 },
+                Velocity { x: 20, y: -9 },
+                Velocity { x: 20, y: -8 },
+                Velocity { x: 20, y: -7 },
+                Velocity { x: 20, y: -6 },
+                Velocity { x: 20, y: -5 },
+                Velocity { x: 21, y: -10 },
+                Velocity { x: 21, y: -9 },
+                Velocity { x: 21, y: -8 },
+                Velocity { x: 21, y: -7 },
+                Velocity { x: 21, y: -6 },
+                Velocity { x: 21, y: -5 },
+                Velocity { x: 22, y: -10 },
+                Velocity { x: 22, y: -9 },
+                Velocity { x: 22, y: -8 },
+                Velocity { x: 22, y: -7 },
+                Velocity { x: 22, y: -6 },
+                Velocity { x: 22, y: -5 },
+                Velocity { x: 23, y: -10 },
+                Velocity { x: 23, y: -9 },
+                Velocity { x: 23, y: -8 },
+                Velocity { x: 23, y: -7 },
+                Velocity { x: 23, y: -6 },
+                Velocity { x: 23, y: -5 },
+                Velocity { x: 24, y: -10 },
+                Velocity { x: 24, y: -9 },
+                Velocity { x: 24, y: -8 },
+                Velocity { x: 24, y: -7 },
+                Velocity { x: 24, y: -6 },
+                Velocity { x: 24, y: -5 },
+                Velocity { x: 25, y: -10 },
+                Velocity { x: 25, y: -9 },
+                Velocity { x: 25, y: -8 },
+                Velocity { x: 25, y: -7 },
+                Velocity { x: 25, y: -6 },
+                Velocity { x: 25, y: -5 },
+                Velocity { x: 26, y: -10 },
+                Velocity { x: 26, y: -9 },
+                Velocity { x: 26, y: -8 },
+                Velocity { x: 26, y: -7 },
+                Velocity { x: 26, y: -6 },
+                Velocity { x: 26, y: -5 },
+                Velocity { x: 27, y: -10 },
+                Velocity { x: 27, y: -9 },
+                Velocity { x: 27, y: -8 },
+                Velocity { x: 27, y: -7 },
+                Velocity { x: 27, y: -6 },
+                Velocity { x: 27, y: -5 },
+                Velocity { x: 28, y: -10 },
+                Velocity { x: 28, y: -9 },
+                Velocity { x: 28, y: -8 },
+                Velocity { x: 28, y: -7 },
+                Velocity { x: 28, y: -6 },
+                Velocity { x: 28, y: -5 },
+                Velocity { x: 29, y: -10 },
+                Velocity { x: 29, y: -9 },
+                Velocity { x: 29, y: -8 },
+                Velocity { x: 29, y: -7 },
+                Velocity { x: 29, y: -6 },
+                Velocity { x: 29, y: -5 },
+                Velocity { x: 30, y: -10 },
+                Velocity { x: 30, y: -9 },
+                Velocity { x: 30, y: -8 },
+                Velocity { x: 30, y: -7 },
+                Velocity { x: 30, y: -6 },
+                Velocity { x: 30, y: -5 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hits_target() {
+        assert!(hits_target(&TARGET, Velocity { x: 7, y: 2 }));
+        assert!(hits_target(&TARGET, Velocity { x: 6, y: 3 }));
+        assert!(hits_target(&TARGET, Velocity { x: 9, y: 0 }));
+        assert!(hits_target(&TARGET, Velocity { x: 6, y: 9 }));
+        assert!(!hits_target(&TARGET, Velocity { x: 17, y: -4 }));
+    }
+
+    #[test]
+    fn test_parse_target_area() {
+        assert_eq!(
+            parse_target_area("target area: x=20..30, y=-10..-5"),
+            Ok(TARGET)
+        );
+        assert_eq!(
+            parse_target_area("target area: x=20..30").unwrap_err().to_string(),
+            "line 1, column 1: Expected \"target area: x=X1..X2, y=Y1..Y2\", got \"target area: x=20..30\""
+        );
+        assert_eq!(
+            parse_target_area("target area: x=20..99999999999, y=-10..-5")
+                .unwrap_err()
+                .to_string(),
+            "line 1, column 20: Invalid x \"99999999999\""
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let area = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        let launch = Puzzle::part1(&area);
+        assert_eq!(launch.height, 45);
+        assert_eq!(
+            Output::from(launch).extra.unwrap().to_string(),
+            r#"{"velocity":{"x":7,"y":9}}"#
+        );
+        assert_eq!(Puzzle::part2(&area), 112);
+    }
+
+    proptest! {
+        // a few cases only, each one tries thousands of velocities
+        #![proptest_config(ProptestConfig::with_cases(4))]
+
+        #[test]
+        fn prop_random_targets(seed: u64, size in 1..20usize) {
+            let target = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert!(target.width() <= size as i64 && target.height() <= size as i64);
+            let launch = Puzzle::part1(&target);
+            prop_assert!(hits_target(&target, launch.velocity));
+            prop_assert_eq!(launch.height, highest_position(&launch.velocity));
+            // every position of the target can be hit in one step
+            prop_assert!(Puzzle::part2(&target) as i64 >= target.width() * target.height());
+        }
+    }
+}
@@ -0,0 +1,889 @@
+use aoc_common::rand::Rng;
+use aoc_common::{parse_lines, parse_token, Generate, ParseError, Solution, StdRng};
+use std::fmt;
+use std::iter::Peekable;
+use std::ops::Add;
+use std::str::CharIndices;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Num {
+    Simple(u8),
+    Pair(Box<SnailNum>),
+}
+
+impl Num {
+    // add v to the leftmost Simple value
+    fn add_left(&mut self, v: u8) {
+        match self {
+            Num::Simple(n) => *n += v,
+            Num::Pair(p) => p.left.add_left(v),
+        }
+    }
+
+    // add v to the rightmost Simple value
+    fn add_right(&mut self, v: u8) {
+        match self {
+            Num::Simple(n) => *n += v,
+            Num::Pair(p) => p.right.add_right(v),
+        }
+    }
+
+    // true when a split operation happens
+    fn split(&mut self) -> bool {
+        match self {
+            Num::Simple(n) if *n >= 10 => {
+                *self = Num::Pair(Box::new(SnailNum {
+                    left: Num::Simple(*n / 2),
+                    right: Num::Simple(n.div_ceil(2)),
+                }));
+                true
+            }
+            Num::Pair(n) => n.left.split() || n.right.split(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnailNum {
+    left: Num,
+    right: Num,
+}
+
+impl SnailNum {
+    pub fn new(a: SnailNum, b: SnailNum) -> Self {
+        Self {
+            left: Num::Pair(Box::new(a)),
+            right: Num::Pair(Box::new(b)),
+        }
+    }
+
+    fn _explode(&mut self, depth: usize) -> (bool, Option<u8>, Option<u8>) {
+        let mut exploded = false;
+        if depth == 5 {
+            match (&self.left, &self.right) {
+                (Num::Simple(l), Num::Simple(r)) => return (true, Some(*l), Some(*r)),
+                _ => panic!("Bad SnailNum more than 5 levels deep!"),
+            }
+        }
+        if let Num::Pair(n) = &mut self.left {
+            let (left_exploded, l, r) = n._explode(depth + 1);
+            exploded |= left_exploded;
+            if left_exploded {
+                match (l, r) {
+                    (Some(l), Some(r)) => {
+                        // left value just exploded, replace with 0 and add its values
+                        self.left = Num::Simple(0);
+                        self.right.add_left(r);
+                        return (true, Some(l), None);
+                    }
+                    (Some(l), None) => {
+                        return (true, Some(l), None);
+                    }
+                    (None, Some(r)) => {
+                        self.right.add_left(r);
+                        return (true, None, None);
+                    }
+                    (None, None) => {
+                        return (true, None, None);
+                    }
+                };
+            }
+        }
+        if !exploded {
+            if let Num::Pair(n) = &mut self.right {
+                let (right_exploded, l, r) = n._explode(depth + 1);
+                exploded |= right_exploded;
+                match (l, r) {
+                    (Some(l), Some(r)) => {
+                        // right value just exploded, replace with 0 and add its values
+                        self.right = Num::Simple(0);
+                        self.left.add_right(l);
+                        return (exploded, None, Some(r));
+                    }
+                    (Some(l), None) => {
+                        self.left.add_right(l);
+                        return (exploded, None, None);
+                    }
+                    (None, Some(r)) => {
+                        return (exploded, None, Some(r));
+                    }
+                    (None, None) => return (exploded, None, None),
+                }
+            }
+        }
+        (exploded, None, None)
+    }
+
+    // true if one pair exploded, false otherwise
+    fn explode(&mut self) -> bool {
+        let (exploded, _, _) = self._explode(1);
+        exploded
+    }
+
+    fn split(&mut self) -> bool {
+        self.left.split() || self.right.split()
+    }
+
+    pub fn reduce(&mut self) {
+        loop {
+            if self.explode() {
+                // exploded, try exploding again as only one pair explodes each call
+                continue;
+            }
+            if self.split() {
+                // after a split need to try exploding again
+                continue;
+            }
+            break;
+        }
+    }
+}
+
+type Cursor<'a> = Peekable<CharIndices<'a>>;
+
+// error about the character at position i of s, or about its end if there are no more
+fn error_at(s: &str, i: Option<usize>, message: &str) -> ParseError {
+    match i {
+        Some(i) => {
+            let c = s[i..].chars().next().unwrap();
+            ParseError::new(s, &s[i..i + c.len_utf8()], message)
+        }
+        None => ParseError::new(s, &s[s.len()..], message),
+    }
+}
+
+fn expect(s: &str, chars: &mut Cursor, expected: char) -> Result<(), ParseError> {
+    match chars.next() {
+        Some((_, c)) if c == expected => Ok(()),
+        next => Err(error_at(
+            s,
+            next.map(|(i, _)| i),
+            &format!("Expected '{}', found", expected),
+        )),
+    }
+}
+
+fn parse_num(s: &str, chars: &mut Cursor) -> Result<Num, ParseError> {
+    let (start, c) = match chars.peek() {
+        Some(&next) => next,
+        None => return Err(error_at(s, None, "Unexpected end of number")),
+    };
+    if c == '[' {
+        return Ok(Num::Pair(Box::new(parse_snailnum(s, chars)?)));
+    }
+    let mut end = start;
+    while let Some(&(i, c)) = chars.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        end = i + 1;
+        chars.next();
+    }
+    if end == start {
+        return Err(error_at(s, Some(start), "Expected a number or a [, found"));
+    }
+    Ok(Num::Simple(parse_token(s, &s[start..end], "Bad number")?))
+}
+
+fn parse_snailnum(s: &str, chars: &mut Cursor) -> Result<SnailNum, ParseError> {
+    expect(s, chars, '[')?;
+    let left = parse_num(s, chars)?;
+    expect(s, chars, ',')?;
+    let right = parse_num(s, chars)?;
+    expect(s, chars, ']')?;
+    Ok(SnailNum { left, right })
+}
+
+impl FromStr for SnailNum {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.char_indices().peekable();
+        let num = parse_snailnum(s, &mut chars)?;
+        if let Some((i, _)) = chars.next() {
+            return Err(ParseError::new(
+                s,
+                &s[i..],
+                "Unexpected trailing characters",
+            ));
+        }
+        Ok(num)
+    }
+}
+
+impl Add for &SnailNum {
+    type Output = SnailNum;
+
+    fn add(self, rhs: Self) -> SnailNum {
+        let mut n = SnailNum::new(self.clone(), rhs.clone());
+        n.reduce();
+        n
+    }
+}
+
+impl Add for SnailNum {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut n = SnailNum::new(self, rhs);
+        n.reduce();
+        n
+    }
+}
+
+// numbers are written like in the input, e.g. [[1,2],3]
+impl fmt::Display for Num {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Num::Simple(v) => write!(f, "{}", v),
+            Num::Pair(p) => write!(f, "{}", p),
+        }
+    }
+}
+
+impl fmt::Display for SnailNum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{},{}]", self.left, self.right)
+    }
+}
+
+pub trait Magnitude {
+    fn magnitude(&self) -> u64;
+}
+
+impl Magnitude for Num {
+    fn magnitude(&self) -> u64 {
+        match self {
+            Num::Simple(v) => *v as u64,
+            Num::Pair(p) => p.magnitude(),
+        }
+    }
+}
+
+impl Magnitude for SnailNum {
+    fn magnitude(&self) -> u64 {
+        3 * self.left.magnitude() + 2 * self.right.magnitude()
+    }
+}
+
+pub fn find_largest_sum(nums: &[SnailNum]) -> u64 {
+    let mut best: u64 = u64::MIN;
+    for i in 0..nums.len() {
+        for j in 0..nums.len() {
+            if i == j {
+                continue;
+            }
+            let m = (&nums[i] + &nums[j]).magnitude();
+            if m > best {
+                best = m;
+            }
+        }
+    }
+    best
+}
+
+pub fn parse_nums(input: &str) -> Result<Vec<SnailNum>, ParseError> {
+    let nums = parse_lines(input, str::parse)?;
+    if nums.is_empty() {
+        return Err(ParseError::input("No snailfish numbers to add"));
+    }
+    Ok(nums)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<SnailNum>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const EXAMPLE: &'static str = "[[[[4,3],4],4],[7,[[8,4],9]]]
+[1,1]";
+
+    fn parse(input: &str) -> Result<Vec<SnailNum>, ParseError> {
+        parse_nums(input)
+    }
+
+    fn part1(nums: &Vec<SnailNum>) -> u64 {
+        nums.iter()
+            .cloned()
+            .reduce(|a, b| a + b)
+            .unwrap()
+            .magnitude()
+    }
+
+    fn part2(nums: &Vec<SnailNum>) -> u64 {
+        find_largest_sum(nums)
+    }
+}
+
+// a reduced number, with regular numbers up to 9 and pairs nested in `depth` more pairs
+// at most
+fn random_num(rng: &mut StdRng, depth: usize) -> Num {
+    if depth == 0 || rng.gen_bool(0.3) {
+        Num::Simple(rng.gen_range(0..=9))
+    } else {
+        Num::Pair(Box::new(random_snailnum(rng, depth - 1)))
+    }
+}
+
+fn random_snailnum(rng: &mut StdRng, depth: usize) -> SnailNum {
+    SnailNum {
+        left: random_num(rng, depth),
+        right: random_num(rng, depth),
+    }
+}
+
+// `size` numbers, at least 2 to have pairs of numbers to add, already reduced like the
+// puzzle's: pairs nested in at most 4 pairs
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(2))
+            .map(|_| format!("{}\n", random_snailnum(rng, 3)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+    use Num::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "[1,2]".parse::<SnailNum>(),
+            Ok(SnailNum {
+                left: Simple(1),
+                right: Simple(2)
+            })
+        );
+        assert_eq!(
+            "[[1,2],3]".parse::<SnailNum>(),
+            Ok(SnailNum {
+                left: Pair(Box::new(SnailNum {
+                    left: Simple(1),
+                    right: Simple(2)
+                })),
+                right: Simple(3)
+            })
+        );
+        assert_eq!(
+            "[9,[8,7]]".parse::<SnailNum>(),
+            Ok(SnailNum {
+                left: Simple(9),
+                right: Pair(Box::new(SnailNum {
+                    left: Simple(8),
+                    right: Simple(7)
+                })),
+            })
+        );
+        assert_eq!(
+            "[[1,9],[8,5]]".parse::<SnailNum>(),
+            Ok(SnailNum {
+                left: Pair(Box::new(SnailNum {
+                    left: Simple(1),
+                    right: Simple(9)
+                })),
+                right: Pair(Box::new(SnailNum {
+                    left: Simple(8),
+                    right: Simple(5)
+                }))
+            })
+        );
+        assert_eq!(
+            "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]".parse::<SnailNum>(),
+            Ok(SnailNum {
+                left: Pair(Box::new(SnailNum {
+                    left: Pair(Box::new(SnailNum {
+                        left: Pair(Box::new(SnailNum {
+                            left: Simple(1),
+                            right: Simple(2)
+                        })),
+                        right: Pair(Box::new(SnailNum {
+                            left: Simple(3),
+                            right: Simple(4)
+                        }))
+                    })),
+                    right: Pair(Box::new(SnailNum {
+                        left: Pair(Box::new(SnailNum {
+                            left: Simple(5),
+                            right: Simple(6)
+                        })),
+                        right: Pair(Box::new(SnailNum {
+                            left: Simple(7),
+                            right: Simple(8)
+                        }))
+                    }))
+                })),
+                right: Simple(9)
+            })
+        );
+        assert_eq!(
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]".parse::<SnailNum>(),
+            Ok(SnailNum {
+                left: Pair(Box::new(SnailNum {
+                    left: Pair(Box::new(SnailNum {
+                        left: Pair(Box::new(SnailNum {
+                            left: Simple(1),
+                            right: Simple(3)
+                        })),
+                        right: Pair(Box::new(SnailNum {
+                            left: Simple(5),
+                            right: Simple(3)
+                        }))
+                    })),
+                    right: Pair(Box::new(SnailNum {
+                        left: Pair(Box::new(SnailNum {
+                            left: Simple(1),
+                            right: Simple(3)
+                        })),
+                        right: Pair(Box::new(SnailNum {
+                            left: Simple(8),
+                            right: Simple(7)
+                        }))
+                    }))
+                })),
+                right: Pair(Box::new(SnailNum {
+                    left: Pair(Box::new(SnailNum {
+                        left: Pair(Box::new(SnailNum {
+                            left: Simple(4),
+                            right: Simple(9)
+                        })),
+                        right: Pair(Box::new(SnailNum {
+                            left: Simple(6),
+                            right: Simple(9)
+                        }))
+                    })),
+                    right: Pair(Box::new(SnailNum {
+                        left: Pair(Box::new(SnailNum {
+                            left: Simple(8),
+                            right: Simple(2)
+                        })),
+                        right: Pair(Box::new(SnailNum {
+                            left: Simple(7),
+                            right: Simple(3)
+                        }))
+                    }))
+                }))
+            })
+        );
+    }
+
+    #[test]
+    fn test_explode() {
+        let mut num = "[[[[[9,8],1],2],3],4]".parse::<SnailNum>().unwrap();
+        num.explode();
+        assert_eq!(num, "[[[[0,9],2],3],4]".parse::<SnailNum>().unwrap());
+
+        let mut num = "[7,[6,[5,[4,[3,2]]]]]".parse::<SnailNum>().unwrap();
+        num.explode();
+        assert_eq!(num, "[7,[6,[5,[7,0]]]]".parse::<SnailNum>().unwrap());
+
+        let mut num = "[[6,[5,[4,[3,2]]]],1]".parse::<SnailNum>().unwrap();
+        num.explode();
+        assert_eq!(num, "[[6,[5,[7,0]]],3]".parse::<SnailNum>().unwrap());
+
+        let mut num = "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]"
+            .parse::<SnailNum>()
+            .unwrap();
+        num.explode();
+        assert_eq!(
+            num,
+            "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+
+        let mut num = "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"
+            .parse::<SnailNum>()
+            .unwrap();
+        num.explode();
+        assert_eq!(
+            num,
+            "[[3,[2,[8,0]]],[9,[5,[7,0]]]]".parse::<SnailNum>().unwrap()
+        );
+
+        let mut num = "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"
+            .parse::<SnailNum>()
+            .unwrap();
+        num.explode();
+        assert_eq!(
+            num,
+            "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num.explode();
+        assert_eq!(
+            num,
+            "[[[[0,7],4],[15,[0,13]]],[1,1]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_num_split() {
+        let mut num = Num::Simple(9);
+        assert!(!num.split());
+
+        let mut num = Num::Simple(15);
+        assert!(num.split());
+        assert_eq!(
+            num,
+            Num::Pair(Box::new(SnailNum {
+                left: Num::Simple(7),
+                right: Num::Simple(8),
+            }))
+        );
+
+        let mut num = Num::Pair(Box::new(SnailNum {
+            left: Num::Simple(7),
+            right: Num::Simple(8),
+        }));
+        assert!(!num.split());
+
+        let mut num = Num::Pair(Box::new(SnailNum {
+            left: Num::Simple(14),
+            right: Num::Simple(8),
+        }));
+        assert!(num.split());
+        assert_eq!(
+            num,
+            Num::Pair(Box::new(SnailNum {
+                left: Num::Pair(Box::new(SnailNum {
+                    left: Num::Simple(7),
+                    right: Num::Simple(7),
+                })),
+                right: Num::Simple(8),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_snailnum_split() {
+        let mut num = SnailNum {
+            left: Num::Pair(Box::new(SnailNum {
+                left: Num::Simple(14),
+                right: Num::Simple(8),
+            })),
+            right: Num::Pair(Box::new(SnailNum {
+                left: Num::Simple(6),
+                right: Num::Simple(12),
+            })),
+        };
+        assert!(num.split());
+        assert_eq!(
+            num,
+            SnailNum {
+                left: Num::Pair(Box::new(SnailNum {
+                    left: Num::Pair(Box::new(SnailNum {
+                        left: Num::Simple(7),
+                        right: Num::Simple(7),
+                    })),
+                    right: Num::Simple(8),
+                })),
+                right: Num::Pair(Box::new(SnailNum {
+                    left: Num::Simple(6),
+                    right: Num::Simple(12),
+                })),
+            }
+        );
+        assert!(num.split());
+        let prev = num.clone();
+        assert_eq!(
+            num,
+            SnailNum {
+                left: Num::Pair(Box::new(SnailNum {
+                    left: Num::Pair(Box::new(SnailNum {
+                        left: Num::Simple(7),
+                        right: Num::Simple(7),
+                    })),
+                    right: Num::Simple(8),
+                })),
+                right: Num::Pair(Box::new(SnailNum {
+                    left: Num::Simple(6),
+                    right: Num::Pair(Box::new(SnailNum {
+                        left: Num::Simple(6),
+                        right: Num::Simple(6),
+                    })),
+                })),
+            }
+        );
+        assert!(!num.split());
+        assert_eq!(num, prev);
+    }
+
+    #[test]
+    fn test_reduce() {
+        let mut num = "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"
+            .parse::<SnailNum>()
+            .unwrap();
+        num.reduce();
+        assert_eq!(
+            num,
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(
+            Vec::from(["[1,1]", "[2,2]", "[3,3]", "[4,4]",])
+                .iter()
+                .map(|s| s.parse::<SnailNum>().unwrap())
+                .reduce(|a, b| a + b),
+            Some("[[[[1,1],[2,2]],[3,3]],[4,4]]".parse::<SnailNum>().unwrap())
+        );
+        assert_eq!(
+            Vec::from(["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]",])
+                .iter()
+                .map(|s| s.parse::<SnailNum>().unwrap())
+                .reduce(|a, b| a + b),
+            Some("[[[[3,0],[5,3]],[4,4]],[5,5]]".parse::<SnailNum>().unwrap())
+        );
+        assert_eq!(
+            Vec::from(["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]",])
+                .iter()
+                .map(|s| s.parse::<SnailNum>().unwrap())
+                .reduce(|a, b| a + b),
+            Some("[[[[5,0],[7,4]],[5,5]],[6,6]]".parse::<SnailNum>().unwrap())
+        );
+        // larger example, step by step
+        let mut num = "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]"
+            .parse::<SnailNum>()
+            .unwrap();
+        num = num
+            + "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]"
+                .parse::<SnailNum>()
+                .unwrap();
+        assert_eq!(
+            num,
+            "[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num
+            + "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]"
+                .parse::<SnailNum>()
+                .unwrap();
+        // [[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]],[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]]
+        // [[[[0,[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]],[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]]
+        // [[[[5,0],[[11,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]],[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]]
+        // [[[[5,11],[0,[13,0]]],[[8,[7,7]],[[7,9],[5,0]]]],[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]]
+        // [[[[5,11],[13,0]],[[8,[7,7]],[[7,9],[5,0]]]],[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]]
+        // [[[[5,11],[13,0]],[[15,0],[[14,9],[5,0]]]],[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]]
+        // [[[[5,11],[13,0]],[[15,14],[0,[14,0]]]],[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]]
+        assert_eq!(
+            num,
+            "[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num
+            + "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]"
+                .parse::<SnailNum>()
+                .unwrap();
+        assert_eq!(
+            num,
+            "[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num + "[7,[5,[[3,8],[1,4]]]]".parse::<SnailNum>().unwrap();
+        assert_eq!(
+            num,
+            "[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num + "[[2,[2,2]],[8,[8,1]]]".parse::<SnailNum>().unwrap();
+        assert_eq!(
+            num,
+            "[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num + "[2,9]".parse::<SnailNum>().unwrap();
+        assert_eq!(
+            num,
+            "[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num + "[1,[[[9,3],9],[[9,0],[0,7]]]]".parse::<SnailNum>().unwrap();
+        assert_eq!(
+            num,
+            "[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num + "[[[5,[7,4]],7],1]".parse::<SnailNum>().unwrap();
+        assert_eq!(
+            num,
+            "[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        num = num + "[[[[4,2],2],6],[8,7]]".parse::<SnailNum>().unwrap();
+        assert_eq!(
+            num,
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+        );
+        assert_eq!(
+            Vec::from([
+                "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+                "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+                "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+                "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+                "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+                "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+                "[[[[5,4],[7,7]],8],[[8,3],8]]",
+                "[[9,3],[[9,9],[6,[4,9]]]]",
+                "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+                "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+            ])
+            .iter()
+            .map(|s| s.parse::<SnailNum>().unwrap())
+            .reduce(|a, b| a + b),
+            Some(
+                "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+                    .parse::<SnailNum>()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(
+            "[[1,2],[[3,4],5]]".parse::<SnailNum>().unwrap().magnitude(),
+            143
+        );
+        assert_eq!(
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"
+                .parse::<SnailNum>()
+                .unwrap()
+                .magnitude(),
+            1384
+        );
+        assert_eq!(
+            "[[[[1,1],[2,2]],[3,3]],[4,4]]"
+                .parse::<SnailNum>()
+                .unwrap()
+                .magnitude(),
+            445
+        );
+        assert_eq!(
+            "[[[[3,0],[5,3]],[4,4]],[5,5]]"
+                .parse::<SnailNum>()
+                .unwrap()
+                .magnitude(),
+            791
+        );
+        assert_eq!(
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]"
+                .parse::<SnailNum>()
+                .unwrap()
+                .magnitude(),
+            1137
+        );
+        assert_eq!(
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"
+                .parse::<SnailNum>()
+                .unwrap()
+                .magnitude(),
+            3488
+        );
+    }
+
+    #[test]
+    fn test_find_largest_sum() {
+        let nums: Vec<SnailNum> = vec![
+            "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]",
+            "[[[5,[2,8]],4],[5,[[9,9],0]]]",
+            "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]",
+            "[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]",
+            "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]",
+            "[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]",
+            "[[[[5,4],[7,7]],8],[[8,3],8]]",
+            "[[9,3],[[9,9],[6,[4,9]]]]",
+            "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]",
+            "[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+        ]
+        .into_iter()
+        .map(|s| s.parse::<SnailNum>().unwrap())
+        .collect();
+        assert_eq!(find_largest_sum(&nums), 3993);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            "[1,2".parse::<SnailNum>().unwrap_err().to_string(),
+            "column 5: Expected ']', found"
+        );
+        assert_eq!(
+            "[[1,2],x]".parse::<SnailNum>().unwrap_err().to_string(),
+            "column 8: Expected a number or a [, found \"x\""
+        );
+        assert_eq!(
+            "[1,2]]".parse::<SnailNum>().unwrap_err().to_string(),
+            "column 6: Unexpected trailing characters \"]\""
+        );
+        assert_eq!(
+            parse_nums("[1,2]\n[1;2]").unwrap_err().to_string(),
+            "line 2, column 3: Expected ',', found \";\""
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let nums = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        // the sum is [[[[0,7],4],[[7,8],[6,0]]],[8,1]], adding in the other order gives a
+        // smaller magnitude
+        assert_eq!(Puzzle::part1(&nums), 1384);
+        assert_eq!(Puzzle::part2(&nums), 1384);
+    }
+
+    // deepest nesting of pairs, the outer pair being at depth 1
+    fn depth(text: &str) -> usize {
+        text.chars()
+            .scan(0usize, |depth, c| {
+                match c {
+                    '[' => *depth += 1,
+                    ']' => *depth -= 1,
+                    _ => {}
+                }
+                Some(*depth)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn prop_random_numbers(seed: u64, size in 2..10usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let nums = Puzzle::parse(&text).unwrap();
+            let formatted: String = nums.iter().map(|n| format!("{}\n", n)).collect();
+            prop_assert_eq!(formatted, text);
+            // sums are reduced and the largest of them is the largest of every pair's
+            let sum = nums.iter().cloned().reduce(|a, b| a + b).unwrap();
+            prop_assert!(depth(&sum.to_string()) <= 4);
+            prop_assert!(!sum.to_string().split(['[', ']', ',']).any(|n| n.len() > 1));
+            prop_assert!(Puzzle::part2(&nums) >= (&nums[1] + &nums[0]).magnitude());
+        }
+    }
+}
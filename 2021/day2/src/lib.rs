@@ -0,0 +1,234 @@
+use aoc_common::rand::Rng;
+use aoc_common::{parse_lines, parse_token, Generate, ParseError, Solution, StdRng, Streaming};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Position {
+    depth: i64,
+    horizontal: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Command {
+    Up(i32),
+    Down(i32),
+    Forward(i32),
+}
+
+pub fn parse_command(line: &str) -> Result<Command, ParseError> {
+    let (op, amountstr) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new(line, line, "Invalid format for line"))?;
+    let amount = parse_token::<i32>(line, amountstr, "Units must be integers")?;
+    match op {
+        "up" => Ok(Command::Up(amount)),
+        "down" => Ok(Command::Down(amount)),
+        "forward" => Ok(Command::Forward(amount)),
+        _ => Err(ParseError::new(line, op, "Invalid instruction")),
+    }
+}
+
+// Where the commands read so far take the submarine, when they move it directly and
+// when they aim it
+#[derive(Default)]
+pub struct Course {
+    position: Position,
+    aimed: Position,
+    aim: i64,
+}
+
+impl Course {
+    pub fn steer(&mut self, command: &Command) {
+        match *command {
+            Command::Up(amount) => {
+                self.position.depth -= i64::from(amount);
+                self.aim -= i64::from(amount);
+            }
+            Command::Down(amount) => {
+                self.position.depth += i64::from(amount);
+                self.aim += i64::from(amount);
+            }
+            Command::Forward(amount) => {
+                self.position.horizontal += i64::from(amount);
+                self.aimed.horizontal += i64::from(amount);
+                self.aimed.depth += self.aim * i64::from(amount);
+            }
+        }
+    }
+}
+
+fn follow_course(plan: &[Command]) -> Course {
+    let mut course = Course::default();
+    for command in plan {
+        course.steer(command);
+    }
+    course
+}
+
+pub fn move_submarine(plan: &[Command]) -> Position {
+    follow_course(plan).position
+}
+
+pub fn move_submarine_with_aim(plan: &[Command]) -> Position {
+    follow_course(plan).aimed
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Command>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    const EXAMPLE: &'static str = "down 10
+up 5
+forward 3
+down 4
+forward 6
+up 7";
+
+    fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+        parse_lines(input, parse_command)
+    }
+
+    fn part1(plan: &Vec<Command>) -> i64 {
+        let position = move_submarine(plan);
+        position.depth * position.horizontal
+    }
+
+    fn part2(plan: &Vec<Command>) -> i64 {
+        let position = move_submarine_with_aim(plan);
+        position.depth * position.horizontal
+    }
+}
+
+impl Streaming for Puzzle {
+    type State = Course;
+
+    fn feed(course: &mut Course, line: &str) -> Result<(), ParseError> {
+        course.steer(&parse_command(line)?);
+        Ok(())
+    }
+
+    fn answer1(course: &Course) -> i64 {
+        course.position.depth * course.position.horizontal
+    }
+
+    fn answer2(course: &Course) -> i64 {
+        course.aimed.depth * course.aimed.horizontal
+    }
+}
+
+// `size` commands of up to 9 units, going down more often than up so that the
+// submarine stays under water
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let command = ["forward", "forward", "down", "down", "up"][rng.gen_range(0..5)];
+                format!("{} {}\n", command, rng.gen_range(1..=9))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{generate, solve, solve_stream};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("forward 5"), Ok(Command::Forward(5)));
+        assert_eq!(parse_command("up 3"), Ok(Command::Up(3)));
+        assert_eq!(
+            parse_command("backward 5").unwrap_err().to_string(),
+            "column 1: Invalid instruction \"backward\""
+        );
+        assert_eq!(
+            Puzzle::parse("up 3\ndown x").unwrap_err().to_string(),
+            "line 2, column 6: Units must be integers \"x\""
+        );
+    }
+
+    #[test]
+    fn test_move_submarine() {
+        assert_eq!(
+            move_submarine(&[]),
+            Position {
+                depth: 0,
+                horizontal: 0
+            }
+        );
+        assert_eq!(
+            move_submarine(&Puzzle::parse(Puzzle::EXAMPLE).unwrap()),
+            Position {
+                depth: 2,
+                horizontal: 9
+            }
+        );
+    }
+
+    #[test]
+    fn test_move_submarine_with_aim() {
+        assert_eq!(
+            move_submarine_with_aim(&[]),
+            Position {
+                depth: 0,
+                horizontal: 0
+            }
+        );
+        assert_eq!(
+            move_submarine_with_aim(&Puzzle::parse(Puzzle::EXAMPLE).unwrap()),
+            Position {
+                depth: 69,
+                horizontal: 9
+            }
+        );
+    }
+
+    #[test]
+    fn test_stream() {
+        assert_eq!(
+            solve_stream::<Puzzle>(&mut Puzzle::EXAMPLE.as_bytes(), &[1, 2]),
+            solve::<Puzzle>(Puzzle::EXAMPLE, &[1, 2])
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_commands(seed: u64, size in 0..200usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let plan = Puzzle::parse(&text).unwrap();
+            let formatted: String = plan
+                .iter()
+                .map(|command| match command {
+                    Command::Up(n) => format!("up {}\n", n),
+                    Command::Down(n) => format!("down {}\n", n),
+                    Command::Forward(n) => format!("forward {}\n", n),
+                })
+                .collect();
+            prop_assert_eq!(formatted, text);
+            // aiming only changes how deep the submarine goes
+            let (position, aimed) = (move_submarine(&plan), move_submarine_with_aim(&plan));
+            prop_assert_eq!(position.horizontal, aimed.horizontal);
+            let forward: i64 = plan
+                .iter()
+                .map(|command| match command {
+                    Command::Forward(n) => i64::from(*n),
+                    _ => 0,
+                })
+                .sum();
+            prop_assert_eq!(position.horizontal, forward);
+        }
+
+        #[test]
+        fn prop_stream_same_answers(seed: u64, size in 0..100usize) {
+            let input = generate::<Puzzle>(seed, size);
+            prop_assert_eq!(
+                solve_stream::<Puzzle>(&mut input.as_bytes(), &[1, 2]),
+                solve::<Puzzle>(&input, &[1, 2])
+            );
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use aoc_common::rand::seq::index;
+use aoc_common::{parse_lines, Generate, ParseError, Solution, StdRng};
+
+pub type Counts = Vec<(u32, u32)>;
+
+#[derive(Debug)]
+pub struct Diagnostics {
+    number_len: usize,       // number of digits on each input number
+    numbers: Vec<Vec<char>>, // input numbers as vector of characters
+    counts: Counts,          // number of 0s and 1s at each position
+}
+
+#[derive(Debug)]
+pub enum BitCriteria {
+    MostCommon,
+    LeastCommon,
+}
+
+pub fn count(numbers: &Vec<&Vec<char>>, len: usize) -> Counts {
+    let mut counts: Counts = vec![(0, 0); len];
+    for num in numbers.iter() {
+        for (n, b) in num.iter().enumerate() {
+            if n >= 32 {
+                panic!("Only 32-bit integers are supported")
+            }
+            match b {
+                '0' => counts[n].0 += 1,
+                '1' => counts[n].1 += 1,
+                _ => panic!("Invalid character on input: {}", b),
+            }
+        }
+    }
+    counts
+}
+
+pub fn parse_diagnostics(input: &str) -> Result<Diagnostics, ParseError> {
+    let mut number_len = None;
+    let numbers = parse_lines(input, |line| {
+        if line.is_empty() {
+            return Err(ParseError::input("Empty diagnostic number"));
+        }
+        if let Some(i) = line.find(|c| c != '0' && c != '1') {
+            return Err(ParseError::new(line, &line[i..=i], "Invalid character"));
+        }
+        if line.len() > 32 {
+            return Err(ParseError::new(
+                line,
+                line,
+                "Only 32-bit integers are supported",
+            ));
+        }
+        if number_len.is_none() {
+            number_len = Some(line.len());
+        } else if number_len != Some(line.len()) {
+            return Err(ParseError::new(
+                line,
+                line,
+                "All diagnostic numbers must have the same length",
+            ));
+        }
+        Ok(line.chars().collect())
+    })?;
+    let Some(number_len) = number_len else {
+        return Err(ParseError::input("No diagnostic numbers"));
+    };
+    let counts = count(&numbers.iter().collect(), number_len);
+    Ok(Diagnostics {
+        number_len,
+        numbers,
+        counts,
+    })
+}
+
+pub fn calculate_rate(diag: &Diagnostics, criteria: BitCriteria) -> u32 {
+    let mut rate: u32 = 0;
+    for (n, (c0, c1)) in diag.counts.iter().rev().enumerate() {
+        match criteria {
+            BitCriteria::MostCommon => {
+                if c1 > c0 {
+                    rate += 1 << n;
+                }
+            }
+            BitCriteria::LeastCommon => {
+                if c1 < c0 {
+                    rate += 1 << n;
+                }
+            }
+        }
+    }
+    rate
+}
+
+// the number left after filtering by the bit criteria, if any: the candidates are all
+// filtered out when they share their next bit and the least common one is wanted, and
+// when a number is repeated its copies are left together after the last bit
+pub fn calculate_rating(diag: &Diagnostics, criteria: BitCriteria) -> Option<u32> {
+    let mut candidates: Vec<&Vec<char>> = diag.numbers.iter().collect();
+    let mut pos = 0;
+    while candidates.len() > 1 && pos < diag.number_len {
+        let counts = count(&candidates, diag.number_len);
+        let (count0, count1) = counts[pos];
+        let target = match criteria {
+            BitCriteria::MostCommon => {
+                if count0 > count1 {
+                    '0'
+                } else {
+                    '1'
+                }
+            }
+            BitCriteria::LeastCommon => {
+                if count0 > count1 {
+                    '1'
+                } else {
+                    '0'
+                }
+            }
+        };
+        candidates.retain(|c| c[pos] == target);
+        pos += 1;
+    }
+    let rating = candidates.first()?.iter().collect::<String>();
+    u32::from_str_radix(&rating, 2).ok()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Diagnostics;
+    type Part1 = u64;
+    type Part2 = Option<u64>;
+
+    const EXAMPLE: &'static str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    fn parse(input: &str) -> Result<Diagnostics, ParseError> {
+        parse_diagnostics(input)
+    }
+
+    fn part1(diag: &Diagnostics) -> u64 {
+        let gamma = calculate_rate(diag, BitCriteria::MostCommon);
+        let epsilon = calculate_rate(diag, BitCriteria::LeastCommon);
+        u64::from(gamma) * u64::from(epsilon)
+    }
+
+    // None when no number meets the criteria for the CO2 scrubber rating
+    fn part2(diag: &Diagnostics) -> Option<u64> {
+        let o2 = calculate_rating(diag, BitCriteria::MostCommon)?;
+        let co2 = calculate_rating(diag, BitCriteria::LeastCommon)?;
+        Some(u64::from(o2) * u64::from(co2))
+    }
+}
+
+// true if filtering by the least common bits leaves a number, which the puzzle takes
+// for granted: the candidates are all filtered out when they share their next bit
+fn has_co2_rating(numbers: &[usize], bits: usize) -> bool {
+    let mut candidates = numbers.to_vec();
+    for bit in (0..bits).rev() {
+        if candidates.len() <= 1 {
+            break;
+        }
+        let ones = candidates.iter().filter(|&&n| n >> bit & 1 == 1).count();
+        let target = usize::from(2 * ones < candidates.len());
+        candidates.retain(|&n| n >> bit & 1 == target);
+    }
+    candidates.len() == 1
+}
+
+// `size` different numbers, at least one, of 5 to 16 bits so that the ratings, which
+// rely on the numbers being unique, are found
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let count = size.clamp(1, 1 << 15);
+        let bits = (usize::BITS - count.leading_zeros() + 1).clamp(5, 16) as usize;
+        let numbers = loop {
+            let numbers = index::sample(rng, 1 << bits, count).into_vec();
+            if has_co2_rating(&numbers, bits) {
+                break numbers;
+            }
+        };
+        numbers
+            .into_iter()
+            .map(|n| format!("{:0width$b}\n", n, width = bits))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_calculate_gamma_epsilon() {
+        let diag = &parse_diagnostics(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(calculate_rate(diag, BitCriteria::MostCommon), 0b10110);
+        assert_eq!(calculate_rate(diag, BitCriteria::LeastCommon), 0b01001);
+    }
+
+    #[test]
+    fn test_calculate_rating() {
+        let diag = &parse_diagnostics(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(
+            calculate_rating(diag, BitCriteria::MostCommon),
+            Some(0b10111)
+        );
+        assert_eq!(
+            calculate_rating(diag, BitCriteria::LeastCommon),
+            Some(0b01010)
+        );
+    }
+
+    #[test]
+    fn test_repeated_numbers() {
+        let diag = &parse_diagnostics("101\n101").unwrap();
+        assert_eq!(calculate_rating(diag, BitCriteria::MostCommon), Some(0b101));
+        assert_eq!(calculate_rating(diag, BitCriteria::LeastCommon), None);
+        assert_eq!(Puzzle::part2(diag), None);
+        let diag = &parse_diagnostics("101\n101\n011").unwrap();
+        assert_eq!(calculate_rating(diag, BitCriteria::MostCommon), Some(0b101));
+        assert_eq!(
+            calculate_rating(diag, BitCriteria::LeastCommon),
+            Some(0b011)
+        );
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let diag = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&diag), 198);
+        assert_eq!(Puzzle::part2(&diag), Some(230));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_diagnostics("00100\n11210").unwrap_err().to_string(),
+            "line 2, column 3: Invalid character \"2\""
+        );
+        assert_eq!(
+            parse_diagnostics("00100\n1110").unwrap_err().to_string(),
+            "line 2, column 1: All diagnostic numbers must have the same length \"1110\""
+        );
+        assert_eq!(
+            parse_diagnostics("").unwrap_err().to_string(),
+            "No diagnostic numbers"
+        );
+        assert_eq!(
+            parse_diagnostics("\n").unwrap_err().to_string(),
+            "line 1, Empty diagnostic number"
+        );
+        // 32-bit rates and ratings don't overflow their product
+        let (high, low) = (
+            "1".repeat(16) + &"0".repeat(16),
+            "0".repeat(16) + &"1".repeat(16),
+        );
+        let diag = parse_diagnostics(&format!("{high}\n{high}\n{low}")).unwrap();
+        assert_eq!(Puzzle::part1(&diag), 0xffff0000 * 0xffff);
+        assert_eq!(Puzzle::part2(&diag), Some(0xffff0000 * 0xffff));
+    }
+
+    #[test]
+    fn test_has_co2_rating() {
+        assert!(has_co2_rating(&[0b100, 0b011, 0b010], 3));
+        // 011 and 010 are left, both with a 1 as their second bit
+        assert!(!has_co2_rating(&[0b100, 0b101, 0b011, 0b010], 3));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_diagnostics(seed: u64, size in 1..200usize) {
+            let diag = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(diag.numbers.len(), size);
+            // bits are either the most or the least common, unless tied
+            let gamma = calculate_rate(&diag, BitCriteria::MostCommon);
+            let epsilon = calculate_rate(&diag, BitCriteria::LeastCommon);
+            let ties = diag.counts.iter().filter(|(c0, c1)| c0 == c1).count();
+            prop_assert_eq!((gamma | epsilon).count_ones() as usize, diag.number_len - ties);
+            prop_assert_eq!(gamma & epsilon, 0);
+            // ratings are among the numbers
+            let numbers: Vec<u32> = diag
+                .numbers
+                .iter()
+                .map(|n| u32::from_str_radix(&n.iter().collect::<String>(), 2).unwrap())
+                .collect();
+            for criteria in [BitCriteria::MostCommon, BitCriteria::LeastCommon] {
+                prop_assert!(numbers.contains(&calculate_rating(&diag, criteria).unwrap()));
+            }
+        }
+    }
+}
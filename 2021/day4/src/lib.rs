@@ -0,0 +1,278 @@
+use aoc_common::rand::seq::{index, SliceRandom};
+use aoc_common::{parse_token, Generate, ParseError, Solution, StdRng};
+use std::collections::HashSet;
+
+pub type Number = u8;
+
+#[derive(Debug, PartialEq)]
+pub struct Board {
+    rows: Vec<HashSet<Number>>,
+    columns: Vec<HashSet<Number>>,
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Number>, Vec<Board>), ParseError> {
+    let mut lines = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+
+    // first line is all drawn numbers
+    let (_, first) = lines
+        .next()
+        .ok_or_else(|| ParseError::input("Missing drawn numbers"))?;
+    let drawn_numbers: Vec<Number> = first
+        .split(',')
+        .map(|x| parse_token::<Number>(first, x, "Expected an integer number"))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.at_line(1))?;
+    let mut lines = lines.skip(1);
+    let mut boards = Vec::new();
+    // boards are 5 lines followed by an empty line
+    loop {
+        let board_lines: Vec<(usize, &str)> =
+            lines.by_ref().take_while(|(_, l)| l.trim() != "").collect();
+        if board_lines.is_empty() {
+            break;
+        }
+        boards.push(parse_board(&board_lines)?);
+    }
+    Ok((drawn_numbers, boards))
+}
+
+// parse the lines of a board, each with its line number in the input
+pub fn parse_board(lines: &[(usize, &str)]) -> Result<Board, ParseError> {
+    if lines.len() != 5 {
+        let (n, line) = lines[0];
+        return Err(ParseError::new(line, line, "Board must have 5 rows, starting at").at_line(n));
+    }
+    let mut rows = vec![HashSet::<Number>::new(); 5];
+    let mut columns = vec![HashSet::<Number>::new(); 5];
+    for (rown, &(n, line)) in lines.iter().enumerate() {
+        let numbers: Vec<Number> = line
+            .split(' ')
+            .filter(|n| !n.is_empty())
+            .map(|number| {
+                parse_token(
+                    line,
+                    number,
+                    "Expected an integer number in board definition",
+                )
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at_line(n))?;
+        if numbers.len() != 5 {
+            return Err(ParseError::new(line, line, "Board row must have 5 numbers").at_line(n));
+        }
+        for (coln, number) in numbers.iter().enumerate() {
+            columns[coln].insert(*number);
+        }
+        rows[rown].extend(numbers);
+    }
+    Ok(Board { rows, columns })
+}
+
+// true if given Board is a winner with the given drawn numbers
+pub fn is_winner(board: &Board, numbers: &HashSet<Number>) -> bool {
+    board.rows.iter().any(|r| r.is_subset(numbers))
+        || board.columns.iter().any(|r| r.is_subset(numbers))
+}
+
+pub fn calculate_score(board: &Board, numbers: &HashSet<Number>, last_draw: Number) -> u32 {
+    let mut score: u32 = 0;
+    for row in &board.rows {
+        score += row
+            .iter()
+            .filter(|n| !numbers.contains(n))
+            .map(|n| *n as u32)
+            .sum::<u32>();
+    }
+    score * last_draw as u32
+}
+
+// scores of all winning boards, in the order in which they win
+pub fn play_bingo(all_numbers: &[Number], boards: &[Board]) -> Vec<u32> {
+    let mut draw = HashSet::new();
+    let mut seen_winners: HashSet<usize> = HashSet::new();
+    let mut scores = Vec::new();
+    for &last_draw in all_numbers {
+        draw.insert(last_draw);
+        for (n, board) in boards.iter().enumerate() {
+            if is_winner(board, &draw) && !seen_winners.contains(&n) {
+                seen_winners.insert(n);
+                scores.push(calculate_score(board, &draw, last_draw));
+            }
+        }
+    }
+    scores
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = (Vec<Number>, Vec<Board>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    const EXAMPLE: &'static str =
+        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((numbers, boards): &Self::Input) -> u32 {
+        *play_bingo(numbers, boards).first().expect("No winner!")
+    }
+
+    fn part2((numbers, boards): &Self::Input) -> u32 {
+        *play_bingo(numbers, boards).last().expect("No winner!")
+    }
+}
+
+// every number from 0 to 99 drawn in random order, so that all boards win, and `size`
+// boards of different numbers, at least one
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let mut draws: Vec<Number> = (0..100).collect();
+        draws.shuffle(rng);
+        let draws: Vec<String> = draws.iter().map(|n| n.to_string()).collect();
+        let mut input = draws.join(",") + "\n";
+        for _ in 0..size.max(1) {
+            let numbers = index::sample(rng, 100, 25).into_vec();
+            input.push('\n');
+            for row in numbers.chunks(5) {
+                let row: Vec<String> = row.iter().map(|n| format!("{:2}", n)).collect();
+                input.push_str(&row.join(" "));
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_board() {
+        assert_eq!(
+            parse_board(&[
+                (3, "22 13 17 11  0"),
+                (4, "8  2 23  4 24"),
+                (5, "21  9 14 16  7"),
+                (6, "6 10  3 18  5"),
+                (7, "1 12 20 15 19"),
+            ]),
+            Ok(Board {
+                rows: vec![
+                    HashSet::from([22, 13, 17, 11, 0]),
+                    HashSet::from([8, 2, 23, 4, 24]),
+                    HashSet::from([21, 9, 14, 16, 7]),
+                    HashSet::from([6, 10, 3, 18, 5]),
+                    HashSet::from([1, 12, 20, 15, 19,])
+                ],
+                columns: vec![
+                    HashSet::from([22, 8, 21, 6, 1]),
+                    HashSet::from([13, 2, 9, 10, 12]),
+                    HashSet::from([17, 23, 14, 3, 20]),
+                    HashSet::from([11, 4, 16, 18, 15]),
+                    HashSet::from([0, 24, 7, 5, 19])
+                ],
+            })
+        );
+        assert_eq!(
+            parse_board(&[(3, "22 13 17 11  0"), (4, "8  2 23  4")])
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: Board must have 5 rows, starting at \"22 13 17 11  0\""
+        );
+        assert_eq!(
+            parse_input("1,2,x").unwrap_err().to_string(),
+            "line 1, column 5: Expected an integer number \"x\""
+        );
+    }
+
+    #[test]
+    fn test_is_winner() {
+        let board1 = Board {
+            rows: vec![
+                HashSet::from([14, 21, 17, 24, 4]),
+                HashSet::from([10, 16, 15, 9, 19]),
+                HashSet::from([18, 8, 23, 26, 20]),
+                HashSet::from([22, 11, 13, 6, 5]),
+                HashSet::from([2, 0, 12, 3, 7]),
+            ],
+            columns: vec![
+                HashSet::from([14, 10, 18, 22, 2]),
+                HashSet::from([21, 16, 8, 13, 12]),
+                HashSet::from([17, 15, 23, 13, 12]),
+                HashSet::from([24, 9, 26, 6, 3]),
+                HashSet::from([4, 19, 20, 5, 7]),
+            ],
+        };
+        let board2 = Board {
+            rows: vec![
+                HashSet::from([3, 15, 0, 2, 22]),
+                HashSet::from([9, 18, 13, 17, 5]),
+                HashSet::from([19, 8, 7, 25, 23]),
+                HashSet::from([20, 11, 10, 24, 4]),
+                HashSet::from([14, 21, 16, 12, 6]),
+            ],
+            columns: vec![
+                HashSet::from([3, 9, 19, 20, 14]),
+                HashSet::from([15, 18, 8, 11, 21]),
+                HashSet::from([0, 13, 7, 10, 16]),
+                HashSet::from([2, 17, 25, 24, 12]),
+                HashSet::from([22, 5, 23, 4, 6]),
+            ],
+        };
+        let draw = HashSet::from([17, 23, 4, 0, 14, 21, 24]);
+        assert!(is_winner(&board1, &draw));
+        assert!(!is_winner(&board2, &draw));
+    }
+
+    #[test]
+    fn test_example() {
+        let game = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&game), 4512);
+        assert_eq!(Puzzle::part2(&game), 1924);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn prop_random_games(seed: u64, size in 1..20usize) {
+            let input = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            let (numbers, boards) = &input;
+            prop_assert_eq!(numbers.len(), 100);
+            prop_assert_eq!(boards.len(), size);
+            for board in boards {
+                let all: HashSet<&Number> = board.rows.iter().flatten().collect();
+                prop_assert_eq!(all.len(), 25);
+            }
+            let scores = play_bingo(numbers, boards);
+            prop_assert_eq!(scores.len(), size);
+            prop_assert_eq!(Puzzle::part1(&input), scores[0]);
+            prop_assert_eq!(Puzzle::part2(&input), scores[size - 1]);
+        }
+    }
+}
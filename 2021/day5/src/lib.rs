@@ -0,0 +1,186 @@
+use aoc_common::rand::Rng;
+use aoc_common::{
+    parse_lines, Animation, BoundingBox, Direction8, Generate, Palette, ParseError, Point, Rgb,
+    Solution, StdRng, Unsolved, Visual,
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Vent {
+    from: Point,
+    to: Point,
+}
+
+const MAX_X: usize = 1000;
+const MAX_Y: usize = 1000;
+
+pub type Grid = [u8; MAX_X * MAX_Y];
+
+// Parse an input in the form "X,Y" into a Point of the grid, errors are reported
+// relative to the whole line
+pub fn parse_coordinate(line: &str, input: &str) -> Result<Point, ParseError> {
+    let point = Point::parse(line, input)?;
+    match point.to_position() {
+        Some((x, y)) if x < MAX_X && y < MAX_Y => Ok(point),
+        _ => Err(ParseError::new(
+            line,
+            input.trim(),
+            "Coordinate out of bounds",
+        )),
+    }
+}
+
+// Parse an input in the form "X1,Y1 -> X2,Y2" into a Vent
+pub fn parse_instruction(input: &str) -> Result<Vent, ParseError> {
+    let (from, to) = input.split_once("->").ok_or_else(|| {
+        ParseError::new(input, input, "Invalid instruction: expected X1,Y1 -> X2,Y2")
+    })?;
+    let from = parse_coordinate(input, from)?;
+    let to = parse_coordinate(input, to)?;
+    Ok(Vent { from, to })
+}
+
+// marks the points of the horizontal, vertical or diagonal line of the vent, which
+// were checked to be inside the grid when parsing
+pub fn apply_vent(vent: &Vent, grid: &mut Grid) {
+    let step = (vent.to - vent.from).signum();
+    let mut point = vent.from;
+    for _ in 0..=vent.from.chebyshev(vent.to) {
+        let (x, y) = point.to_position().unwrap();
+        grid[y * MAX_X + x] += 1;
+        point += step;
+    }
+}
+
+// this is Part 2 solution which includes diagonal lines,
+// for Part 1 solution see Part 1 commit
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Vent>;
+    type Part1 = Unsolved;
+    type Part2 = usize;
+
+    const EXAMPLE: &'static str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    fn parse(input: &str) -> Result<Vec<Vent>, ParseError> {
+        parse_lines(input, parse_instruction)
+    }
+
+    fn part1(_vents: &Vec<Vent>) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(vents: &Vec<Vent>) -> usize {
+        let mut grid: Grid = [0; MAX_X * MAX_Y];
+        for vent in vents {
+            apply_vent(vent, &mut grid);
+        }
+        grid.iter().filter(|n| **n >= 2).count()
+    }
+}
+
+// vents drawn 50 at a time, overlaps getting hotter
+impl Visual for Puzzle {
+    fn frames(vents: &Vec<Vent>) -> Animation {
+        let palette = Palette::new(vec![
+            Rgb::BLACK,
+            Rgb(40, 80, 160),
+            Rgb(255, 160, 0),
+            Rgb::RED,
+        ]);
+        let mut grid: Grid = [0; MAX_X * MAX_Y];
+        let mut animation = Animation::new();
+        for (i, vent) in vents.iter().enumerate() {
+            apply_vent(vent, &mut grid);
+            if (i + 1) % 50 == 0 || i + 1 == vents.len() {
+                let cells = aoc_common::Grid::from_cells(MAX_X, grid.to_vec());
+                animation.push(palette.paint(&cells));
+            }
+        }
+        animation
+    }
+}
+
+// `size` horizontal, vertical or diagonal vents of up to 300 points inside the grid
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let bounds = BoundingBox::new(
+            Point::ORIGIN,
+            Point::new(MAX_X as i64 - 1, MAX_Y as i64 - 1),
+        );
+        (0..size)
+            .map(|_| {
+                let from = Point::new(
+                    rng.gen_range(0..MAX_X as i64),
+                    rng.gen_range(0..MAX_Y as i64),
+                );
+                let step = Direction8::ALL[rng.gen_range(0..8)].offset();
+                let mut to = from;
+                for _ in 0..rng.gen_range(0..300) {
+                    if !bounds.contains(to + step) {
+                        break;
+                    }
+                    to += step;
+                }
+                format!("{},{} -> {},{}\n", from.x, from.y, to.x, to.y)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_instruction() {
+        assert_eq!(
+            parse_instruction("0,9 -> 5,9"),
+            Ok(Vent {
+                from: Point { x: 0, y: 9 },
+                to: Point { x: 5, y: 9 },
+            })
+        );
+        assert_eq!(
+            parse_instruction("0,9 -> 5,y").unwrap_err().to_string(),
+            "column 10: Invalid coordinate, expected an integer \"y\""
+        );
+        assert_eq!(
+            Puzzle::parse("0,9 -> 5,9\n0,9 5,9")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: Invalid instruction: expected X1,Y1 -> X2,Y2 \"0,9 5,9\""
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let vents = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&vents), Unsolved);
+        assert_eq!(Puzzle::part2(&vents), 12);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn prop_random_vents(seed: u64, size in 1..100usize) {
+            let vents = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(vents.len(), size);
+            // overlapping points are covered twice at least
+            let covered: i64 = vents.iter().map(|v| v.from.chebyshev(v.to) + 1).sum();
+            prop_assert!(2 * Puzzle::part2(&vents) as i64 <= covered);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use aoc_common::rand::Rng;
+use aoc_common::{
+    parse_token, Configurable, Generate, Kind, Params, ParseError, Simulation, Solution, StdRng,
+};
+
+const RESET: usize = 6;
+const SPAWN: usize = RESET + 2;
+const DAYS_TO_SIMULATE_PART_1: usize = 80;
+const DAYS_TO_SIMULATE_PART_2: usize = 256;
+// days after which a school of a thousand fishes, more than in the puzzle's inputs, may
+// no longer be counted in a u64
+const MAX_DAYS_TO_SIMULATE: usize = 400;
+
+// number of fishes by days until they spawn
+#[derive(Clone, Debug)]
+pub struct School {
+    fishes_per_day: Vec<u64>,
+}
+
+impl School {
+    pub fn from_fishes(fishes: &[usize]) -> Self {
+        let mut fishes_per_day = vec![0; SPAWN + 1];
+        for f in fishes {
+            fishes_per_day[*f] += 1;
+        }
+        Self { fishes_per_day }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.fishes_per_day.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// each day, fishes about to spawn start over and their offspring join the school
+impl Simulation for School {
+    fn step(&mut self) {
+        self.fishes_per_day.rotate_left(1);
+        self.fishes_per_day[RESET] += self.fishes_per_day[SPAWN];
+    }
+}
+
+pub fn parse_fishes(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::input("Missing list of fishes"))?;
+    line.split(',')
+        .map(|x| {
+            let timer = parse_token::<usize>(line, x, "Expected an integer number")?;
+            if timer > SPAWN {
+                return Err(ParseError::new(line, x, "Timer larger than spawn time"));
+            }
+            Ok(timer)
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.at_line(1))
+}
+
+// number of fishes after simulating the given number of days
+pub fn simulate(fishes: &[usize], days: usize) -> u64 {
+    let mut school = School::from_fishes(fishes);
+    school.run(days);
+    school.len()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<usize>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const EXAMPLE: &'static str = "3,4,3,1,2";
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_fishes(input)
+    }
+
+    fn part1(fishes: &Vec<usize>) -> u64 {
+        Puzzle::part1_with(fishes, &Days::default())
+    }
+
+    fn part2(fishes: &Vec<usize>) -> u64 {
+        Puzzle::part2_with(fishes, &Days::default())
+    }
+}
+
+// days simulated by each part
+pub struct Days {
+    part1: usize,
+    part2: usize,
+}
+
+impl Default for Days {
+    fn default() -> Self {
+        Days {
+            part1: DAYS_TO_SIMULATE_PART_1,
+            part2: DAYS_TO_SIMULATE_PART_2,
+        }
+    }
+}
+
+impl Configurable for Puzzle {
+    type Settings = Days;
+
+    const PARAMS: &'static [(&'static str, Kind)] = &[
+        ("days_to_simulate_part_1", Kind::Count),
+        ("days_to_simulate_part_2", Kind::Count),
+    ];
+
+    fn settings(params: &Params) -> Result<Days, String> {
+        let days = |name, default| match params.count(name) {
+            None => Ok(default),
+            Some(n) if n <= MAX_DAYS_TO_SIMULATE as u64 => Ok(n as usize),
+            Some(_) => Err(format!("{} must be at most {}", name, MAX_DAYS_TO_SIMULATE)),
+        };
+        Ok(Days {
+            part1: days("days_to_simulate_part_1", DAYS_TO_SIMULATE_PART_1)?,
+            part2: days("days_to_simulate_part_2", DAYS_TO_SIMULATE_PART_2)?,
+        })
+    }
+
+    fn part1_with(fishes: &Vec<usize>, days: &Days) -> u64 {
+        simulate(fishes, days.part1)
+    }
+
+    fn part2_with(fishes: &Vec<usize>, days: &Days) -> u64 {
+        simulate(fishes, days.part2)
+    }
+}
+
+// `size` fishes, at least one, with timers from 1 to 5 like the puzzle's
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let timers: Vec<String> = (0..size.max(1))
+            .map(|_| rng.gen_range(1..=5).to_string())
+            .collect();
+        timers.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use aoc_common::{check_params, solve_configured, Answer, Param};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_school_step() {
+        let mut school = School::from_fishes(&[3, 4, 3, 1, 2]);
+        assert_eq!(school.fishes_per_day, vec![0, 1, 1, 2, 1, 0, 0, 0, 0]);
+        let history: Vec<Vec<u64>> = school
+            .history(5)
+            .into_iter()
+            .map(|school| school.fishes_per_day)
+            .collect();
+        assert_eq!(
+            history[1..],
+            [
+                vec![1, 1, 2, 1, 0, 0, 0, 0, 0],
+                vec![1, 2, 1, 0, 0, 0, 1, 0, 1],
+                vec![2, 1, 0, 0, 0, 1, 1, 1, 1],
+                vec![1, 0, 0, 0, 1, 1, 3, 1, 2],
+                vec![0, 0, 0, 1, 1, 3, 2, 2, 1],
+            ]
+        );
+        assert_eq!(school.len(), 10);
+    }
+
+    #[test]
+    fn test_parse_fishes() {
+        assert_eq!(parse_fishes("3,4,3,1,2\n"), Ok(vec![3, 4, 3, 1, 2]));
+        assert_eq!(
+            parse_fishes("3,4,9").unwrap_err().to_string(),
+            "line 1, column 5: Timer larger than spawn time \"9\""
+        );
+        assert_eq!(
+            parse_fishes("3,,4").unwrap_err().to_string(),
+            "line 1, column 3: Expected an integer number"
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let fishes = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&fishes), 5934);
+        assert_eq!(Puzzle::part2(&fishes), 26984457539);
+    }
+
+    #[test]
+    fn test_configured() {
+        let mut params = Params::default();
+        params.set("days_to_simulate_part_1", Param::Count(18));
+        let outputs = solve_configured::<Puzzle>(Puzzle::EXAMPLE, &[1, 2], &params).unwrap();
+        assert_eq!(outputs[0].answer, Answer::Number(26));
+        assert_eq!(outputs[1].answer, Answer::Number(26984457539));
+        // a thousand fishes about to spawn still fit after the most days allowed
+        params.set("days_to_simulate_part_2", Param::Count(400));
+        assert!(Puzzle::settings(&params).is_ok());
+        assert!(simulate(&[0; 1000], MAX_DAYS_TO_SIMULATE) < u64::MAX);
+        params.set("days_to_simulate_part_2", Param::Count(700));
+        assert_eq!(
+            check_params::<Puzzle>(&params),
+            Err("days_to_simulate_part_2 must be at most 400".to_string())
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_fishes(seed: u64, size in 1..300usize) {
+            let fishes = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(fishes.len(), size);
+            prop_assert!(Puzzle::part1(&fishes) < Puzzle::part2(&fishes));
+            // same count as following every single fish for a few days
+            let mut school = fishes.clone();
+            for _ in 0..20 {
+                let spawned = school.iter().filter(|&&t| t == 0).count();
+                school.iter_mut().for_each(|t| *t = if *t == 0 { RESET } else { *t - 1 });
+                school.extend(vec![SPAWN; spawned]);
+            }
+            prop_assert_eq!(simulate(&fishes, 20), school.len() as u64);
+        }
+    }
+}
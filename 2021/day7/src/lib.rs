@@ -0,0 +1,121 @@
+use aoc_common::rand::Rng;
+use aoc_common::{parse_token, Generate, ParseError, Solution, StdRng};
+
+// solution is the sum of the distance of each crab to the median
+// crabs MUST BE SORTED
+pub fn calculate_fuel_part1(crabs: &[i32]) -> i32 {
+    let median = crabs[crabs.len() / 2];
+    crabs.iter().fold(0, |acc, x| acc + (x - median).abs())
+}
+
+// calculated by brute forcing all possible solutions
+// crabs MUST BE SORTED
+pub fn calculate_fuel_part2(crabs: &[i32]) -> i32 {
+    let mut min = i32::MAX;
+    for i in crabs[0]..crabs[crabs.len() - 1] {
+        let c = crabs.iter().fold(0, |acc, x| acc + gauss_distance(i, *x));
+        if c < min {
+            min = c;
+        }
+    }
+    min
+}
+
+// calculate distance from a to b, then add all numbers from 1 to the distance
+// e.g. a=3, b=7, distance=4, sum 1+2+3+4=10
+pub fn gauss_distance(a: i32, b: i32) -> i32 {
+    let d = (a - b).abs();
+    // https://betterexplained.com/articles/techniques-for-adding-the-numbers-1-to-100/
+    (d * (d + 1)) / 2
+}
+
+pub fn parse_crabs(input: &str) -> Result<Vec<i32>, ParseError> {
+    let line = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::input("Missing list of crab positions"))?;
+    let mut crabs: Vec<i32> = line
+        .split(',')
+        .map(|x| parse_token::<i32>(line, x, "Expected an integer number"))
+        .collect::<Result<_, _>>()
+        .map_err(|e| e.at_line(1))?;
+    crabs.sort();
+    Ok(crabs)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    const EXAMPLE: &'static str = "16,1,2,0,4,2,7,1,2,14";
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        parse_crabs(input)
+    }
+
+    fn part1(crabs: &Vec<i32>) -> i32 {
+        calculate_fuel_part1(crabs)
+    }
+
+    fn part2(crabs: &Vec<i32>) -> i32 {
+        calculate_fuel_part2(crabs)
+    }
+}
+
+// `size` crabs, at least one, spread over twice as many positions as there are crabs
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        let positions: Vec<String> = (0..size)
+            .map(|_| rng.gen_range(0..=2 * size).to_string())
+            .collect();
+        positions.join(",") + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    const CRABS: [i32; 10] = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+
+    #[test]
+    fn test_part1() {
+        let mut crabs = CRABS.to_vec();
+        crabs.sort();
+        assert_eq!(calculate_fuel_part1(&crabs), 37);
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut crabs = CRABS.to_vec();
+        crabs.sort();
+        assert_eq!(calculate_fuel_part2(&crabs), 168);
+    }
+
+    #[test]
+    fn test_example() {
+        let crabs = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&crabs), 37);
+        assert_eq!(Puzzle::part2(&crabs), 168);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_crabs(seed: u64, size in 1..200usize) {
+            let crabs = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(crabs.len(), size);
+            prop_assert!(crabs.windows(2).all(|w| w[0] <= w[1]));
+            // the median is the best of all positions, and moving costs more in part 2
+            let fuel = |to: i32| crabs.iter().map(|c| (c - to).abs()).sum::<i32>();
+            let best = (crabs[0]..=crabs[size - 1]).map(fuel).min().unwrap();
+            prop_assert_eq!(Puzzle::part1(&crabs), best);
+            prop_assert!(Puzzle::part1(&crabs) <= Puzzle::part2(&crabs));
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use aoc_common::rand::seq::SliceRandom;
+use aoc_common::rand::Rng;
+use aoc_common::{parse_lines, Generate, ParseError, Solution, StdRng, Unsolved};
+
+const SEGMENTS_1: u8 = 2;
+const SEGMENTS_4: u8 = 4;
+const SEGMENTS_7: u8 = 3;
+const SEGMENTS_8: u8 = 7;
+
+// a line of notes: the ten unique signal patterns and the four digits of the output
+#[derive(Debug, PartialEq)]
+pub struct Entry {
+    patterns: Vec<String>,
+    output: Vec<String>,
+}
+
+pub fn parse_digits(line: &str, digits: &str, count: usize) -> Result<Vec<String>, ParseError> {
+    let digits: Vec<&str> = digits.split(' ').collect();
+    if digits.len() != count {
+        return Err(ParseError::new(
+            line,
+            digits[0],
+            format!(
+                "Expected {} digits, got {}, starting at",
+                count,
+                digits.len()
+            ),
+        ));
+    }
+    for digit in digits.iter() {
+        if digit.is_empty() || !digit.chars().all(|c| ('a'..='g').contains(&c)) {
+            return Err(ParseError::new(line, digit, "Invalid digit"));
+        }
+    }
+    Ok(digits.into_iter().map(String::from).collect())
+}
+
+pub fn parse_entry(line: &str) -> Result<Entry, ParseError> {
+    let (patterns, output) = line
+        .split_once(" | ")
+        .ok_or_else(|| ParseError::new(line, line, "Missing \" | \" separator"))?;
+    Ok(Entry {
+        patterns: parse_digits(line, patterns, 10)?,
+        output: parse_digits(line, output, 4)?,
+    })
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    const EXAMPLE: &'static str =
+        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+    fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+        parse_lines(input, parse_entry)
+    }
+
+    fn part1(entries: &Vec<Entry>) -> usize {
+        entries
+            .iter()
+            .flat_map(|entry| entry.output.iter())
+            .map(|x| x.len() as u8)
+            .filter(|&x| x == SEGMENTS_1 || x == SEGMENTS_4 || x == SEGMENTS_7 || x == SEGMENTS_8)
+            .count()
+    }
+
+    fn part2(_entries: &Vec<Entry>) -> Unsolved {
+        Unsolved
+    }
+}
+
+// segments lit for each digit when the wires are connected right
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+// `size` displays, each with its wires mixed up in its own way
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut wires: Vec<char> = ('a'..='g').collect();
+                wires.shuffle(rng);
+                let pattern = |rng: &mut StdRng, digit: usize| {
+                    let mut segments: Vec<char> = DIGITS[digit]
+                        .chars()
+                        .map(|s| wires[s as usize - 'a' as usize])
+                        .collect();
+                    segments.shuffle(rng);
+                    segments.into_iter().collect::<String>()
+                };
+                let mut digits: Vec<usize> = (0..10).collect();
+                digits.shuffle(rng);
+                let patterns: Vec<String> = digits.iter().map(|&d| pattern(rng, d)).collect();
+                let output: Vec<String> = (0..4)
+                    .map(|_| {
+                        let digit = rng.gen_range(0..10);
+                        pattern(rng, digit)
+                    })
+                    .collect();
+                format!("{} | {}\n", patterns.join(" "), output.join(" "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    const ENTRY: &str =
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    #[test]
+    fn test_parse_entry() {
+        let entry = parse_entry(ENTRY).unwrap();
+        assert_eq!(entry.patterns.len(), 10);
+        assert_eq!(entry.output, vec!["cdfeb", "fcadb", "cdfeb", "cdbaf"]);
+        assert_eq!(
+            parse_entry("acedgfb cdfbe | cdfeb")
+                .unwrap_err()
+                .to_string(),
+            "column 1: Expected 10 digits, got 2, starting at \"acedgfb\""
+        );
+        assert_eq!(
+            Puzzle::parse(&format!("{}\n{}", ENTRY, ENTRY.replace("fcadb", "fcadx")))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 68: Invalid digit \"fcadx\""
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let entries = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&entries), 26);
+        assert_eq!(Puzzle::part2(&entries), Unsolved);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_random_entries(seed: u64, size in 0..100usize) {
+            let entries = Puzzle::parse(&generate::<Puzzle>(seed, size)).unwrap();
+            prop_assert_eq!(entries.len(), size);
+            // every digit is shown once among the patterns
+            for entry in &entries {
+                let mut lengths: Vec<usize> = entry.patterns.iter().map(|p| p.len()).collect();
+                lengths.sort_unstable();
+                prop_assert_eq!(lengths, [2, 3, 4, 5, 5, 5, 6, 6, 6, 7]);
+                prop_assert!(entry.output.iter().all(|o| entry
+                    .patterns
+                    .iter()
+                    .any(|p| p.len() == o.len() && o.chars().all(|c| p.contains(c)))));
+            }
+            prop_assert!(Puzzle::part1(&entries) <= 4 * size);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use aoc_common::rand::Rng;
+use aoc_common::{Animation, Generate, Grid, Palette, ParseError, Rgb, Solution, StdRng, Visual};
+use std::collections::{HashSet, VecDeque};
+
+pub type Height = u8;
+const BASIN_LIMIT: Height = 9;
+
+pub type Heightmap = Grid<Height>;
+
+// return basin size around point at (x, y)
+pub fn basin(grid: &Heightmap, (x, y): (usize, usize)) -> u32 {
+    let mut visited = HashSet::<(usize, usize)>::new();
+    let mut to_visit = vec![(x, y)];
+    while let Some((x, y)) = to_visit.pop() {
+        visited.insert((x, y));
+        to_visit.extend(
+            grid.neighbours4(x, y)
+                .filter(|p| grid[*p] != BASIN_LIMIT && !visited.contains(p)),
+        );
+    }
+    visited.len() as u32
+}
+
+// true if point at (x, y) is a low point
+pub fn is_low_point(grid: &Heightmap, (x, y): (usize, usize)) -> bool {
+    let v = grid[(x, y)];
+    grid.neighbours4(x, y).all(|p| v < grid[p])
+}
+
+pub fn risk_level(height: Height) -> u32 {
+    (height + 1).into()
+}
+
+// grid position of the low points
+pub fn low_points(grid: &Heightmap) -> Vec<(usize, usize)> {
+    grid.positions()
+        .filter(|p| is_low_point(grid, *p))
+        .collect()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Heightmap;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    const EXAMPLE: &'static str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    fn parse(input: &str) -> Result<Heightmap, ParseError> {
+        Grid::parse_digits(input)
+    }
+
+    fn part1(grid: &Heightmap) -> u32 {
+        low_points(grid)
+            .iter()
+            .map(|p| risk_level(grid[*p]))
+            .sum::<u32>()
+    }
+
+    fn part2(grid: &Heightmap) -> u32 {
+        // sizes of all basins, calculations rely on the fact that each point belongs to
+        // only one basin
+        let mut basins = low_points(grid)
+            .iter()
+            .map(|p| basin(grid, *p))
+            .collect::<Vec<u32>>();
+        // sort will put smallest basins first, that's why rev() is used later
+        basins.sort_unstable();
+        basins.iter().rev().take(3).product()
+    }
+}
+
+// basins in shades of blue, deeper where lower, between black walls of height 9, and
+// their low points in red
+impl Visual for Puzzle {
+    fn frames(grid: &Heightmap) -> Animation {
+        let (deep, shallow) = (Rgb(20, 40, 120), Rgb(170, 210, 255));
+        let mut colors: Vec<Rgb> = (0..BASIN_LIMIT)
+            .map(|h| deep.mix(shallow, f64::from(h) / f64::from(BASIN_LIMIT - 1)))
+            .collect();
+        colors.push(Rgb::BLACK);
+        let mut image = Palette::new(colors).paint(grid);
+        for (x, y) in low_points(grid) {
+            image.set(x, y, Rgb::RED);
+        }
+        Animation::still(image)
+    }
+}
+
+// positions of the cells connected to the given one without crossing a wall of height 9,
+// by increasing number of steps away from it
+fn walk_basin(grid: &Heightmap, from: (usize, usize)) -> Vec<((usize, usize), usize)> {
+    let mut visited = HashSet::from([from]);
+    let mut to_visit = VecDeque::from([(from, 0)]);
+    let mut cells = vec![];
+    while let Some((p, steps)) = to_visit.pop_front() {
+        cells.push((p, steps));
+        for n in grid.neighbours4(p.0, p.1) {
+            if grid[n] != BASIN_LIMIT && visited.insert(n) {
+                to_visit.push_back((n, steps + 1));
+            }
+        }
+    }
+    cells
+}
+
+// a `size` x `size` map, at least 2 x 2, split by walls of height 9 into regions around
+// random centres. Each region is a basin with a single low point, which the puzzle relies
+// on, and rises away from it
+impl Generate for Puzzle {
+    fn random_input(rng: &mut StdRng, size: usize) -> String {
+        let side = size.max(2);
+        let centres: Vec<(usize, usize)> = (0..(side * side / 40).max(1))
+            .map(|_| (rng.gen_range(0..side), rng.gen_range(0..side)))
+            .collect();
+        let regions = Grid::from_cells(
+            side,
+            Grid::new(side, side, ())
+                .positions()
+                .map(|(x, y)| {
+                    (0..centres.len())
+                        .min_by_key(|&c| centres[c].0.abs_diff(x) + centres[c].1.abs_diff(y))
+                        .unwrap()
+                })
+                .collect(),
+        );
+        // walls between regions, on the side of the region of the lowest index
+        let mut grid: Heightmap = regions.map(|_| 0);
+        for (x, y) in regions.positions() {
+            if regions
+                .neighbours4(x, y)
+                .any(|n| regions[n] > regions[(x, y)])
+            {
+                grid[(x, y)] = BASIN_LIMIT;
+            }
+        }
+        let mut done = Grid::new(side, side, false);
+        for p in regions.positions() {
+            if grid[p] == BASIN_LIMIT || done[p] {
+                continue;
+            }
+            let cells = walk_basin(&grid, p);
+            let (low, _) = cells[rng.gen_range(0..cells.len())];
+            // every cell is as high or higher than a neighbour one step closer to the
+            // low point, which stays the only one
+            for (p, steps) in walk_basin(&grid, low) {
+                done[p] = true;
+                if steps > 0 {
+                    grid[p] = (steps as Height + rng.gen_range(0..2)).min(BASIN_LIMIT - 1);
+                }
+            }
+        }
+        grid.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::generate;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_grid() {
+        let grid = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!((grid.width(), grid.height()), (10, 5));
+        assert_eq!(
+            grid.cells(),
+            &[
+                2, 1, 9, 9, 9, 4, 3, 2, 1, 0, 3, 9, 8, 7, 8, 9, 4, 9, 2, 1, 9, 8, 5, 6, 7, 8, 9, 8,
+                9, 2, 8, 7, 6, 7, 8, 9, 6, 7, 8, 9, 9, 8, 9, 9, 9, 6, 5, 6, 7, 8
+            ]
+        )
+    }
+
+    #[test]
+    fn test_basin() {
+        let grid = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(basin(&grid, (1, 0)), 3);
+        assert_eq!(basin(&grid, (9, 0)), 9);
+        assert_eq!(basin(&grid, (2, 2)), 14);
+        assert_eq!(basin(&grid, (6, 4)), 9);
+    }
+
+    #[test]
+    fn test_is_low_point() {
+        let grid = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert!(!is_low_point(&grid, (0, 0)));
+        assert!(is_low_point(&grid, (1, 0)));
+        assert!(is_low_point(&grid, (9, 0)));
+        assert!(!is_low_point(&grid, (0, 1)));
+        assert!(!is_low_point(&grid, (0, 2)));
+        assert!(is_low_point(&grid, (2, 2)));
+        assert!(!is_low_point(&grid, (9, 3)));
+        assert!(is_low_point(&grid, (6, 4)));
+        assert!(!is_low_point(&grid, (9, 4)));
+    }
+
+    #[test]
+    fn test_single_row() {
+        let grid = Puzzle::parse("3191").unwrap();
+        assert_eq!(low_points(&grid), vec![(1, 0), (3, 0)]);
+        assert_eq!(Puzzle::part1(&grid), 4);
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let grid = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&grid), 15);
+        assert_eq!(Puzzle::part2(&grid), 1134);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn prop_random_maps(seed: u64, size in 2..40usize) {
+            let text = generate::<Puzzle>(seed, size);
+            let grid = Puzzle::parse(&text).unwrap();
+            prop_assert_eq!(grid.to_string(), text);
+            prop_assert_eq!((grid.width(), grid.height()), (size, size));
+            // each point outside of walls belongs to the basin of one low point
+            let basins: u32 = low_points(&grid).iter().map(|&p| basin(&grid, p)).sum();
+            let outside = grid.iter().filter(|&&h| h != BASIN_LIMIT).count();
+            prop_assert_eq!(basins as usize, outside);
+        }
+    }
+}
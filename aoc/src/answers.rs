@@ -0,0 +1,329 @@
+// Recorded answers of every day on its real input, used to check that a change to a
+// solution still gives the same answers
+//
+// The answers file has one answer per line: year, day, part and answer separated by
+// spaces. Backslashes and newlines in answers (e.g. ASCII art) are escaped, and lines
+// starting with '#' are comments.
+
+use aoc_common::Answer;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+// year, day and part of an answer
+pub type Key = (u16, u8, u8);
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<Key, String>);
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> Result<String, String> {
+    let mut output = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => output.push('\\'),
+            Some('n') => output.push('\n'),
+            Some(c) => return Err(format!("invalid escape \"\\{}\"", c)),
+            None => return Err("unfinished escape at end of answer".to_string()),
+        }
+    }
+    Ok(output)
+}
+
+fn parse_line(line: &str) -> Result<(Key, String), String> {
+    let mut fields = line.splitn(4, ' ');
+    let mut field = |name: &str| {
+        fields
+            .next()
+            .filter(|f| !f.is_empty())
+            .ok_or(format!("missing {}", name))
+    };
+    let year = field("year")?;
+    let day = field("day")?;
+    let part = field("part")?;
+    let answer = field("answer")?;
+    let key = (
+        year.parse()
+            .map_err(|_| format!("invalid year \"{}\"", year))?,
+        day.parse()
+            .map_err(|_| format!("invalid day \"{}\"", day))?,
+        part.parse()
+            .map_err(|_| format!("invalid part \"{}\"", part))?,
+    );
+    Ok((key, unescape(answer)?))
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, answer) = parse_line(line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+            answers.insert(key, answer);
+        }
+        Ok(Answers(answers))
+    }
+
+    // answers recorded in the given file, none if the file doesn't exist yet
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Answers::parse(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_string())
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, key: Key) -> Option<&str> {
+        self.0.get(&key).map(String::as_str)
+    }
+
+    // records a solved answer, unsolved parts are forgotten
+    pub fn record(&mut self, key: Key, answer: &Answer) {
+        if answer.is_solved() {
+            self.0.insert(key, answer.to_string());
+        } else {
+            self.0.remove(&key);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# year day part answer")?;
+        for ((year, day, part), answer) in &self.0 {
+            writeln!(f, "{} {} {} {}", year, day, part, escape(answer))?;
+        }
+        Ok(())
+    }
+}
+
+// result of comparing a part's answer with the recorded one
+#[derive(Debug, PartialEq)]
+pub enum Check {
+    Match,
+    Mismatch { expected: String, actual: String },
+    NewlySolved(String),
+    Unsolved,
+}
+
+pub fn check(recorded: Option<&str>, answer: &Answer) -> Check {
+    let actual = answer.to_string();
+    match recorded {
+        Some(expected) if expected == actual && answer.is_solved() => Check::Match,
+        Some(expected) => Check::Mismatch {
+            expected: expected.to_string(),
+            actual,
+        },
+        None if answer.is_solved() => Check::NewlySolved(actual),
+        None => Check::Unsolved,
+    }
+}
+
+// answers are written on a single line in reports, as they are in the answers file
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Check::Match => write!(f, "ok"),
+            Check::Mismatch { expected, actual } => write!(
+                f,
+                "MISMATCH expected {}, got {}",
+                escape(expected),
+                escape(actual)
+            ),
+            Check::NewlySolved(answer) => write!(f, "newly solved: {}", escape(answer)),
+            Check::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+// outcome of checking every day against the recorded answers
+#[derive(Debug, Default)]
+pub struct Report {
+    lines: Vec<String>,
+    matching: usize,
+    mismatches: usize,
+    newly_solved: usize,
+    unsolved: usize,
+    missing_inputs: usize,
+    invalid_inputs: usize,
+}
+
+impl Report {
+    pub fn missing_input(&mut self, year: u16, day: u8) {
+        self.missing_inputs += 1;
+        self.lines
+            .push(format!("{} day {}: missing input", year, day));
+    }
+
+    pub fn invalid_input(&mut self, year: u16, day: u8, error: &str) {
+        self.invalid_inputs += 1;
+        self.lines
+            .push(format!("{} day {}: invalid input: {}", year, day, error));
+    }
+
+    pub fn part(&mut self, (year, day, part): Key, check: Check) {
+        match check {
+            Check::Match => self.matching += 1,
+            Check::Mismatch { .. } => self.mismatches += 1,
+            Check::NewlySolved(_) => self.newly_solved += 1,
+            Check::Unsolved => self.unsolved += 1,
+        }
+        if check != Check::Match && check != Check::Unsolved {
+            self.lines
+                .push(format!("{} day {} part {}: {}", year, day, part, check));
+        }
+    }
+
+    // newly solved parts and missing inputs are reported but are not failures
+    pub fn is_success(&self) -> bool {
+        self.mismatches == 0 && self.invalid_inputs == 0
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{}", line)?;
+        }
+        writeln!(
+            f,
+            "{} matching, {} mismatches, {} newly solved, {} unsolved, {} missing inputs, {} invalid inputs",
+            self.matching,
+            self.mismatches,
+            self.newly_solved,
+            self.unsolved,
+            self.missing_inputs,
+            self.invalid_inputs
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let text = "# year day part answer
+2015 1 1 138
+2021 13 2 #.#\\n.#.\\\\
+
+2015 7 2 a b c
+";
+        let answers = Answers::parse(text).unwrap();
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers.get((2015, 1, 1)), Some("138"));
+        assert_eq!(answers.get((2021, 13, 2)), Some("#.#\n.#.\\"));
+        assert_eq!(answers.get((2015, 7, 2)), Some("a b c"));
+        assert_eq!(answers.get((2015, 1, 2)), None);
+        assert_eq!(
+            answers.to_string(),
+            "# year day part answer
+2015 1 1 138
+2015 7 2 a b c
+2021 13 2 #.#\\n.#.\\\\
+"
+        );
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("2015 1 1 138\n2015 1 x 1"),
+            Err("line 2: invalid part \"x\"".to_string())
+        );
+        assert_eq!(
+            Answers::parse("2015 1 1"),
+            Err("line 1: missing answer".to_string())
+        );
+        assert_eq!(
+            Answers::parse("2015 1 1 a\\tb"),
+            Err("line 1: invalid escape \"\\t\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::default();
+        answers.record((2015, 1, 1), &Answer::from(138));
+        answers.record((2015, 1, 2), &Answer::Unsolved);
+        assert_eq!(answers.get((2015, 1, 1)), Some("138"));
+        assert_eq!(answers.len(), 1);
+        answers.record((2015, 1, 1), &Answer::Unsolved);
+        assert!(answers.is_empty());
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("138"), &Answer::from(138)), Check::Match);
+        assert_eq!(
+            check(Some("138"), &Answer::from(139)),
+            Check::Mismatch {
+                expected: "138".to_string(),
+                actual: "139".to_string()
+            }
+        );
+        assert_eq!(
+            check(Some("138"), &Answer::Unsolved),
+            Check::Mismatch {
+                expected: "138".to_string(),
+                actual: "unsolved".to_string()
+            }
+        );
+        assert_eq!(
+            check(None, &Answer::from("#\n#")),
+            Check::NewlySolved("#\n#".to_string())
+        );
+        assert_eq!(check(None, &Answer::Unsolved), Check::Unsolved);
+        assert_eq!(
+            check(Some("#"), &Answer::from("#\n#")).to_string(),
+            "MISMATCH expected #, got #\\n#"
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let mut report = Report::default();
+        report.part((2015, 1, 1), check(Some("138"), &Answer::from(138)));
+        report.part((2015, 1, 2), check(None, &Answer::from(1771)));
+        report.part((2015, 7, 2), check(None, &Answer::Unsolved));
+        report.missing_input(2015, 2);
+        assert!(report.is_success());
+        report.part((2021, 1, 1), check(Some("7"), &Answer::from(8)));
+        report.invalid_input(2021, 2, "line 1: Invalid command \"up\"");
+        assert!(!report.is_success());
+        assert_eq!(
+            report.to_string(),
+            "2015 day 1 part 2: newly solved: 1771
+2015 day 2: missing input
+2021 day 1 part 1: MISMATCH expected 7, got 8
+2021 day 2: invalid input: line 1: Invalid command \"up\"
+1 matching, 1 mismatches, 1 newly solved, 1 unsolved, 1 missing inputs, 1 invalid inputs
+"
+        );
+    }
+}
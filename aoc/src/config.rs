@@ -0,0 +1,253 @@
+// Settings of the runner, read from aoc.toml at the root of the repository when it
+// exists. The file isn't committed as it holds the session token:
+//
+//     # session cookie of a logged in adventofcode.com account, needed to fetch inputs
+//     session = "53616c7465645f5f..."
+//     # server inputs are fetched from, https://adventofcode.com by default
+//     base_url = "http://localhost:8080"
+//     # directory caching the inputs as <year>/day<day>/input.txt, the root of the
+//     # repository by default so that each input lives next to its day's crate
+//     inputs = "../inputs"
+//     # years of the days run by the all, time and stress commands, every year by default
+//     years = [2021]
+//     # "json" to print JSON records instead of text answers by default
+//     format = "json"
+//     # number of days run at once by the all command, one per CPU by default
+//     threads = 4
+//
+//     # parameters of a day's parts, changing the answers it prints when run alone or
+//     # with the all command; recorded and verified answers keep the puzzle's values
+//     [days.2021.day14]
+//     steps_part_1 = 5
+//
+// The AOC_SESSION environment variable takes precedence over the session of the file.
+
+use crate::days::Day;
+use aoc_common::{Param, Params};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+// the parameters of each day, by year then by "day<day>"
+type DayTables = BTreeMap<String, BTreeMap<String, BTreeMap<String, toml::Value>>>;
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub inputs: Option<PathBuf>,
+    pub years: Option<Vec<u16>>,
+    #[serde(default)]
+    pub format: OutputFormat,
+    pub threads: Option<usize>,
+    #[serde(default)]
+    days: DayTables,
+}
+
+fn to_param(value: &toml::Value) -> Option<Param> {
+    match value {
+        toml::Value::Integer(n) => u64::try_from(*n).ok().map(Param::Count),
+        toml::Value::String(text) => Some(Param::Text(text.clone())),
+        toml::Value::Boolean(flag) => Some(Param::Flag(*flag)),
+        _ => None,
+    }
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|e| e.message().to_string())
+    }
+
+    // a missing file is an empty configuration, relative paths are relative to the file
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(format!("cannot read {}: {}", path.display(), e)),
+        };
+        let mut config =
+            Config::parse(&text).map_err(|e| format!("invalid {}: {}", path.display(), e))?;
+        if let (Some(inputs), Some(dir)) = (&config.inputs, path.parent()) {
+            config.inputs = Some(dir.join(inputs));
+        }
+        Ok(config)
+    }
+
+    // the session of the environment first, then the one of the file
+    pub fn session(&self) -> Option<String> {
+        std::env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| self.session.clone())
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+
+    // the inputs directory, or the given default when not configured
+    pub fn inputs(&self, default: &Path) -> PathBuf {
+        self.inputs.clone().unwrap_or_else(|| default.to_path_buf())
+    }
+
+    // whether the commands running every day run the days of this year
+    pub fn runs_year(&self, year: u16) -> bool {
+        self.years
+            .as_ref()
+            .is_none_or(|years| years.contains(&year))
+    }
+
+    // the parameters set for a day, none unless configured
+    pub fn params(&self, year: u16, day: u8) -> Params {
+        let mut params = Params::default();
+        let table = self
+            .days
+            .get(&year.to_string())
+            .and_then(|days| days.get(&format!("day{}", day)));
+        for (name, value) in table.into_iter().flatten() {
+            if let Some(param) = to_param(value) {
+                params.set(name, param);
+            }
+        }
+        params
+    }
+
+    // every configured year and day must be implemented, with parameters it has and
+    // values it accepts
+    pub fn check(&self, days: &[Day]) -> Result<(), String> {
+        let implemented = |year: u16| days.iter().any(|d| d.year == year);
+        for &year in self.years.iter().flatten() {
+            if !implemented(year) {
+                return Err(format!("years: no day of {} is implemented", year));
+            }
+        }
+        for (year, tables) in &self.days {
+            for (name, table) in tables {
+                let key = format!("days.{}.{}", year, name);
+                let day = name
+                    .strip_prefix("day")
+                    .and_then(|day| Some((year.parse().ok()?, day.parse().ok()?)))
+                    .ok_or(format!("{}: expected a table like days.2021.day14", key))?;
+                let day = days.iter().find(|d| (d.year, d.day) == day).ok_or(format!(
+                    "{}: {} day {} is not implemented",
+                    key, day.0, day.1
+                ))?;
+                for (param, value) in table {
+                    if to_param(value).is_none() {
+                        return Err(format!(
+                            "{}.{}: expected a non-negative integer, a string or a boolean",
+                            key, param
+                        ));
+                    }
+                }
+                (day.check_params)(&self.params(day.year, day.day))
+                    .map_err(|e| format!("{}: {}", key, e))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+        let config =
+            Config::parse("session = \"abc\"\nbase_url = \"http://localhost:8080\"\n").unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url(), "http://localhost:8080");
+        assert_eq!(Config::default().base_url(), DEFAULT_BASE_URL);
+        assert!(Config::parse("sesion = \"abc\"")
+            .unwrap_err()
+            .starts_with("unknown field `sesion`"));
+    }
+
+    #[test]
+    fn test_settings() {
+        let config = Config::parse("years = [2021]\nformat = \"json\"\nthreads = 2\n").unwrap();
+        assert!(config.runs_year(2021));
+        assert!(!config.runs_year(2015));
+        assert_eq!(config.format, OutputFormat::Json);
+        assert_eq!(config.threads, Some(2));
+        assert!(Config::default().runs_year(2015));
+        assert_eq!(Config::default().format, OutputFormat::Text);
+        assert!(Config::parse("format = \"yaml\"")
+            .unwrap_err()
+            .starts_with("unknown variant `yaml`"));
+    }
+
+    #[test]
+    fn test_params() {
+        let config = Config::parse(
+            "[days.2021.day14]\nsteps_part_1 = 5\n[days.2015.day4]\nsecret = \"abc\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.check(DAYS), Ok(()));
+        assert_eq!(config.params(2021, 14).count("steps_part_1"), Some(5));
+        assert_eq!(config.params(2015, 4).text("secret"), Some("abc"));
+        assert!(config.params(2021, 6).is_empty());
+        let check = |text: &str| Config::parse(text).unwrap().check(DAYS).unwrap_err();
+        assert_eq!(
+            check("[days.2021.day14]\nstpes_part_1 = 5\n"),
+            "days.2021.day14: unknown parameter stpes_part_1, expected one of steps_part_1, \
+             steps_part_2"
+        );
+        assert_eq!(
+            check("[days.2021.day14]\nsteps_part_1 = \"5\"\n"),
+            "days.2021.day14: steps_part_1 must be a non-negative integer"
+        );
+        assert_eq!(
+            check("[days.2021.day6]\ndays_to_simulate_part_2 = 700\n"),
+            "days.2021.day6: days_to_simulate_part_2 must be at most 400"
+        );
+        assert_eq!(
+            check("[days.2021.day14]\nsteps_part_1 = -5\n"),
+            "days.2021.day14.steps_part_1: expected a non-negative integer, a string or a \
+             boolean"
+        );
+        assert_eq!(
+            check("[days.2021.day1]\nsteps = 5\n"),
+            "days.2021.day1: unknown parameter steps, there are none"
+        );
+        assert_eq!(
+            check("[days.2021.day30]\nsteps = 5\n"),
+            "days.2021.day30: 2021 day 30 is not implemented"
+        );
+        assert_eq!(
+            check("[days.2021.fourteen]\nsteps = 5\n"),
+            "days.2021.fourteen: expected a table like days.2021.day14"
+        );
+        assert_eq!(
+            check("years = [2016]"),
+            "years: no day of 2016 is implemented"
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = crate::mock::temp_dir("config");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("aoc.toml");
+        assert_eq!(Config::load(&path), Ok(Config::default()));
+        std::fs::write(&path, "inputs = \"cache\"\n").unwrap();
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.inputs(Path::new("/root")), dir.join("cache"));
+        std::fs::write(&path, "inputs = 3\n").unwrap();
+        assert!(Config::load(&path).unwrap_err().starts_with("invalid "));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
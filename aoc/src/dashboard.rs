@@ -0,0 +1,279 @@
+// Runs every day at once on a thread pool and reports how each of them went. A day that
+// panics is reported as such without stopping the others.
+
+use crate::config::Config;
+use crate::days::Day;
+use crate::table::{render, Align};
+use crate::timing::format_duration;
+use aoc_common::{input_path, Input, Output};
+use rayon::prelude::*;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+pub enum Status {
+    Solved(Vec<Output>),
+    MissingInput,
+    InvalidInput(String),
+    Panicked(String),
+}
+
+impl Status {
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Solved(_) => "ok",
+            Status::MissingInput => "missing input",
+            Status::InvalidInput(_) => "invalid input",
+            Status::Panicked(_) => "panicked",
+        }
+    }
+}
+
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub status: Status,
+    // time taken to parse the input and solve both parts
+    pub duration: Duration,
+}
+
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+fn run_day(day: &Day, inputs: &Path, config: &Config) -> Run {
+    let path = input_path(inputs, day.year, day.day);
+    let start = Instant::now();
+    let status = match Input::File(path).read() {
+        Ok(input) => {
+            let params = config.params(day.year, day.day);
+            let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+                day.outputs_with(&input, &[1, 2], &params)
+            }));
+            match solved {
+                Ok(Ok(outputs)) => Status::Solved(outputs),
+                Ok(Err(e)) => Status::InvalidInput(e.to_string()),
+                Err(payload) => Status::Panicked(panic_message(payload)),
+            }
+        }
+        Err(_) => Status::MissingInput,
+    };
+    Run {
+        year: day.year,
+        day: day.day,
+        status,
+        duration: start.elapsed(),
+    }
+}
+
+// runs the given days with their configured parameters on as many threads as configured,
+// one per CPU by default, in the order of days
+pub fn run_days(days: &[&Day], inputs: &Path, config: &Config) -> Result<Vec<Run>, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(config.threads.unwrap_or(0))
+        .build()
+        .map_err(|e| format!("cannot start the thread pool: {}", e))?;
+    // panics are reported with the day, not as they happen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let runs = pool.install(|| {
+        days.par_iter()
+            .map(|day| run_day(day, inputs, config))
+            .collect()
+    });
+    panic::set_hook(hook);
+    Ok(runs)
+}
+
+// why the days that couldn't be solved failed, e.g. "2021 day 13 panicked: ..."
+pub fn errors(runs: &[Run]) -> Vec<String> {
+    runs.iter()
+        .filter_map(|run| match &run.status {
+            Status::InvalidInput(e) | Status::Panicked(e) => Some(format!(
+                "{} day {} {}: {}",
+                run.year,
+                run.day,
+                run.status.name(),
+                e
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+// a table of the status, answers and duration of each day, followed by the answers
+// spanning several lines (e.g. ASCII art) and a summary
+pub fn format_dashboard(runs: &[Run], elapsed: Duration) -> String {
+    let header = ["Year", "Day", "Status", "Part 1", "Part 2", "Time"];
+    let mut cells = vec![header.iter().map(|h| h.to_string()).collect::<Vec<_>>()];
+    let mut multiline = vec![];
+    for run in runs {
+        let mut row = vec![
+            run.year.to_string(),
+            run.day.to_string(),
+            run.status.name().to_string(),
+        ];
+        match &run.status {
+            Status::Solved(outputs) => {
+                for (part, output) in (1..).zip(outputs) {
+                    if output.answer.is_multiline() {
+                        multiline.push(format!(
+                            "{} day {} part {}:\n{}",
+                            run.year, run.day, part, output.answer
+                        ));
+                        row.push("(see below)".to_string());
+                    } else {
+                        row.push(output.answer.to_string());
+                    }
+                }
+                row.push(format_duration(run.duration));
+            }
+            _ => row.extend(std::iter::repeat_n(String::new(), 3)),
+        }
+        cells.push(row);
+    }
+    let align = [
+        Align::Right,
+        Align::Right,
+        Align::Left,
+        Align::Left,
+        Align::Left,
+        Align::Right,
+    ];
+    let mut dashboard = render(&cells, &align);
+    for answer in multiline {
+        dashboard.push('\n');
+        dashboard.push_str(answer.trim_end());
+        dashboard.push('\n');
+    }
+    let count = |name| runs.iter().filter(|r| r.status.name() == name).count();
+    let solving: Duration = runs.iter().map(|r| r.duration).sum();
+    dashboard.push_str(&format!(
+        "\n{} days: {} ok, {} panicked, {} invalid input, {} missing input in {} ({} solving)\n",
+        runs.len(),
+        count("ok"),
+        count("panicked"),
+        count("invalid input"),
+        count("missing input"),
+        format_duration(elapsed),
+        format_duration(solving)
+    ));
+    dashboard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Answer, ParseError, Timings};
+
+    fn solve_outputs(input: &str, parts: &[u8]) -> Result<Vec<Output>, ParseError> {
+        match input.trim() {
+            "panic" => panic!("empty paper"),
+            "" => Err(ParseError::input("empty input")),
+            input => Ok(parts.iter().map(|_| Output::from(input)).collect()),
+        }
+    }
+
+    fn day(day: u8) -> Day {
+        Day {
+            year: 2015,
+            day,
+            solve: |_, _| Ok(vec![]),
+            solve_outputs,
+            time: |_| Ok(Timings::default()),
+            generate: |_, _| String::new(),
+            animate: None,
+            stream: None,
+            params: &[],
+            check_params: |params| params.check(&[]),
+            solve_configured: None,
+            example: "",
+        }
+    }
+
+    #[test]
+    fn test_run_days() {
+        let inputs = crate::mock::temp_dir("dashboard");
+        for (day, input) in [(1, "42"), (2, "panic"), (3, "")] {
+            let path = input_path(&inputs, 2015, day);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, input).unwrap();
+        }
+        let days = [day(1), day(2), day(3), day(4)];
+        let mut config = Config::default();
+        config.threads = Some(2);
+        let runs = run_days(&days.iter().collect::<Vec<_>>(), &inputs, &config).unwrap();
+        let statuses: Vec<&str> = runs.iter().map(|r| r.status.name()).collect();
+        assert_eq!(
+            statuses,
+            ["ok", "panicked", "invalid input", "missing input"]
+        );
+        match &runs[0].status {
+            Status::Solved(outputs) => {
+                assert_eq!(outputs[1].answer, Answer::Text("42".to_string()))
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(
+            errors(&runs),
+            [
+                "2015 day 2 panicked: empty paper",
+                "2015 day 3 invalid input: empty input"
+            ]
+        );
+        std::fs::remove_dir_all(&inputs).unwrap();
+    }
+
+    #[test]
+    fn test_format_dashboard() {
+        let ms = Duration::from_millis;
+        let runs = vec![
+            Run {
+                year: 2015,
+                day: 1,
+                status: Status::Solved(vec![Output::from(280), Output::from(1797)]),
+                duration: ms(2),
+            },
+            Run {
+                year: 2021,
+                day: 13,
+                status: Status::Solved(vec![Output::from(17), Output::from("# #\n###\n")]),
+                duration: ms(15),
+            },
+            Run {
+                year: 2021,
+                day: 14,
+                status: Status::Panicked("empty paper".to_string()),
+                duration: ms(1),
+            },
+            Run {
+                year: 2021,
+                day: 15,
+                status: Status::MissingInput,
+                duration: ms(0),
+            },
+        ];
+        assert_eq!(
+            format_dashboard(&runs, ms(16)),
+            "Year  Day  Status         Part 1  Part 2            Time
+2015    1  ok             280     1797          2.000 ms
+2021   13  ok             17      (see below)  15.000 ms
+2021   14  panicked
+2021   15  missing input
+
+2021 day 13 part 2:
+# #
+###
+
+4 days: 2 ok, 1 panicked, 0 invalid input, 1 missing input in 16.000 ms (18.000 ms solving)
+"
+        );
+    }
+}
@@ -0,0 +1,246 @@
+// Registry of all implemented days, the runner dispatches to the Solution implemented
+// by each day's library
+
+use aoc_common::{
+    Animation, Answer, Configurable, Kind, Output, Params, ParseError, Solution, Timings,
+};
+use std::io::BufRead;
+
+// solves the given parts of a puzzle for the given input
+pub type Solver = fn(&str, &[u8]) -> Result<Vec<Answer>, ParseError>;
+
+// same as Solver, with the extra details reported by the day for each part
+pub type OutputSolver = fn(&str, &[u8]) -> Result<Vec<Output>, ParseError>;
+
+// measures the parsing and each part of a puzzle for the given input
+pub type Timer = fn(&str) -> Result<Timings, ParseError>;
+
+// random input for the puzzle from a seed, of the given size
+pub type Generator = fn(u64, usize) -> String;
+
+// same as Solver, reading the input as it goes
+pub type StreamSolver = fn(&mut dyn BufRead, &[u8]) -> Result<Vec<Answer>, ParseError>;
+
+// same as OutputSolver, with the parts run with the given parameters
+pub type ConfiguredSolver = fn(&str, &[u8], &Params) -> Result<Vec<Output>, ParseError>;
+
+// whether the parameters set for a puzzle are valid ones, and why not
+pub type ParamsChecker = fn(&Params) -> Result<(), String>;
+
+// pictures of the solving of a puzzle for the given input
+pub type Animator = fn(&str) -> Result<Animation, ParseError>;
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solve: Solver,
+    pub solve_outputs: OutputSolver,
+    pub time: Timer,
+    pub generate: Generator,
+    // only for the days implementing Visual
+    pub animate: Option<Animator>,
+    // only for the days implementing Streaming
+    pub stream: Option<StreamSolver>,
+    // parameters of the days implementing Configurable, their checker and their solver
+    pub params: &'static [(&'static str, Kind)],
+    pub check_params: ParamsChecker,
+    pub solve_configured: Option<ConfiguredSolver>,
+    // sample input from the puzzle description
+    pub example: &'static str,
+}
+
+// the features of a day follow its puzzle, e.g. day!(2021, 11, Puzzle, visual, configurable)
+macro_rules! day {
+    ($year:literal, $day:literal, $puzzle:ty $(, $feature:ident)*) => {{
+        #[allow(unused_mut)]
+        let mut day = Day {
+            year: $year,
+            day: $day,
+            solve: aoc_common::solve::<$puzzle>,
+            solve_outputs: aoc_common::solve_outputs::<$puzzle>,
+            time: aoc_common::time::<$puzzle>,
+            generate: aoc_common::generate::<$puzzle>,
+            animate: None,
+            stream: None,
+            params: &[],
+            check_params: |params| params.check(&[]),
+            solve_configured: None,
+            example: <$puzzle as Solution>::EXAMPLE,
+        };
+        $(day!(@$feature day, $puzzle);)*
+        day
+    }};
+    (@visual $day:ident, $puzzle:ty) => {
+        $day.animate = Some(aoc_common::animate::<$puzzle>)
+    };
+    (@streaming $day:ident, $puzzle:ty) => {
+        $day.stream = Some(aoc_common::solve_stream::<$puzzle>)
+    };
+    (@configurable $day:ident, $puzzle:ty) => {
+        $day.params = <$puzzle as Configurable>::PARAMS;
+        $day.check_params = aoc_common::check_params::<$puzzle>;
+        $day.solve_configured = Some(aoc_common::solve_configured::<$puzzle>)
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2015, 1, aoc2015_day1::Puzzle, streaming, configurable),
+    day!(2015, 2, aoc2015_day2::Puzzle, streaming, configurable),
+    day!(2015, 3, aoc2015_day3::Puzzle),
+    day!(2015, 4, aoc2015_day4::Puzzle, configurable),
+    day!(2015, 5, aoc2015_day5::Puzzle, streaming),
+    day!(2015, 6, aoc2015_day6::Puzzle, visual),
+    day!(2015, 7, aoc2015_day7::Puzzle),
+    day!(2015, 8, aoc2015_day8::Puzzle, streaming),
+    day!(2015, 9, aoc2015_day9::Puzzle),
+    day!(2021, 1, aoc2021_day1::Puzzle, streaming),
+    day!(2021, 2, aoc2021_day2::Puzzle, streaming),
+    day!(2021, 3, aoc2021_day3::Puzzle),
+    day!(2021, 4, aoc2021_day4::Puzzle),
+    day!(2021, 5, aoc2021_day5::Puzzle, visual),
+    day!(2021, 6, aoc2021_day6::Puzzle, configurable),
+    day!(2021, 7, aoc2021_day7::Puzzle),
+    day!(2021, 8, aoc2021_day8::Puzzle),
+    day!(2021, 9, aoc2021_day9::Puzzle, visual),
+    // streamed, but keeping the score of every incomplete line
+    day!(2021, 10, aoc2021_day10::Puzzle, streaming),
+    day!(2021, 11, aoc2021_day11::Puzzle, visual, configurable),
+    day!(2021, 12, aoc2021_day12::Puzzle),
+    day!(2021, 13, aoc2021_day13::Puzzle, visual),
+    day!(2021, 14, aoc2021_day14::Puzzle, configurable),
+    day!(2021, 15, aoc2021_day15::Puzzle, visual),
+    day!(2021, 16, aoc2021_day16::Puzzle),
+    day!(2021, 17, aoc2021_day17::Puzzle),
+    day!(2021, 18, aoc2021_day18::Puzzle),
+];
+
+impl Day {
+    // the outputs of the parts with the parameters set for the day, if it has any
+    pub fn outputs_with(
+        &self,
+        input: &str,
+        parts: &[u8],
+        params: &Params,
+    ) -> Result<Vec<Output>, ParseError> {
+        match self.solve_configured {
+            Some(solve) => solve(input, parts, params),
+            None => (self.solve_outputs)(input, parts),
+        }
+    }
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Param;
+
+    #[test]
+    fn test_days_are_sorted_and_unique() {
+        let keys: Vec<(u16, u8)> = DAYS.iter().map(|d| (d.year, d.day)).collect();
+        let mut sorted = keys.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_find() {
+        assert!(find(2015, 1).is_some());
+        assert!(find(2021, 18).is_some());
+        assert!(find(2021, 25).is_none());
+    }
+
+    #[test]
+    fn test_examples_parse() {
+        for day in DAYS {
+            assert!(
+                (day.solve)(day.example, &[]).is_ok(),
+                "{} day {}",
+                day.year,
+                day.day
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = find(2021, 1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+        assert_eq!(
+            (day.solve)(input, &[1, 2]),
+            Ok(vec![Answer::Number(7), Answer::Number(5)])
+        );
+        assert_eq!((day.solve)(input, &[2]), Ok(vec![Answer::Number(5)]));
+        assert_eq!(
+            (day.solve)("199\n2OO", &[1]).unwrap_err().to_string(),
+            "line 2, column 1: Expected an integer measure \"2OO\""
+        );
+    }
+
+    #[test]
+    fn test_generated_inputs_parse() {
+        for day in DAYS {
+            let input = (day.generate)(1, 5);
+            assert_eq!(input, (day.generate)(1, 5));
+            assert!(
+                (day.solve)(&input, &[]).is_ok(),
+                "{} day {}",
+                day.year,
+                day.day
+            );
+        }
+    }
+
+    #[test]
+    fn test_stream() {
+        let day = find(2021, 1).unwrap();
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+        let stream = day.stream.unwrap();
+        assert_eq!(
+            stream(&mut input.as_bytes(), &[2]),
+            (day.solve)(input, &[2])
+        );
+        assert!(find(2021, 3).unwrap().stream.is_none());
+    }
+
+    #[test]
+    fn test_outputs_with() {
+        let day = find(2021, 14).unwrap();
+        let mut params = Params::default();
+        params.set("steps_part_1", Param::Count(10));
+        params.set("steps_part_2", Param::Count(1));
+        let outputs = day.outputs_with(day.example, &[1, 2], &params).unwrap();
+        assert_eq!(outputs[0].answer, Answer::Number(1588));
+        assert_eq!(outputs[1].answer, Answer::Number(1));
+        assert_eq!(day.params.len(), 2);
+        assert!(find(2021, 11).unwrap().animate.is_some());
+        assert_eq!(find(2021, 1).unwrap().params, &[]);
+    }
+
+    #[test]
+    fn test_animate() {
+        let day = find(2021, 11).unwrap();
+        let frames = (day.animate.unwrap())(day.example).unwrap();
+        // the initial state and the 195 steps until the octopi flash together
+        assert_eq!(frames.len(), 196);
+        assert!(find(2021, 1).unwrap().animate.is_none());
+    }
+
+    #[test]
+    fn test_solve_outputs() {
+        let day = find(2015, 9).unwrap();
+        let outputs = (day.solve_outputs)(day.example, &[1]).unwrap();
+        assert_eq!(outputs[0].answer, Answer::Number(605));
+        assert_eq!(
+            outputs[0].extra.as_ref().unwrap().to_string(),
+            r#"{"nodes":["London","Dublin","Belfast"]}"#
+        );
+        let day = find(2021, 1).unwrap();
+        let outputs = (day.solve_outputs)(day.example, &[1]).unwrap();
+        assert_eq!(outputs[0].extra, None);
+    }
+}
@@ -0,0 +1,145 @@
+// Puzzle inputs downloaded from adventofcode.com and cached on disk as
+// <inputs>/<year>/day<day>/input.txt, where the inputs of the repository already live.
+// A cached input is never downloaded again.
+
+use aoc_common::input_path;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// sent with every request to adventofcode.com
+pub const USER_AGENT: &str = concat!("aoc runner ", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+pub struct Fetcher {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Fetcher {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, String> {
+        let session = self.session.as_ref().ok_or(
+            "no session token, set session in aoc.toml or the AOC_SESSION environment variable",
+        )?;
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call();
+        let input = match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("cannot read the input from {}: {}", url, e))?,
+            Err(ureq::Error::Status(404, _)) => {
+                return Err(format!("{} day {} is not available yet", year, day))
+            }
+            Err(ureq::Error::Status(code @ (400 | 401 | 403 | 500), _)) => {
+                return Err(format!(
+                    "{} answered {}, is the session token valid?",
+                    url, code
+                ))
+            }
+            Err(ureq::Error::Status(code, _)) => return Err(format!("{} answered {}", url, code)),
+            Err(e) => return Err(format!("cannot reach {}: {}", url, e)),
+        };
+        if input.trim().is_empty() {
+            return Err(format!("{} answered an empty input", url));
+        }
+        Ok(input)
+    }
+
+    // the path of the day's input, downloaded first when not cached yet
+    pub fn fetch(&self, inputs: &Path, year: u16, day: u8) -> Result<Fetched, String> {
+        let path = input_path(inputs, year, day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let input = self.download(year, day)?;
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir).map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+        // written aside first so that an interrupted write doesn't leave a partial input
+        // in the cache
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, input)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, temp_dir, Request};
+
+    #[test]
+    fn test_fetch() {
+        let (url, requests) = serve(200, "1\n2\n3\n");
+        let inputs = temp_dir("fetch");
+        let fetcher = Fetcher::new(&url, Some("abc".to_string()));
+        let path = input_path(&inputs, 2021, 1);
+        assert_eq!(
+            fetcher.fetch(&inputs, 2021, 1),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(
+            requests.try_recv(),
+            Ok(Request {
+                method: "GET".to_string(),
+                url: "/2021/day/1/input".to_string(),
+                cookie: "session=abc".to_string(),
+                body: String::new(),
+            })
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+        // the cached input is used from now on
+        assert_eq!(fetcher.fetch(&inputs, 2021, 1), Ok(Fetched::Cached(path)));
+        assert!(requests.try_recv().is_err());
+        fs::remove_dir_all(&inputs).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let inputs = temp_dir("fetch-errors");
+        let (url, _requests) = serve(404, "Please don't repeatedly request this endpoint");
+        let fetcher = Fetcher::new(&url, Some("abc".to_string()));
+        assert_eq!(
+            fetcher.fetch(&inputs, 2030, 1),
+            Err("2030 day 1 is not available yet".to_string())
+        );
+        let (url, _requests) = serve(400, "Puzzle inputs differ by user");
+        let fetcher = Fetcher::new(&url, Some("expired".to_string()));
+        assert_eq!(
+            fetcher.fetch(&inputs, 2021, 1),
+            Err(format!(
+                "{}/2021/day/1/input answered 400, is the session token valid?",
+                url
+            ))
+        );
+        let fetcher = Fetcher::new(&url, None);
+        assert!(fetcher
+            .fetch(&inputs, 2021, 1)
+            .unwrap_err()
+            .starts_with("no session token"));
+        // nothing was cached
+        assert!(!inputs.exists());
+    }
+}
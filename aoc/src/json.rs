@@ -0,0 +1,55 @@
+// JSON Lines output of the runner: one record per part with its answer and the details
+// the day reports about it, e.g.
+//
+//     {"year":2015,"day":9,"part":1,"answer":605,"extra":{"nodes":["London","Dublin","Belfast"]}}
+
+use aoc_common::{Answer, Output};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    extra: &'a Option<Value>,
+}
+
+// one line for each of the given parts and their outputs
+pub fn format_records(year: u16, day: u8, parts: &[u8], outputs: &[Output]) -> String {
+    let mut json = String::new();
+    for (&part, output) in parts.iter().zip(outputs) {
+        let record = Record {
+            year,
+            day,
+            part,
+            answer: &output.answer,
+            extra: &output.extra,
+        };
+        json.push_str(&serde_json::to_string(&record).expect("Records must serialize"));
+        json.push('\n');
+    }
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_records() {
+        let outputs = vec![
+            Output::with_extra(45, &serde_json::json!({"velocity": {"x": 7, "y": 9}})),
+            Output::from(".#\n#."),
+            Output::from(aoc_common::Unsolved),
+        ];
+        assert_eq!(
+            format_records(2021, 17, &[1, 2, 2], &outputs),
+            r##"{"year":2021,"day":17,"part":1,"answer":45,"extra":{"velocity":{"x":7,"y":9}}}
+{"year":2021,"day":17,"part":2,"answer":".#\n#.","extra":null}
+{"year":2021,"day":17,"part":2,"answer":null,"extra":null}
+"##
+        );
+    }
+}
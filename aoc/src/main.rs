@@ -0,0 +1,688 @@
+mod answers;
+mod config;
+mod dashboard;
+mod days;
+mod fetch;
+mod json;
+#[cfg(test)]
+mod mock;
+mod scaffold;
+mod stress;
+mod submit;
+mod table;
+mod timing;
+
+use answers::{check, Answers, Report};
+use aoc_common::{Answer, Format, Input, TrackingAllocator};
+use clap::{Parser, Subcommand};
+use config::{Config, OutputFormat};
+use dashboard::Status;
+use days::{Day, DAYS};
+use fetch::{Fetched, Fetcher};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use submit::Submitter;
+
+// needed to report the peak memory of each day
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+// inputs live next to each day's crate unless configured otherwise:
+// <root>/<year>/day<day>/input.txt
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+#[derive(Parser)]
+#[command(
+    about = "Run Advent of Code solutions",
+    args_conflicts_with_subcommands = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Puzzle year, e.g. 2021
+    #[arg(required = true)]
+    year: Option<u16>,
+    /// Puzzle day, e.g. 15
+    #[arg(required = true)]
+    day: Option<u8>,
+    /// Only run the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from this file instead of the day's input.txt, "-" for stdin
+    #[arg(long)]
+    input: Option<String>,
+    /// Use the sample input from the puzzle description
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+    /// Print one JSON record per part, with the day's extra details
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every implemented day in parallel and print a dashboard of their answers
+    All {
+        /// Print one JSON record per part instead of a dashboard
+        #[arg(long)]
+        json: bool,
+        /// Number of days run at once, one per CPU by default
+        #[arg(long)]
+        threads: Option<usize>,
+    },
+    /// Run every day and record its answers to the answers file
+    Record {
+        /// Answers file, answers.txt at the root of the repository by default
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Run every day and compare its answers with the recorded ones
+    Verify {
+        /// Answers file, answers.txt at the root of the repository by default
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Download the input of a day to the inputs directory, unless already there
+    Fetch {
+        /// Puzzle year, e.g. 2021
+        year: u16,
+        /// Puzzle day, e.g. 15
+        day: u8,
+    },
+    /// Create the crate of a new day and register it with the runner
+    New {
+        /// Puzzle year, e.g. 2021
+        year: u16,
+        /// Puzzle day, e.g. 15
+        day: u8,
+    },
+    /// Submit the answer to a part of a day, unless it is known to be wrong already
+    Submit {
+        /// Puzzle year, e.g. 2021
+        year: u16,
+        /// Puzzle day, e.g. 15
+        day: u8,
+        /// Puzzle part
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this answer instead of the one computed on the day's input, e.g. the
+        /// letters drawn by an answer in ASCII art
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Time the parsing and each part of every day, or of the given year or day
+    Time {
+        /// Only time the days of this year
+        year: Option<u16>,
+        /// Only time this day
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Print comma separated values instead of a table
+        #[arg(long)]
+        csv: bool,
+    },
+    /// Save pictures of how a day is solved, one image file per frame
+    Render {
+        /// Puzzle year, e.g. 2021
+        year: u16,
+        /// Puzzle day, e.g. 11
+        day: u8,
+        /// Directory of the frames, frames/<year>/day<day> at the root of the repository by
+        /// default
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Size in pixels of a cell of the grids
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+        /// Save grey level PGM images instead of colour PPM images
+        #[arg(long)]
+        pgm: bool,
+        /// Use the sample input from the puzzle description
+        #[arg(long)]
+        example: bool,
+    },
+    /// Print a random input of a day, the same for the same seed and size
+    Generate {
+        /// Puzzle year, e.g. 2021
+        year: u16,
+        /// Puzzle day, e.g. 16
+        day: u8,
+        /// Seed of the random input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Size of the random input, e.g. its number of lines
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Solve random inputs with every day, or the given year or day, and report the
+    /// first input a day fails on
+    Stress {
+        /// Only stress the days of this year
+        year: Option<u16>,
+        /// Only stress this day
+        #[arg(requires = "year")]
+        day: Option<u8>,
+        /// Number of random inputs per day
+        #[arg(long, default_value_t = 20)]
+        runs: u64,
+        /// Size of the random inputs
+        #[arg(long, default_value_t = 20)]
+        size: usize,
+        /// Seed of the first random input, the next ones use the following seeds
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+fn default_config() -> PathBuf {
+    PathBuf::from(ROOT).join("aoc.toml")
+}
+
+fn default_input(inputs: &Path, day: &Day) -> PathBuf {
+    aoc_common::input_path(inputs, day.year, day.day)
+}
+
+fn default_frames(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(ROOT)
+        .join("frames")
+        .join(year.to_string())
+        .join(format!("day{}", day))
+}
+
+fn default_answers() -> PathBuf {
+    PathBuf::from(ROOT).join("answers.txt")
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    Input::File(path.to_path_buf()).read()
+}
+
+// input chosen on the command line, the day's input.txt by default
+fn day_input<'a>(inputs: &Path, day: &'a Day, input: Option<&str>, example: bool) -> Input<'a> {
+    match input {
+        _ if example => Input::Example(day.example),
+        Some(arg) => Input::from_arg(arg),
+        None => Input::File(default_input(inputs, day)),
+    }
+}
+
+// a missing file, with how to download it when it is the day's cached input
+fn check_exists(inputs: &Path, day: &Day, source: &Input) -> Result<(), String> {
+    match source {
+        Input::File(path) if path.exists() => Ok(()),
+        Input::File(path) if *path == default_input(inputs, day) => Err(format!(
+            "missing input {}, run \"aoc fetch {} {}\" to download it",
+            path.display(),
+            day.year,
+            day.day
+        )),
+        Input::File(path) => Err(format!("cannot read {}", path.display())),
+        _ => Ok(()),
+    }
+}
+
+// with the parameters and the output format configured for the day
+fn run_day(
+    config: &Config,
+    inputs: &Path,
+    (year, day): (u16, u8),
+    part: Option<u8>,
+    input: Option<&str>,
+    example: bool,
+    json: bool,
+) -> Result<(), String> {
+    let day = days::find(year, day).ok_or(format!("{} day {} is not implemented", year, day))?;
+    let source = day_input(inputs, day, input, example);
+    check_exists(inputs, day, &source)?;
+    let parts = match part {
+        Some(p) => vec![p],
+        None => vec![1, 2],
+    };
+    let params = config.params(day.year, day.day);
+    if json || config.format == OutputFormat::Json {
+        let input = source.read()?;
+        let outputs = day
+            .outputs_with(&input, &parts, &params)
+            .map_err(|e| format!("invalid input {}: {}", source, e))?;
+        print!(
+            "{}",
+            json::format_records(day.year, day.day, &parts, &outputs)
+        );
+        return Ok(());
+    }
+    let answers = match day.stream {
+        Some(stream) if params.is_empty() => stream(&mut source.reader()?, &parts),
+        _ => day
+            .outputs_with(&source.read()?, &parts, &params)
+            .map(|outputs| outputs.into_iter().map(|o| o.answer).collect()),
+    }
+    .map_err(|e| format!("invalid input {}: {}", source, e))?;
+    for (p, answer) in parts.iter().zip(answers) {
+        if answer.is_multiline() {
+            print!("Part {}:\n{}", p, answer);
+        } else {
+            println!("Part {}: {}", p, answer);
+        }
+    }
+    Ok(())
+}
+
+// the days of the configured years, or of the given year, or the given day
+fn selected_days(config: &Config, year: Option<u16>, day: Option<u8>) -> Vec<&'static Day> {
+    DAYS.iter()
+        .filter(|d| year.map_or(config.runs_year(d.year), |y| d.year == y))
+        .filter(|d| day.is_none_or(|n| d.day == n))
+        .collect()
+}
+
+// every day at once, as a dashboard or as JSON records of the days solved
+fn run_all(config: &Config, inputs: &Path) -> Result<(), String> {
+    let start = Instant::now();
+    let runs = dashboard::run_days(&selected_days(config, None, None), inputs, config)?;
+    if config.format == OutputFormat::Json {
+        for run in &runs {
+            match &run.status {
+                Status::Solved(outputs) => print!(
+                    "{}",
+                    json::format_records(run.year, run.day, &[1, 2], outputs)
+                ),
+                Status::MissingInput => {
+                    eprintln!("warning: {} day {}: missing input", run.year, run.day)
+                }
+                _ => {}
+            }
+        }
+    } else {
+        print!("{}", dashboard::format_dashboard(&runs, start.elapsed()));
+    }
+    for e in dashboard::errors(&runs) {
+        eprintln!("error: {}", e);
+    }
+    Ok(())
+}
+
+// result of running both parts of a day on its input.txt
+enum Outcome {
+    MissingInput,
+    InvalidInput(String),
+    Solved(Vec<Answer>),
+}
+
+fn solve_day(inputs: &Path, day: &Day) -> Outcome {
+    match read_input(&default_input(inputs, day)) {
+        Ok(input) => match (day.solve)(&input, &[1, 2]) {
+            Ok(answers) => Outcome::Solved(answers),
+            Err(e) => Outcome::InvalidInput(e.to_string()),
+        },
+        Err(_) => Outcome::MissingInput,
+    }
+}
+
+// days whose input is missing or invalid keep their previously recorded answers
+fn record(inputs: &Path, path: PathBuf) -> Result<(), String> {
+    let mut answers = Answers::load(&path)?;
+    for day in DAYS {
+        match solve_day(inputs, day) {
+            Outcome::Solved(solved) => {
+                for (part, answer) in (1..).zip(&solved) {
+                    answers.record((day.year, day.day, part), answer);
+                }
+            }
+            Outcome::MissingInput => {
+                eprintln!("warning: {} day {}: missing input", day.year, day.day)
+            }
+            Outcome::InvalidInput(e) => {
+                eprintln!(
+                    "warning: {} day {}: invalid input: {}",
+                    day.year, day.day, e
+                )
+            }
+        }
+    }
+    answers.save(&path)?;
+    println!("Recorded {} answers to {}", answers.len(), path.display());
+    Ok(())
+}
+
+fn verify(inputs: &Path, path: PathBuf) -> Result<bool, String> {
+    let answers = Answers::load(&path)?;
+    if answers.is_empty() {
+        return Err(format!(
+            "no answers recorded in {}, run the record command first",
+            path.display()
+        ));
+    }
+    let mut report = Report::default();
+    for day in DAYS {
+        match solve_day(inputs, day) {
+            Outcome::Solved(solved) => {
+                for (part, answer) in (1..).zip(&solved) {
+                    let key = (day.year, day.day, part);
+                    report.part(key, check(answers.get(key), answer));
+                }
+            }
+            Outcome::MissingInput => report.missing_input(day.year, day.day),
+            Outcome::InvalidInput(e) => report.invalid_input(day.year, day.day, &e),
+        }
+    }
+    print!("{}", report);
+    Ok(report.is_success())
+}
+
+fn time(
+    config: &Config,
+    inputs: &Path,
+    (year, day): (Option<u16>, Option<u8>),
+    csv: bool,
+) -> Result<(), String> {
+    let days = selected_days(config, year, day);
+    if days.is_empty() {
+        return Err("no implemented day matches".to_string());
+    }
+    if cfg!(debug_assertions) {
+        eprintln!("warning: timing a debug build, use --release for meaningful numbers");
+    }
+    let rows: Vec<timing::Row> = days
+        .into_iter()
+        .map(|day| {
+            let timings = match read_input(&default_input(inputs, day)) {
+                Ok(input) => (day.time)(&input).map_err(|_| "invalid input".to_string()),
+                Err(_) => Err("missing input".to_string()),
+            };
+            (day.year, day.day, timings)
+        })
+        .collect();
+    if csv {
+        print!("{}", timing::format_csv(&rows));
+    } else {
+        print!("{}", timing::format_table(&rows));
+    }
+    Ok(())
+}
+
+fn render(
+    inputs: &Path,
+    (year, day): (u16, u8),
+    out: &Path,
+    scale: u16,
+    format: Format,
+    example: bool,
+) -> Result<(), String> {
+    let day = days::find(year, day).ok_or(format!("{} day {} is not implemented", year, day))?;
+    let animate = day
+        .animate
+        .ok_or(format!("{} day {} has nothing to render", year, day.day))?;
+    let source = day_input(inputs, day, None, example);
+    let input = source.read()?;
+    let animation = animate(&input).map_err(|e| format!("invalid input {}: {}", source, e))?;
+    let paths = animation
+        .scaled(usize::from(scale))
+        .save(format, out)
+        .map_err(|e| format!("cannot write frames to {}: {}", out.display(), e))?;
+    println!("Wrote {} frames to {}", paths.len(), out.display());
+    Ok(())
+}
+
+fn generate(year: u16, day: u8, seed: u64, size: usize) -> Result<(), String> {
+    let day = days::find(year, day).ok_or(format!("{} day {} is not implemented", year, day))?;
+    print!("{}", (day.generate)(seed, size));
+    Ok(())
+}
+
+// whether every day solved all its inputs
+fn stress(
+    config: &Config,
+    (year, day): (Option<u16>, Option<u8>),
+    runs: u64,
+    size: usize,
+    seed: u64,
+) -> Result<bool, String> {
+    let days = selected_days(config, year, day);
+    if days.is_empty() {
+        return Err("no implemented day matches".to_string());
+    }
+    let results = stress::stress_days(&days, seed..seed.saturating_add(runs), size);
+    print!("{}", stress::format_results(&results, size));
+    Ok(results.iter().all(|(_, result)| result.is_ok()))
+}
+
+fn fetch(config: &Config, inputs: &Path, year: u16, day: u8) -> Result<(), String> {
+    let fetcher = Fetcher::new(config.base_url(), config.session());
+    match fetcher.fetch(inputs, year, day)? {
+        Fetched::Cached(path) => println!("Already fetched to {}", path.display()),
+        Fetched::Downloaded(path) => println!("Fetched to {}", path.display()),
+    }
+    Ok(())
+}
+
+// the answer to a part computed on the day's input
+fn computed_answer(inputs: &Path, year: u16, day: u8, part: u8) -> Result<String, String> {
+    let day = days::find(year, day).ok_or(format!("{} day {} is not implemented", year, day))?;
+    let path = default_input(inputs, day);
+    let input = read_input(&path)?;
+    let answers = (day.solve)(&input, &[part])
+        .map_err(|e| format!("invalid input {}: {}", path.display(), e))?;
+    match &answers[0] {
+        Answer::Unsolved => Err(format!("part {} is not solved", part)),
+        answer if answer.is_multiline() => Err(format!(
+            "the answer to part {} spans several lines, read it and submit it with --answer:\n{}",
+            part, answer
+        )),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+fn submit(
+    config: &Config,
+    inputs: &Path,
+    (year, day, part): (u16, u8, u8),
+    answer: Option<String>,
+) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => computed_answer(inputs, year, day, part)?,
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_secs();
+    let submitter = Submitter::new(config.base_url(), config.session());
+    let response = submitter.submit(inputs, (year, day, part), &answer, now)?;
+    print!(
+        "{} day {} part {}: {} is {}",
+        year, day, part, answer, response.verdict
+    );
+    if response.wait > 0 {
+        print!(", wait {}s before submitting again", response.wait);
+    }
+    println!();
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let path = default_config();
+    let mut config = Config::load(&path)
+        .and_then(|config| {
+            config
+                .check(DAYS)
+                .map_err(|e| format!("invalid {}: {}", path.display(), e))?;
+            Ok(config)
+        })
+        .unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            exit(1);
+        });
+    let inputs = &config.inputs(Path::new(ROOT));
+    match cli.command {
+        Some(Command::Fetch { year, day }) => {
+            if let Err(e) = fetch(&config, inputs, year, day) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+        Some(Command::New { year, day }) => match scaffold::new_day(Path::new(ROOT), year, day) {
+            Ok(dir) => println!(
+                "Created {}, fetch its input with \"aoc fetch {} {}\"",
+                dir.display(),
+                year,
+                day
+            ),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        },
+        Some(Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        }) => {
+            if let Err(e) = submit(&config, inputs, (year, day, part), answer) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+        Some(Command::Time { year, day, csv }) => {
+            if let Err(e) = time(&config, inputs, (year, day), csv) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+        Some(Command::Render {
+            year,
+            day,
+            out,
+            scale,
+            pgm,
+            example,
+        }) => {
+            let out = out.unwrap_or_else(|| default_frames(year, day));
+            let format = if pgm { Format::Pgm } else { Format::Ppm };
+            if let Err(e) = render(inputs, (year, day), &out, scale, format, example) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+        Some(Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+        }) => {
+            if let Err(e) = generate(year, day, seed, size) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+        Some(Command::Stress {
+            year,
+            day,
+            runs,
+            size,
+            seed,
+        }) => match stress(&config, (year, day), runs, size, seed) {
+            Ok(true) => {}
+            Ok(false) => exit(1),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        },
+        Some(Command::All { json, threads }) => {
+            config.threads = threads.or(config.threads);
+            if json {
+                config.format = OutputFormat::Json;
+            }
+            if let Err(e) = run_all(&config, inputs) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+        Some(Command::Record { answers }) => {
+            if let Err(e) = record(inputs, answers.unwrap_or_else(default_answers)) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+        Some(Command::Verify { answers }) => {
+            match verify(inputs, answers.unwrap_or_else(default_answers)) {
+                Ok(true) => {}
+                Ok(false) => exit(1),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    exit(1);
+                }
+            }
+        }
+        None => {
+            let (year, day) = (cli.year.unwrap(), cli.day.unwrap());
+            let input = cli.input.as_deref();
+            if let Err(e) = run_day(
+                &config,
+                inputs,
+                (year, day),
+                cli.part,
+                input,
+                cli.example,
+                cli.json,
+            ) {
+                eprintln!("error: {}", e);
+                exit(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_input() {
+        let day = days::find(2021, 1).unwrap();
+        let inputs = Path::new("/inputs");
+        assert_eq!(
+            day_input(inputs, day, None, false),
+            Input::File(PathBuf::from("/inputs/2021/day1/input.txt"))
+        );
+        assert_eq!(day_input(inputs, day, Some("-"), false), Input::Stdin);
+        assert_eq!(
+            day_input(inputs, day, Some("other.txt"), false),
+            Input::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            day_input(inputs, day, None, true),
+            Input::Example(day.example)
+        );
+    }
+
+    #[test]
+    fn test_check_exists() {
+        let day = days::find(2021, 1).unwrap();
+        let inputs = Path::new("/nonexistent/inputs");
+        assert_eq!(
+            check_exists(inputs, day, &day_input(inputs, day, None, false)),
+            Err(
+                "missing input /nonexistent/inputs/2021/day1/input.txt, run \"aoc fetch 2021 1\" \
+                 to download it"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            check_exists(
+                inputs,
+                day,
+                &day_input(inputs, day, Some("/some/path"), false)
+            ),
+            Err("cannot read /some/path".to_string())
+        );
+        assert_eq!(
+            check_exists(inputs, day, &Input::File(PathBuf::from(ROOT))),
+            Ok(())
+        );
+        assert_eq!(check_exists(inputs, day, &Input::Stdin), Ok(()));
+    }
+}
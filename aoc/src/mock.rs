@@ -0,0 +1,51 @@
+// Local stand-in for adventofcode.com and scratch directories used by the tests of the
+// HTTP clients
+
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+
+// what the server got: method, url, session cookie and body
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: String,
+    pub body: String,
+}
+
+// serves the given status and body to every request on a free local port, returns the
+// base URL of the server and the requests it gets
+pub fn serve(status: u16, body: &'static str) -> (String, mpsc::Receiver<Request>) {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for mut request in server.incoming_requests() {
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string())
+                .unwrap_or_default();
+            let mut content = String::new();
+            request.as_reader().read_to_string(&mut content).unwrap();
+            let _ = sender.send(Request {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                cookie,
+                body: content,
+            });
+            let response = tiny_http::Response::from_string(body).with_status_code(status);
+            request.respond(response).unwrap();
+        }
+    });
+    (url, receiver)
+}
+
+// empty directory for the files written by a test, not created yet
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
@@ -0,0 +1,349 @@
+// Generator of the crate of a new day, with the same layout as the existing ones:
+//
+//     <year>/day<day>/Cargo.toml
+//     <year>/day<day>/src/main.rs   binary running the day on its input
+//     <year>/day<day>/src/lib.rs    Solution stub and tests on its sample input
+//
+// The crate is added to the workspace members unless a <year>/day* glob already covers
+// it, and the day is registered with the runner, as a dependency in its manifest and in
+// DAYS, so that it builds and runs right away. Years can hold days solved in other
+// languages (e.g. 2022), so a new glob is never added for them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn crate_name(year: u16, day: u8) -> String {
+    format!("aoc{}-day{}", year, day)
+}
+
+fn manifest(year: u16, day: u8) -> String {
+    format!(
+        "[package]
+name = \"{}\"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc-common.workspace = true
+",
+        crate_name(year, day)
+    )
+}
+
+fn main_rs(year: u16, day: u8) -> String {
+    format!(
+        "fn main() {{
+    aoc_common::main::<aoc{}_day{}::Puzzle>();
+}}
+",
+        year, day
+    )
+}
+
+const LIB_RS: &str =
+    "use aoc_common::{parse_lines, Generate, ParseError, Solution, StdRng, Unsolved};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<String>;
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    const EXAMPLE: &'static str = \"\";
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        parse_lines(input, |line| Ok(line.to_string()))
+    }
+
+    fn part1(_input: &Vec<String>) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(_input: &Vec<String>) -> Unsolved {
+        Unsolved
+    }
+}
+
+// the example until random inputs can be made up
+impl Generate for Puzzle {
+    fn random_input(_rng: &mut StdRng, _size: usize) -> String {
+        Puzzle::EXAMPLE.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = Puzzle::parse(Puzzle::EXAMPLE).unwrap();
+        assert_eq!(Puzzle::part1(&input), Unsolved);
+        assert_eq!(Puzzle::part2(&input), Unsolved);
+    }
+}
+";
+
+// year and day of lines registering a day, e.g. "aoc2021-day7 = ..." or
+// "day!(2021, 7, ...)"
+fn registered_day(line: &str, prefix: &str, separator: &str) -> Option<(u16, u8)> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    let (year, rest) = rest.split_once(separator)?;
+    let day: String = rest
+        .trim_start()
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+// inserts the line of the day among the lines registering days, sorted by year and day
+fn insert_sorted(
+    text: &str,
+    (year, day): (u16, u8),
+    line: &str,
+    registered: impl Fn(&str) -> Option<(u16, u8)>,
+) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, (u16, u8))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(n, l)| registered(l).map(|key| (n, key)))
+        .collect();
+    if days.iter().any(|&(_, key)| key == (year, day)) {
+        return Err(format!("{} day {} is already registered", year, day));
+    }
+    let (last, _) = days.last().ok_or("no registered day to insert after")?;
+    let position = days
+        .iter()
+        .find(|&&(_, key)| key > (year, day))
+        .map_or(last + 1, |&(n, _)| n);
+    let mut output: Vec<&str> = lines[..position].to_vec();
+    output.push(line);
+    output.extend(&lines[position..]);
+    Ok(output.join("\n") + "\n")
+}
+
+// the runner's manifest with the day as a dependency
+pub fn register_dependency(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let line = format!(
+        "{} = {{ path = \"../{}/day{}\" }}",
+        crate_name(year, day),
+        year,
+        day
+    );
+    insert_sorted(manifest, (year, day), &line, |l| {
+        registered_day(l, "aoc", "-day")
+    })
+}
+
+// the runner's registry of days with the day added to DAYS
+pub fn register_day(days: &str, year: u16, day: u8) -> Result<String, String> {
+    let line = format!(
+        "    day!({}, {}, aoc{}_day{}::Puzzle),",
+        year, day, year, day
+    );
+    insert_sorted(days, (year, day), &line, |l| {
+        registered_day(l, "day!(", ",")
+    })
+}
+
+// the workspace manifest with the crate of the day as a member
+pub fn register_member(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let member = format!("\"{}/day{}\"", year, day);
+    let glob = format!("\"{}/day*\"", year);
+    let mut found = false;
+    let lines: Vec<String> = manifest
+        .lines()
+        .map(|line| match line.strip_prefix("members = [") {
+            Some(members) if !members.contains(&member) && !members.contains(&glob) => {
+                found = true;
+                let members = members.strip_suffix(']').unwrap_or(members);
+                format!("members = [{}, {}]", members, member)
+            }
+            Some(_) => {
+                found = true;
+                line.to_string()
+            }
+            None => line.to_string(),
+        })
+        .collect();
+    if !found {
+        return Err("no single line workspace members to add the day to".to_string());
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path.display(), e))
+}
+
+// creates the crate of the day in the repository at the given root and registers it
+// with the runner, returns the directory of the crate
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {}, expected 1 to 25", day));
+    }
+    let dir = root.join(year.to_string()).join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    // registered first so that nothing is left behind when the day can't be
+    let workspace = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let registry = root.join("aoc").join("src").join("days.rs");
+    let workspace_text = register_member(&read(&workspace)?, year, day)?;
+    let manifest_text = register_dependency(&read(&runner_manifest)?, year, day)?;
+    let registry_text = register_day(&read(&registry)?, year, day)?;
+    let src = dir.join("src");
+    fs::create_dir_all(&src).map_err(|e| format!("cannot create {}: {}", src.display(), e))?;
+    write(&dir.join("Cargo.toml"), &manifest(year, day))?;
+    write(&src.join("main.rs"), &main_rs(year, day))?;
+    write(&src.join("lib.rs"), LIB_RS)?;
+    write(&workspace, &workspace_text)?;
+    write(&runner_manifest, &manifest_text)?;
+    write(&registry, &registry_text)?;
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::temp_dir;
+
+    const MANIFEST: &str = "[package]
+name = \"aoc\"
+
+[dependencies]
+clap = { version = \"4\", features = [\"derive\"] }
+aoc-common = { path = \"../common\" }
+aoc2015-day9 = { path = \"../2015/day9\" }
+aoc2021-day1 = { path = \"../2021/day1\" }
+aoc2021-day18 = { path = \"../2021/day18\" }
+
+[dev-dependencies]
+tiny_http = \"0.12\"
+";
+
+    const DAYS: &str = "pub const DAYS: &[Day] = &[
+    day!(2015, 9, aoc2015_day9::Puzzle),
+    day!(2021, 1, aoc2021_day1::Puzzle),
+    day!(2021, 18, aoc2021_day18::Puzzle),
+];
+";
+
+    #[test]
+    fn test_register_dependency() {
+        let manifest = register_dependency(MANIFEST, 2021, 7).unwrap();
+        assert!(manifest.contains(
+            "aoc2021-day1 = { path = \"../2021/day1\" }
+aoc2021-day7 = { path = \"../2021/day7\" }
+aoc2021-day18 = { path = \"../2021/day18\" }
+"
+        ));
+        let manifest = register_dependency(MANIFEST, 2021, 19).unwrap();
+        assert!(manifest.contains(
+            "aoc2021-day18 = { path = \"../2021/day18\" }
+aoc2021-day19 = { path = \"../2021/day19\" }
+
+[dev-dependencies]"
+        ));
+        assert_eq!(
+            register_dependency(MANIFEST, 2021, 1),
+            Err("2021 day 1 is already registered".to_string())
+        );
+    }
+
+    #[test]
+    fn test_register_day() {
+        assert_eq!(
+            register_day(DAYS, 2015, 10).unwrap(),
+            "pub const DAYS: &[Day] = &[
+    day!(2015, 9, aoc2015_day9::Puzzle),
+    day!(2015, 10, aoc2015_day10::Puzzle),
+    day!(2021, 1, aoc2021_day1::Puzzle),
+    day!(2021, 18, aoc2021_day18::Puzzle),
+];
+"
+        );
+        assert!(register_day(DAYS, 2014, 1).unwrap().starts_with(
+            "pub const DAYS: &[Day] = &[\n    day!(2014, 1, aoc2014_day1::Puzzle),\n"
+        ));
+    }
+
+    #[test]
+    fn test_register_member() {
+        let workspace = "[workspace]\nmembers = [\"common\", \"aoc\", \"2015/day*\"]\n";
+        assert_eq!(register_member(workspace, 2015, 10).unwrap(), workspace);
+        let added = register_member(workspace, 2016, 1).unwrap();
+        assert_eq!(
+            added,
+            "[workspace]\nmembers = [\"common\", \"aoc\", \"2015/day*\", \"2016/day1\"]\n"
+        );
+        assert_eq!(register_member(&added, 2016, 1).unwrap(), added);
+        assert!(register_member("[package]\n", 2016, 1).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = temp_dir("scaffold");
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\"]\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+        let dir = new_day(&root, 2021, 19).unwrap();
+        assert_eq!(dir, root.join("2021/day19"));
+        assert_eq!(
+            fs::read_to_string(dir.join("src/main.rs")).unwrap(),
+            "fn main() {\n    aoc_common::main::<aoc2021_day19::Puzzle>();\n}\n"
+        );
+        assert!(fs::read_to_string(dir.join("Cargo.toml"))
+            .unwrap()
+            .contains("name = \"aoc2021-day19\""));
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("members = [\"aoc\", \"2021/day19\"]"));
+        assert!(fs::read_to_string(root.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("day!(2021, 19, aoc2021_day19::Puzzle)"));
+        assert_eq!(
+            new_day(&root, 2021, 19),
+            Err(format!("{} already exists", dir.display()))
+        );
+        assert_eq!(
+            new_day(&root, 2021, 26),
+            Err("invalid day 26, expected 1 to 25".to_string())
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_next_to_other_languages() {
+        let root = temp_dir("scaffold-languages");
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        // a day solved in Python, without a crate
+        fs::create_dir_all(root.join("2022/day1")).unwrap();
+        fs::write(root.join("2022/day1/solution.py"), "").unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"aoc\"]\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+        new_day(&root, 2022, 16).unwrap();
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("members = [\"aoc\", \"2022/day16\"]"));
+        assert!(!workspace.contains("2022/day*"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,119 @@
+// Runs the days on random inputs made up by their generators, to find the inputs a
+// solution fails on before a real one does. Each input comes from a seed, so that a
+// failure can be looked at again with "aoc generate".
+
+use crate::dashboard::panic_message;
+use crate::days::Day;
+use rayon::prelude::*;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+
+// the first input a day failed on
+#[derive(Debug, PartialEq)]
+pub struct Failure {
+    pub seed: u64,
+    pub error: String,
+}
+
+// both parts of the day on an input of the given size for each seed, stops at the first
+// invalid input or panic
+pub fn stress_day(day: &Day, seeds: Range<u64>, size: usize) -> Result<usize, Failure> {
+    let count = seeds.end.saturating_sub(seeds.start) as usize;
+    for seed in seeds {
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            let input = (day.generate)(seed, size);
+            (day.solve)(&input, &[1, 2])
+        }));
+        let error = match solved {
+            Ok(Ok(_)) => continue,
+            Ok(Err(e)) => format!("invalid input: {}", e),
+            Err(payload) => format!("panicked: {}", panic_message(payload)),
+        };
+        return Err(Failure { seed, error });
+    }
+    Ok(count)
+}
+
+// every given day in parallel, in the order of days
+pub fn stress_days<'a>(
+    days: &[&'a Day],
+    seeds: Range<u64>,
+    size: usize,
+) -> Vec<(&'a Day, Result<usize, Failure>)> {
+    // panics are reported with the day and seed, not as they happen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = days
+        .par_iter()
+        .map(|&day| (day, stress_day(day, seeds.clone(), size)))
+        .collect();
+    panic::set_hook(hook);
+    results
+}
+
+// a line per day, e.g. "2021 day 1: 100 inputs solved"
+pub fn format_results(results: &[(&Day, Result<usize, Failure>)], size: usize) -> String {
+    results
+        .iter()
+        .map(|(day, result)| match result {
+            Ok(count) => format!("{} day {}: {} inputs solved\n", day.year, day.day, count),
+            Err(Failure { seed, error }) => format!(
+                "{} day {}: {} with seed {}, see the input with \"aoc generate {} {} --seed {} --size {}\"\n",
+                day.year, day.day, error, seed, day.year, day.day, seed, size
+            ),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Answer, ParseError, Timings};
+
+    // inputs are the seeds, the day panics on 13 and rejects 7
+    fn day() -> Day {
+        Day {
+            year: 2015,
+            day: 1,
+            solve: |input, _| match input {
+                "13" => panic!("unlucky"),
+                "7" => Err(ParseError::input("not a lucky number")),
+                _ => Ok(vec![Answer::Number(1), Answer::Number(2)]),
+            },
+            solve_outputs: |_, _| Ok(vec![]),
+            time: |_| Ok(Timings::default()),
+            generate: |seed, _| seed.to_string(),
+            animate: None,
+            stream: None,
+            params: &[],
+            check_params: |params| params.check(&[]),
+            solve_configured: None,
+            example: "",
+        }
+    }
+
+    #[test]
+    fn test_stress_day() {
+        let day = day();
+        assert_eq!(stress_day(&day, 0..7, 10), Ok(7));
+        assert_eq!(
+            stress_day(&day, 0..20, 10),
+            Err(Failure {
+                seed: 7,
+                error: "invalid input: not a lucky number".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_stress_days() {
+        let day = day();
+        let results = stress_days(&[&day], 10..20, 5);
+        assert_eq!(
+            format_results(&results, 5),
+            "2015 day 1: panicked: unlucky with seed 13, see the input with \"aoc generate 2015 1 --seed 13 --size 5\"\n"
+        );
+        let results = stress_days(&[&day], 14..20, 5);
+        assert_eq!(format_results(&results, 5), "2015 day 1: 6 inputs solved\n");
+    }
+}
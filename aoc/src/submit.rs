@@ -0,0 +1,513 @@
+// Answers submitted to adventofcode.com, with a history per day kept next to its input
+// as <inputs>/<year>/day<day>/submissions.txt so that:
+// - the same wrong answer is never sent twice, nor one on the wrong side of an answer
+//   known to be too high or too low
+// - nothing is sent for a part already answered correctly
+// - the cooldown asked for by the server is waited for before sending again
+//
+// The history has one submission per line: the unix time it was sent at, the part, the
+// verdict, the seconds to wait before the next one and the answer, separated by spaces.
+
+use crate::fetch::USER_AGENT;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// where the submissions of a day are kept
+pub fn history_path(inputs: &Path, year: u16, day: u8) -> PathBuf {
+    inputs
+        .join(year.to_string())
+        .join(format!("day{}", day))
+        .join("submissions.txt")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    // submitted during the cooldown of a previous answer, which wasn't checked
+    Wait,
+    // the part was already solved, or the previous one wasn't yet
+    WrongLevel,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 6] = [
+        Verdict::Correct,
+        Verdict::Incorrect,
+        Verdict::TooHigh,
+        Verdict::TooLow,
+        Verdict::Wait,
+        Verdict::WrongLevel,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wait => "wait",
+            Verdict::WrongLevel => "wrong-level",
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wait => write!(f, "not checked, submitted too recently"),
+            Verdict::WrongLevel => write!(f, "for a part already solved or not unlocked yet"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    // seconds before another answer is accepted
+    pub wait: u64,
+}
+
+// seconds in durations like "1m 23s" or "45s"
+fn parse_seconds(duration: &str) -> Option<u64> {
+    duration.split_whitespace().try_fold(0, |total, part| {
+        let (number, unit) = part.split_at(part.len().checked_sub(1)?);
+        let number: u64 = number.parse().ok()?;
+        let unit = match unit {
+            "h" => 3600,
+            "m" => 60,
+            "s" => 1,
+            _ => return None,
+        };
+        Some(total + number * unit)
+    })
+}
+
+// the text between the given prefix and suffix
+fn between<'a>(text: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    let start = text.find(prefix)? + prefix.len();
+    let end = text[start..].find(suffix)?;
+    Some(&text[start..start + end])
+}
+
+// reads the verdict from the page answered by the server
+pub fn parse_response(page: &str) -> Option<Response> {
+    if page.contains("That's the right answer") {
+        return Some(Response {
+            verdict: Verdict::Correct,
+            wait: 0,
+        });
+    }
+    if page.contains("You gave an answer too recently") {
+        let wait = between(page, "You have ", " left to wait").and_then(parse_seconds)?;
+        return Some(Response {
+            verdict: Verdict::Wait,
+            wait,
+        });
+    }
+    if page.contains("You don't seem to be solving the right level") {
+        return Some(Response {
+            verdict: Verdict::WrongLevel,
+            wait: 0,
+        });
+    }
+    if page.contains("That's not the right answer") {
+        let verdict = if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        };
+        let wait = match between(&page.to_lowercase(), "please wait ", " before trying again") {
+            Some("one minute") => 60,
+            Some(minutes) => {
+                let minutes = minutes.strip_suffix(" minutes")?;
+                minutes.parse::<u64>().ok()? * 60
+            }
+            None => 0,
+        };
+        return Some(Response { verdict, wait });
+    }
+    None
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    // unix time, in seconds
+    pub time: u64,
+    pub part: u8,
+    pub answer: String,
+    pub response: Response,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct History(Vec<Submission>);
+
+fn parse_submission(line: &str) -> Result<Submission, String> {
+    let fields: Vec<&str> = line.splitn(5, ' ').collect();
+    let [time, part, verdict, wait, answer] = fields[..] else {
+        return Err(format!(
+            "expected time, part, verdict, wait and answer, got \"{}\"",
+            line
+        ));
+    };
+    let number = |field: &str, name: &str| {
+        field
+            .parse()
+            .map_err(|_| format!("invalid {} \"{}\"", name, field))
+    };
+    let verdict = Verdict::ALL
+        .into_iter()
+        .find(|v| v.name() == verdict)
+        .ok_or(format!("invalid verdict \"{}\"", verdict))?;
+    let part = match part.parse::<u8>() {
+        Ok(n @ (1 | 2)) => n,
+        _ => return Err(format!("invalid part \"{}\"", part)),
+    };
+    Ok(Submission {
+        time: number(time, "time")?,
+        part,
+        answer: answer.to_string(),
+        response: Response {
+            verdict,
+            wait: number(wait, "wait")?,
+        },
+    })
+}
+
+impl History {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        text.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(n, line)| parse_submission(line).map_err(|e| format!("line {}: {}", n + 1, e)))
+            .collect::<Result<_, _>>()
+            .map(History)
+    }
+
+    // a missing file is an empty history
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                History::parse(&text).map_err(|e| format!("invalid {}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(format!("cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("cannot create {}: {}", dir.display(), e))?;
+        }
+        fs::write(path, self.to_string())
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    pub fn push(&mut self, submission: Submission) {
+        self.0.push(submission);
+    }
+
+    // why the answer to a part shouldn't be sent at the given time, if it shouldn't
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        let number = answer.parse::<i64>().ok();
+        for s in self.0.iter().filter(|s| s.part == part) {
+            let verdict = s.response.verdict;
+            if verdict == Verdict::Correct {
+                return Err(format!(
+                    "part {} was already answered correctly with {}",
+                    part, s.answer
+                ));
+            }
+            if verdict.is_wrong() && s.answer == answer {
+                return Err(format!(
+                    "{} was already submitted for part {} and was {}",
+                    answer, part, verdict
+                ));
+            }
+            let bound = s.answer.parse::<i64>().ok();
+            if let (Some(n), Some(bound)) = (number, bound) {
+                if (verdict == Verdict::TooHigh && n >= bound)
+                    || (verdict == Verdict::TooLow && n <= bound)
+                {
+                    return Err(format!(
+                        "{} can't be right, {} was already {}",
+                        answer, bound, verdict
+                    ));
+                }
+            }
+        }
+        if let Some(until) = self.0.iter().map(|s| s.time + s.response.wait).max() {
+            if until > now {
+                return Err(format!("wait {}s before submitting again", until - now));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for History {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "# time part verdict wait answer")?;
+        for s in &self.0 {
+            writeln!(
+                f,
+                "{} {} {} {} {}",
+                s.time,
+                s.part,
+                s.response.verdict.name(),
+                s.response.wait,
+                s.answer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Submitter {
+    agent: ureq::Agent,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Submitter {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    fn post(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Response, String> {
+        let session = self.session.as_ref().ok_or(
+            "no session token, set session in aoc.toml or the AOC_SESSION environment variable",
+        )?;
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let page = match self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("cannot read the answer of {}: {}", url, e))?,
+            Err(ureq::Error::Status(code, _)) => return Err(format!("{} answered {}", url, code)),
+            Err(e) => return Err(format!("cannot reach {}: {}", url, e)),
+        };
+        parse_response(&page).ok_or(format!("unexpected answer from {}", url))
+    }
+
+    // sends the answer to a part unless the history of the day tells it is pointless or
+    // too early, and records the response
+    pub fn submit(
+        &self,
+        inputs: &Path,
+        (year, day, part): (u16, u8, u8),
+        answer: &str,
+        now: u64,
+    ) -> Result<Response, String> {
+        if answer.is_empty() || answer.contains('\n') {
+            return Err("answers must be a single non empty line".to_string());
+        }
+        let path = history_path(inputs, year, day);
+        let mut history = History::load(&path)?;
+        history.check(part, answer, now)?;
+        let response = self.post(year, day, part, answer)?;
+        history.push(Submission {
+            time: now,
+            part,
+            answer: answer.to_string(),
+            response,
+        });
+        history.save(&path)?;
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{serve, temp_dir, Request};
+
+    const CORRECT: &str = "<article><p>That's the right answer!  You are one gold star \
+        closer to finding the sleigh keys. [<a href=\"/2021/day/1#part2\">Continue to Part \
+        Two</a>]</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too \
+        high.  If you're stuck, make sure you're using the full input data. Please wait one \
+        minute before trying again. [<a href=\"/2021/day/1\">Return to Day 1</a>]</p></article>";
+    const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait after \
+        submitting an answer before trying again.  You have 1m 23s left to wait. \
+        [<a href=\"/2021/day/1\">Return to Day 1</a>]</p></article>";
+
+    fn submission(time: u64, part: u8, answer: &str, verdict: Verdict, wait: u64) -> Submission {
+        Submission {
+            time,
+            part,
+            answer: answer.to_string(),
+            response: Response { verdict, wait },
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response = |verdict, wait| Some(Response { verdict, wait });
+        assert_eq!(parse_response(CORRECT), response(Verdict::Correct, 0));
+        assert_eq!(parse_response(TOO_HIGH), response(Verdict::TooHigh, 60));
+        assert_eq!(
+            parse_response("That's not the right answer; your answer is too low. please wait 5 minutes before trying again."),
+            response(Verdict::TooLow, 300)
+        );
+        assert_eq!(
+            parse_response("That's not the right answer.  If you're stuck, ..."),
+            response(Verdict::Incorrect, 0)
+        );
+        assert_eq!(parse_response(WAIT), response(Verdict::Wait, 83));
+        assert_eq!(
+            parse_response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            response(Verdict::WrongLevel, 0)
+        );
+        assert_eq!(parse_response("<html>Server error</html>"), None);
+    }
+
+    #[test]
+    fn test_history() {
+        let history = History(vec![
+            submission(1000, 1, "1500", Verdict::TooHigh, 60),
+            submission(1100, 1, "1200", Verdict::TooLow, 60),
+            submission(1200, 1, "1300", Verdict::Incorrect, 300),
+        ]);
+        let text = "# time part verdict wait answer\n\
+            1000 1 too-high 60 1500\n\
+            1100 1 too-low 60 1200\n\
+            1200 1 incorrect 300 1300\n";
+        assert_eq!(history.to_string(), text);
+        assert_eq!(History::parse(text), Ok(history));
+        assert_eq!(
+            History::parse("1000 1 maybe 60 1500"),
+            Err("line 1: invalid verdict \"maybe\"".to_string())
+        );
+        for part in ["257", "3", "0", "one"] {
+            assert_eq!(
+                History::parse(&format!("1000 {} too-high 60 1500", part)),
+                Err(format!("line 1: invalid part \"{}\"", part))
+            );
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History(vec![
+            submission(1000, 1, "1500", Verdict::TooHigh, 60),
+            submission(1100, 1, "1200", Verdict::TooLow, 60),
+            submission(1200, 1, "1300", Verdict::Incorrect, 300),
+        ]);
+        assert_eq!(
+            history.check(1, "1300", 2000),
+            Err("1300 was already submitted for part 1 and was incorrect".to_string())
+        );
+        assert_eq!(
+            history.check(1, "1600", 2000),
+            Err("1600 can't be right, 1500 was already too high".to_string())
+        );
+        assert_eq!(
+            history.check(1, "1200", 2000),
+            Err("1200 was already submitted for part 1 and was too low".to_string())
+        );
+        assert_eq!(
+            history.check(1, "1400", 1300),
+            Err("wait 200s before submitting again".to_string())
+        );
+        assert_eq!(history.check(1, "1400", 1500), Ok(()));
+        assert_eq!(history.check(2, "1300", 1500), Ok(()));
+        history.push(submission(1500, 1, "1400", Verdict::Correct, 0));
+        assert_eq!(
+            history.check(1, "1401", 1600),
+            Err("part 1 was already answered correctly with 1400".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let inputs = temp_dir("submit");
+        let (url, requests) = serve(200, TOO_HIGH);
+        let submitter = Submitter::new(&url, Some("abc".to_string()));
+        assert_eq!(
+            submitter.submit(&inputs, (2021, 1, 2), "1500", 1000),
+            Ok(Response {
+                verdict: Verdict::TooHigh,
+                wait: 60
+            })
+        );
+        assert_eq!(
+            requests.try_recv(),
+            Ok(Request {
+                method: "POST".to_string(),
+                url: "/2021/day/1/answer".to_string(),
+                cookie: "session=abc".to_string(),
+                body: "level=2&answer=1500".to_string(),
+            })
+        );
+        // refused locally, without asking the server
+        assert_eq!(
+            submitter.submit(&inputs, (2021, 1, 2), "1400", 1030),
+            Err("wait 30s before submitting again".to_string())
+        );
+        assert_eq!(
+            submitter.submit(&inputs, (2021, 1, 2), "1500", 2000),
+            Err("1500 was already submitted for part 2 and was too high".to_string())
+        );
+        assert!(requests.try_recv().is_err());
+        assert_eq!(
+            History::load(&history_path(&inputs, 2021, 1)),
+            Ok(History(vec![submission(
+                1000,
+                2,
+                "1500",
+                Verdict::TooHigh,
+                60
+            )]))
+        );
+        fs::remove_dir_all(&inputs).unwrap();
+    }
+
+    #[test]
+    fn test_submit_unexpected() {
+        let inputs = temp_dir("submit-unexpected");
+        let (url, _requests) = serve(200, "<html>Maintenance</html>");
+        let submitter = Submitter::new(&url, Some("abc".to_string()));
+        assert_eq!(
+            submitter.submit(&inputs, (2021, 1, 1), "7", 1000),
+            Err(format!("unexpected answer from {}/2021/day/1/answer", url))
+        );
+        assert_eq!(
+            submitter.submit(&inputs, (2021, 1, 1), "", 1000),
+            Err("answers must be a single non empty line".to_string())
+        );
+        // nothing was recorded
+        assert!(!history_path(&inputs, 2021, 1).exists());
+    }
+}
@@ -0,0 +1,50 @@
+// Plain text tables printed by the runner
+
+#[derive(Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+// one line per row with cells separated by two spaces, each column as wide as its widest
+// cell
+pub fn render(rows: &[Vec<String>], align: &[Align]) -> String {
+    let widths: Vec<usize> = (0..align.len())
+        .map(|c| rows.iter().map(|r| r[c].len()).max().unwrap_or(0))
+        .collect();
+    let mut table = String::new();
+    for row in rows {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(align.iter().zip(&widths))
+            .map(|(cell, (align, &width))| match align {
+                Align::Left => format!("{:<width$}", cell, width = width),
+                Align::Right => format!("{:>width$}", cell, width = width),
+            })
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let rows = vec![
+            vec!["Day".to_string(), "Answer".to_string(), "".to_string()],
+            vec!["9".to_string(), "1".to_string(), "".to_string()],
+            vec!["10".to_string(), "123".to_string(), "x".to_string()],
+        ];
+        assert_eq!(
+            render(&rows, &[Align::Right, Align::Left, Align::Left]),
+            "Day  Answer
+  9  1
+ 10  123     x
+"
+        );
+    }
+}
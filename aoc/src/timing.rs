@@ -0,0 +1,149 @@
+// Reports of the time and memory taken by each day, as a table to read or as CSV to keep
+// track of regressions
+
+use crate::table::{render, Align};
+use aoc_common::Timings;
+use std::time::Duration;
+
+// a day's timings, or why it couldn't be timed (e.g. "missing input")
+pub type Row = (u16, u8, Result<Timings, String>);
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+pub fn format_table(rows: &[Row]) -> String {
+    let header = [
+        "Year",
+        "Day",
+        "Parse",
+        "Part 1",
+        "Part 2",
+        "Total",
+        "Peak memory",
+    ];
+    let mut cells = vec![header.iter().map(|h| h.to_string()).collect::<Vec<_>>()];
+    for (year, day, timings) in rows {
+        let mut row = vec![year.to_string(), day.to_string()];
+        match timings {
+            Ok(t) => row.extend([
+                format_duration(t.parse.time),
+                format_duration(t.part1.time),
+                format_duration(t.part2.time),
+                format_duration(t.total()),
+                format_bytes(t.peak_bytes()),
+            ]),
+            Err(e) => {
+                row.push(e.clone());
+                row.extend(std::iter::repeat_n(String::new(), 4));
+            }
+        }
+        cells.push(row);
+    }
+    let mut align = vec![Align::Right; header.len()];
+    if rows.iter().any(|(_, _, t)| t.is_err()) {
+        // left align the parse column so that errors read naturally
+        align[2] = Align::Left;
+    }
+    render(&cells, &align)
+}
+
+// one line per day with durations in microseconds and memory in bytes, days that
+// couldn't be timed have empty measures and their reason in the status column
+pub fn format_csv(rows: &[Row]) -> String {
+    let mut csv = String::from(
+        "year,day,parse_us,part1_us,part2_us,total_us,parse_peak_bytes,part1_peak_bytes,part2_peak_bytes,peak_bytes,status\n",
+    );
+    for (year, day, timings) in rows {
+        let line = match timings {
+            Ok(t) => format!(
+                "{},{},{},{},{},{},{},{},{},{},ok",
+                year,
+                day,
+                t.parse.time.as_micros(),
+                t.part1.time.as_micros(),
+                t.part2.time.as_micros(),
+                t.total().as_micros(),
+                t.parse.peak_bytes,
+                t.part1.peak_bytes,
+                t.part2.peak_bytes,
+                t.peak_bytes()
+            ),
+            Err(e) => format!("{},{},,,,,,,,,{}", year, day, e),
+        };
+        csv.push_str(&line);
+        csv.push('\n');
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Measure;
+
+    fn timings() -> Timings {
+        let measure = |us, peak_bytes| Measure {
+            time: Duration::from_micros(us),
+            peak_bytes,
+        };
+        Timings {
+            parse: measure(120, 2048),
+            part1: measure(1500, 100),
+            part2: measure(25_000_000, 3 * 1024 * 1024),
+        }
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+    }
+
+    #[test]
+    fn test_format_table() {
+        let rows = vec![
+            (2015, 4, Ok(timings())),
+            (2021, 17, Err("missing input".to_string())),
+        ];
+        assert_eq!(
+            format_table(&rows),
+            "Year  Day  Parse            Part 1        Part 2         Total  Peak memory
+2015    4  0.120 ms       1.500 ms  25000.000 ms  25001.620 ms      3.0 MiB
+2021   17  missing input
+"
+        );
+    }
+
+    #[test]
+    fn test_format_csv() {
+        let rows = vec![
+            (2015, 4, Ok(timings())),
+            (2021, 17, Err("missing input".to_string())),
+        ];
+        assert_eq!(
+            format_csv(&rows),
+            "year,day,parse_us,part1_us,part2_us,total_us,parse_peak_bytes,part1_peak_bytes,part2_peak_bytes,peak_bytes,status
+2015,4,120,1500,25000000,25001620,2048,100,3145728,3147776,ok
+2021,17,,,,,,,,,missing input
+"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+// The answer to one part of a puzzle. Most are numbers, a few are text (like the
+// letters drawn by 2021 day 13), and some parts haven't been solved yet.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    Unsolved,
+}
+
+// returned by the parts that have no solution yet
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        *self != Answer::Unsolved
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Text(s) if s.contains('\n'))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    // numbers too large for an i64 are kept as text
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Number(n),
+                        Err(_) => Answer::Text(n.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Unsolved> for Answer {
+    fn from(_: Unsolved) -> Self {
+        Answer::Unsolved
+    }
+}
+
+// no answer when the input doesn't allow one, e.g. a sample without the wire asked for
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(answer: Option<T>) -> Self {
+        answer.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(42u32), Answer::Number(42));
+        assert_eq!(Answer::from(-7i32), Answer::Number(-7));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text("18446744073709551615".to_string())
+        );
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+        assert_eq!(Answer::from(Unsolved), Answer::Unsolved);
+        assert_eq!(Answer::from(Some(3u8)), Answer::Number(3));
+        assert_eq!(Answer::from(None::<u8>), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Number(-3).to_string(), "-3");
+        assert_eq!(Answer::Text("# #\n###".to_string()).to_string(), "# #\n###");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+        assert!(Answer::Text("# #\n###".to_string()).is_multiline());
+        assert!(!Answer::Number(1).is_multiline());
+        assert!(!Answer::Unsolved.is_solved());
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::str::FromStr;
+
+// A malformed input: where it happened (1-based line and column, 0 when unknown), the
+// offending token and what was wrong with it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    // error about `token`, which should be a slice of `text` so its column can be
+    // found; the line number is filled in later by whoever knows it, see `at_line`
+    pub fn new(text: &str, token: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: column_of(text, token),
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    // error about the input as a whole, like a missing section
+    pub fn input(message: impl Into<String>) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            token: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    // for errors found in a slice of a line, moves the column relative to the full line
+    pub fn shifted(mut self, text: &str, slice: &str) -> Self {
+        if self.column > 0 {
+            self.column += column_of(text, slice) - 1;
+        }
+        self
+    }
+}
+
+// 1-based column of `token` inside `text`, using its position in memory when it is a
+// slice of `text` and searching for it otherwise
+fn column_of(text: &str, token: &str) -> usize {
+    let start = text.as_ptr() as usize;
+    let pos = token.as_ptr() as usize;
+    if pos >= start && pos + token.len() <= start + text.len() {
+        pos - start + 1
+    } else {
+        text.find(token).map_or(1, |i| i + 1)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, ", self.line)?;
+        }
+        if self.column > 0 {
+            write!(f, "column {}: ", self.column)?;
+        }
+        write!(f, "{}", self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " \"{}\"", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// parses `token`, a slice of `text`, reporting `message` if it isn't valid
+pub fn parse_token<T: FromStr>(text: &str, token: &str, message: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(text, token, message))
+}
+
+// parses every line of the input, adding the line number to the errors
+pub fn parse_lines<T, F>(input: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    parse_lines_from(input, 1, parse)
+}
+
+// like `parse_lines`, for a section of the input that starts at line `first`
+pub fn parse_lines_from<T, F>(input: &str, first: usize, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.at_line(first + i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let line = "turn on 0,0 thruogh 999,999";
+        let token = line.split(' ').nth(3).unwrap();
+        let error = ParseError::new(line, token, "expected \"through\", got").at_line(7);
+        assert_eq!(error.line, 7);
+        assert_eq!(error.column, 13);
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 13: expected \"through\", got \"thruogh\""
+        );
+        // tokens that aren't slices of the text are searched for
+        assert_eq!(ParseError::new("a b c", "c", "bad").column, 5);
+        assert_eq!(ParseError::new("a b c", "d", "bad").column, 1);
+    }
+
+    #[test]
+    fn test_input() {
+        assert_eq!(ParseError::input("empty input").to_string(), "empty input");
+    }
+
+    #[test]
+    fn test_shifted() {
+        let line = "0,9 -> 5,x";
+        let coordinate = &line[7..];
+        let error =
+            ParseError::new(coordinate, &coordinate[2..], "bad y").shifted(line, coordinate);
+        assert_eq!(error.column, 10);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let parse = |l: &str| parse_token::<u32>(l, l, "not a number");
+        assert_eq!(parse_lines("1\n2\n3", parse), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            parse_lines("1\nx\n3", parse),
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                token: "x".to_string(),
+                message: "not a number".to_string()
+            })
+        );
+        assert_eq!(
+            parse_lines_from("1\nx", 10, parse).unwrap_err().to_string(),
+            "line 11, column 1: not a number \"x\""
+        );
+    }
+}
@@ -0,0 +1,432 @@
+use crate::answer::Answer;
+use crate::output::Output;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::ops::Add;
+
+// index of a node, given in the order nodes are added to the graph
+pub type NodeId = usize;
+
+// Weight of the edges of a graph, numbers summed along paths, 0 being the default
+pub trait Weight: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<W: Copy + Ord + Default + Add<Output = W>> Weight for W {}
+
+pub enum Mode {
+    Shortest,
+    Longest,
+}
+
+// Graph whose nodes are labelled by strings, interned into ids, and whose edges carry a
+// weight of type W, () for unweighted graphs
+//
+// Edges are kept in adjacency lists, in the order they were added, so that algorithms
+// explore neighbours in the order of the input. In undirected graphs each edge is
+// stored in both directions.
+#[derive(Debug)]
+pub struct Graph<W = ()> {
+    directed: bool,
+    ids: HashMap<String, NodeId>,
+    labels: Vec<String>,
+    edges: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Graph::undirected()
+    }
+}
+
+impl<W> Graph<W> {
+    pub fn undirected() -> Self {
+        Graph {
+            directed: false,
+            ids: HashMap::new(),
+            labels: vec![],
+            edges: vec![],
+        }
+    }
+
+    pub fn directed() -> Self {
+        Graph {
+            directed: true,
+            ..Graph::undirected()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    // number of nodes
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    // id of the node with the given label, added to the graph if not present
+    pub fn node(&mut self, label: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.ids.insert(label.to_string(), id);
+        self.labels.push(label.to_string());
+        self.edges.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, label: &str) -> Option<NodeId> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: NodeId) -> &str {
+        &self.labels[id]
+    }
+
+    pub fn labels(&self, path: &[NodeId]) -> Vec<String> {
+        path.iter().map(|&id| self.labels[id].clone()).collect()
+    }
+
+    // adds an edge between the two nodes, in both directions unless the graph is
+    // directed, the weight of an edge added twice is replaced
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W)
+    where
+        W: Clone,
+    {
+        let from = self.node(from);
+        let to = self.node(to);
+        if !self.directed {
+            self.insert_edge(to, from, weight.clone());
+        }
+        self.insert_edge(from, to, weight);
+    }
+
+    fn insert_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        let edges = &mut self.edges[from];
+        match edges.iter_mut().find(|(n, _)| *n == to) {
+            Some(edge) => edge.1 = weight,
+            None => edges.push((to, weight)),
+        }
+    }
+
+    // nodes reached from the given one, with the weight of their edge
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &W)> {
+        self.edges[id].iter().map(|(n, w)| (*n, w))
+    }
+
+    pub fn weight(&self, from: NodeId, to: NodeId) -> Option<&W> {
+        self.neighbours(from)
+            .find(|&(n, _)| n == to)
+            .map(|(_, w)| w)
+    }
+
+    // every path from start to end, as node ids, that only goes through the neighbours
+    // allowed by `allowed(path, visits, next)`, where visits counts how many times each
+    // node is already in the path. Paths stop at end, the rule must prevent endless
+    // cycles
+    pub fn paths<F>(&self, start: NodeId, end: NodeId, mut allowed: F) -> Vec<Vec<NodeId>>
+    where
+        F: FnMut(&[NodeId], &[usize], NodeId) -> bool,
+    {
+        let mut paths = vec![];
+        let mut path = vec![start];
+        let mut visits = vec![0; self.len()];
+        visits[start] = 1;
+        self.extend_paths(end, &mut path, &mut visits, &mut allowed, &mut paths);
+        paths
+    }
+
+    fn extend_paths<F>(
+        &self,
+        end: NodeId,
+        path: &mut Vec<NodeId>,
+        visits: &mut [usize],
+        allowed: &mut F,
+        paths: &mut Vec<Vec<NodeId>>,
+    ) where
+        F: FnMut(&[NodeId], &[usize], NodeId) -> bool,
+    {
+        let last = *path.last().unwrap();
+        if last == end {
+            paths.push(path.clone());
+            return;
+        }
+        for &(next, _) in &self.edges[last] {
+            if allowed(path, visits, next) {
+                path.push(next);
+                visits[next] += 1;
+                self.extend_paths(end, path, visits, allowed, paths);
+                visits[next] -= 1;
+                path.pop();
+            }
+        }
+    }
+}
+
+impl<W> Graph<W>
+where
+    W: Weight,
+{
+    // total weight of the edges along the path, None if two nodes aren't connected
+    pub fn path_weight(&self, path: &[NodeId]) -> Option<W> {
+        path.windows(2).try_fold(W::default(), |total, pair| {
+            Some(total + *self.weight(pair[0], pair[1])?)
+        })
+    }
+
+    // the shortest or longest path visiting each node exactly once, the first one found
+    // when several have the same weight, None if there is no such path
+    pub fn hamiltonian_path(&self, mode: Mode) -> Option<Path<W>> {
+        let mut best: Option<(Vec<NodeId>, W)> = None;
+        let mut path = Vec::with_capacity(self.len());
+        let mut visited = vec![false; self.len()];
+        for start in 0..self.len() {
+            path.push(start);
+            visited[start] = true;
+            self.search_hamiltonian(&mode, &mut path, &mut visited, W::default(), &mut best);
+            visited[start] = false;
+            path.pop();
+        }
+        best.map(|(nodes, total_weight)| Path {
+            nodes: self.labels(&nodes),
+            total_weight,
+        })
+    }
+
+    fn search_hamiltonian(
+        &self,
+        mode: &Mode,
+        path: &mut Vec<NodeId>,
+        visited: &mut [bool],
+        weight: W,
+        best: &mut Option<(Vec<NodeId>, W)>,
+    ) {
+        if path.len() == self.len() {
+            let better = match (mode, &best) {
+                (_, None) => true,
+                (Mode::Shortest, Some((_, w))) => weight < *w,
+                (Mode::Longest, Some((_, w))) => weight > *w,
+            };
+            if better {
+                *best = Some((path.clone(), weight));
+            }
+            return;
+        }
+        let last = *path.last().unwrap();
+        for &(next, w) in &self.edges[last] {
+            if !visited[next] {
+                path.push(next);
+                visited[next] = true;
+                self.search_hamiltonian(mode, path, visited, weight + w, best);
+                visited[next] = false;
+                path.pop();
+            }
+        }
+    }
+
+    // weight of the lightest path from start to every node, None for the nodes that
+    // can't be reached; weights must not be negative
+    pub fn distances(&self, start: NodeId) -> Vec<Option<W>> {
+        let mut distances = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((W::default(), start))]);
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if distances[node].is_some() {
+                continue;
+            }
+            distances[node] = Some(distance);
+            for &(next, w) in &self.edges[node] {
+                if distances[next].is_none() {
+                    queue.push(Reverse((distance + w, next)));
+                }
+            }
+        }
+        distances
+    }
+
+    // the lightest path from start to end using Dijkstra's algorithm, None if end can't be
+    // reached; weights must not be negative
+    pub fn shortest_path(&self, start: NodeId, end: NodeId) -> Option<Path<W>> {
+        let mut distances: Vec<Option<W>> = vec![None; self.len()];
+        let mut previous = vec![start; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((W::default(), start, start))]);
+        while let Some(Reverse((distance, node, from))) = queue.pop() {
+            if distances[node].is_some() {
+                continue;
+            }
+            distances[node] = Some(distance);
+            previous[node] = from;
+            if node == end {
+                break;
+            }
+            for &(next, w) in &self.edges[node] {
+                if distances[next].is_none() {
+                    queue.push(Reverse((distance + w, next, node)));
+                }
+            }
+        }
+        let total_weight = distances[end]?;
+        let mut nodes = vec![end];
+        while *nodes.last().unwrap() != start {
+            nodes.push(previous[*nodes.last().unwrap()]);
+        }
+        nodes.reverse();
+        Some(Path {
+            nodes: self.labels(&nodes),
+            total_weight,
+        })
+    }
+}
+
+// Path through the labels of nodes, the total weight is its answer and the nodes its
+// extra details
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct Path<W> {
+    pub nodes: Vec<String>,
+    #[serde(skip)]
+    pub total_weight: W,
+}
+
+impl<W: fmt::Display> fmt::Display for Path<W> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}; Weight={}",
+            self.nodes.join(" -> "),
+            self.total_weight
+        )
+    }
+}
+
+impl<W: Weight + Into<Answer>> From<Path<W>> for Output {
+    fn from(path: Path<W>) -> Self {
+        Output::with_extra(path.total_weight, &path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cities() -> Graph<i32> {
+        let mut graph = Graph::undirected();
+        graph.add_edge("London", "Dublin", 464);
+        graph.add_edge("London", "Belfast", 518);
+        graph.add_edge("Dublin", "Belfast", 141);
+        graph
+    }
+
+    #[test]
+    fn test_graph() {
+        let graph = cities();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.labels(&[0, 1, 2]), ["London", "Dublin", "Belfast"]);
+        assert_eq!(graph.id("Belfast"), Some(2));
+        assert_eq!(graph.id("Paris"), None);
+        assert_eq!(graph.weight(2, 1), Some(&141));
+        assert_eq!(
+            graph.neighbours(0).collect::<Vec<_>>(),
+            vec![(1, &464), (2, &518)]
+        );
+        let mut directed = Graph::directed();
+        directed.add_edge("a", "b", ());
+        directed.add_edge("a", "b", ());
+        assert_eq!(directed.neighbours(0).count(), 1);
+        assert_eq!(directed.neighbours(1).count(), 0);
+    }
+
+    #[test]
+    fn test_path_weight() {
+        let graph = cities();
+        assert_eq!(graph.path_weight(&[0, 1, 2]), Some(605));
+        assert_eq!(graph.path_weight(&[1, 2, 0]), Some(659));
+        assert_eq!(graph.path_weight(&[1, 0, 2]), Some(982));
+        let mut graph = Graph::undirected();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("c", "d", 1);
+        assert_eq!(graph.path_weight(&[0, 1, 2]), None);
+    }
+
+    #[test]
+    fn test_hamiltonian_path() {
+        let graph = cities();
+        assert_eq!(
+            graph.hamiltonian_path(Mode::Shortest),
+            Some(Path {
+                nodes: graph.labels(&[0, 1, 2]),
+                total_weight: 605
+            })
+        );
+        assert_eq!(
+            graph.hamiltonian_path(Mode::Longest),
+            Some(Path {
+                nodes: graph.labels(&[1, 0, 2]),
+                total_weight: 982
+            })
+        );
+        let mut star = Graph::undirected();
+        star.add_edge("a", "b", 1);
+        star.add_edge("a", "c", 1);
+        star.add_edge("a", "d", 1);
+        assert_eq!(star.hamiltonian_path(Mode::Shortest), None);
+    }
+
+    #[test]
+    fn test_paths() {
+        let mut graph = Graph::undirected();
+        for (from, to) in [("s", "a"), ("s", "b"), ("a", "b"), ("a", "e"), ("b", "e")] {
+            graph.add_edge(from, to, ());
+        }
+        let (s, e) = (graph.id("s").unwrap(), graph.id("e").unwrap());
+        let once = graph.paths(s, e, |_, visits, next| visits[next] == 0);
+        assert_eq!(
+            once.iter()
+                .map(|p| graph.labels(p).concat())
+                .collect::<Vec<_>>(),
+            ["sabe", "sae", "sbae", "sbe"]
+        );
+        let twice = graph.paths(s, e, |_, visits, next| next != s && visits[next] < 2);
+        assert_eq!(twice.len(), 8);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = Graph::directed();
+        graph.add_edge("a", "b", 7);
+        graph.add_edge("a", "c", 2);
+        graph.add_edge("c", "b", 3);
+        graph.add_edge("b", "d", 1);
+        graph.add_edge("d", "a", 1);
+        assert_eq!(
+            graph.shortest_path(0, 3),
+            Some(Path {
+                nodes: graph.labels(&[0, 2, 1, 3]),
+                total_weight: 6
+            })
+        );
+        assert_eq!(graph.distances(1), [Some(2), Some(0), Some(4), Some(1)]);
+        graph.node("e");
+        assert_eq!(graph.shortest_path(0, 4), None);
+        assert_eq!(
+            graph.shortest_path(0, 0).unwrap().to_string(),
+            "a; Weight=0"
+        );
+    }
+
+    #[test]
+    fn test_output() {
+        let path = cities().hamiltonian_path(Mode::Longest).unwrap();
+        assert_eq!(path.to_string(), "Dublin -> London -> Belfast; Weight=982");
+        let output = Output::from(path);
+        assert_eq!(output.answer, Answer::Number(982));
+        assert_eq!(
+            output.extra.unwrap().to_string(),
+            r#"{"nodes":["Dublin","London","Belfast"]}"#
+        );
+    }
+}
@@ -0,0 +1,462 @@
+use crate::error::{parse_lines, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+// offsets of the 4 orthogonal neighbours: above, left, right and below
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+// offsets of the 8 neighbours including diagonals, row by row
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// Rectangular grid of cells stored row after row, addressed by (x, y) positions with
+// (0, 0) at the top left corner
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // grid with every cell set to value
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // grid from cells given row after row, the number of cells must be a multiple of width
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "Number of cells must be a multiple of the width"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    // index of a position in the cells, None if outside of the grid
+    pub fn index(&self, x: usize, y: usize) -> Option<usize> {
+        if self.contains(x, y) {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    // position of an index in the cells
+    pub fn position(&self, i: usize) -> (usize, usize) {
+        (i % self.width, i / self.width)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index(x, y).map(move |i| &mut self.cells[i])
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    // every position of the grid, row after row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    // positions with their cells, row after row
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // positions above, left, right and below the given one that are inside the grid
+    pub fn neighbours4(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(x, y, self.width, self.height, &ORTHOGONAL)
+    }
+
+    // positions around the given one, diagonals included, that are inside the grid
+    pub fn neighbours8(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(x, y, self.width, self.height, &ALL_AROUND)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // read-only view of the rectangle with top left corner at (x, y), clipped to the grid
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> GridView<'_, T> {
+        let x = x.min(self.width);
+        let y = y.min(self.height);
+        GridView {
+            grid: self,
+            x,
+            y,
+            width: width.min(self.width - x),
+            height: height.min(self.height - y),
+        }
+    }
+
+    // one line of text per row, with a character for each cell
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut f));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl Grid<u8> {
+    // parses a map of single digits, like "2199943210"
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let rows = parse_lines(input, |line| {
+            if *width.get_or_insert(line.len()) != line.len() {
+                return Err(ParseError::new(
+                    line,
+                    line,
+                    "Grid must have columns of equal size, got",
+                ));
+            }
+            line.char_indices()
+                .map(|(i, c)| {
+                    c.to_digit(10).map(|d| d as u8).ok_or_else(|| {
+                        ParseError::new(line, &line[i..i + c.len_utf8()], "Expected a digit")
+                    })
+                })
+                .collect::<Result<Vec<u8>, _>>()
+        })?;
+        Ok(Grid::from_cells(width.unwrap_or(0), rows.concat()))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.index(x, y) {
+            Some(i) => &self.cells[i],
+            None => panic!("Position ({}, {}) outside of the grid", x, y),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.index(x, y) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("Position ({}, {}) outside of the grid", x, y),
+        }
+    }
+}
+
+// cells are written one after the other, each row in its own line
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Iterator over the neighbours of a position that are inside the grid
+pub struct Neighbours {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    offsets: std::slice::Iter<'static, (isize, isize)>,
+}
+
+impl Neighbours {
+    fn new(
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> Self {
+        Neighbours {
+            x,
+            y,
+            width,
+            height,
+            offsets: offsets.iter(),
+        }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        for (dx, dy) in self.offsets.by_ref() {
+            let x = self.x.checked_add_signed(*dx);
+            let y = self.y.checked_add_signed(*dy);
+            if let (Some(x), Some(y)) = (x, y) {
+                if x < self.width && y < self.height {
+                    return Some((x, y));
+                }
+            }
+        }
+        None
+    }
+}
+
+// Read-only rectangle inside a grid, positions are relative to its top left corner
+#[derive(Clone, Copy, Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width && y < self.height {
+            self.grid.get(self.x + x, self.y + y)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "Row {} outside of the view", y);
+        &self.grid.row(self.y + y)[self.x..self.x + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.rows().flatten()
+    }
+
+    // copy of the cells in the view as a new grid
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_cells(self.width, self.iter().cloned().collect())
+    }
+
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        let mut output = String::new();
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut f));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGITS: &str = "2199943210
+3987894921
+9856789892";
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+        assert_eq!(grid.width(), 10);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(0, 0)], 2);
+        assert_eq!(grid[(9, 0)], 0);
+        assert_eq!(grid[(3, 2)], 6);
+        assert_eq!(grid.to_string(), format!("{}\n", DIGITS));
+        assert_eq!(
+            Grid::parse_digits("123\n456\n"),
+            Ok(Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6]))
+        );
+        assert_eq!(Grid::parse_digits("").unwrap().len(), 0);
+        assert_eq!(
+            Grid::parse_digits("123\n4x6").unwrap_err().to_string(),
+            "line 2, column 2: Expected a digit \"x\""
+        );
+        assert_eq!(
+            Grid::parse_digits("123\n45").unwrap_err().to_string(),
+            "line 2, column 1: Grid must have columns of equal size, got \"45\""
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        *grid.get_mut(1, 1).unwrap() = 7;
+        grid[(0, 0)] = 8;
+        assert_eq!(grid.cells(), &[8, 2, 3, 4, 7, 6]);
+        assert_eq!(grid.index(1, 1), Some(4));
+        assert_eq!(grid.position(4), (1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Position (3, 0) outside of the grid")]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::new(3, 2, 0);
+        let _ = grid[(3, 0)];
+    }
+
+    #[test]
+    fn test_neighbours4() {
+        let grid = Grid::new(10, 5, 0);
+        let n = |x, y| grid.neighbours4(x, y).collect::<Vec<_>>();
+        assert_eq!(n(0, 0), vec![(1, 0), (0, 1)]);
+        assert_eq!(n(5, 0), vec![(4, 0), (6, 0), (5, 1)]);
+        assert_eq!(n(9, 0), vec![(8, 0), (9, 1)]);
+        assert_eq!(n(5, 1), vec![(5, 0), (4, 1), (6, 1), (5, 2)]);
+        assert_eq!(n(9, 4), vec![(9, 3), (8, 4)]);
+        // a single row has no neighbours above or below
+        let row = Grid::new(3, 1, 0);
+        assert_eq!(
+            row.neighbours4(1, 0).collect::<Vec<_>>(),
+            vec![(0, 0), (2, 0)]
+        );
+    }
+
+    #[test]
+    fn test_neighbours8() {
+        let grid = Grid::new(10, 10, 0);
+        let n = |x, y| grid.neighbours8(x, y).collect::<Vec<_>>();
+        assert_eq!(n(0, 0), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(n(5, 0), vec![(4, 0), (6, 0), (4, 1), (5, 1), (6, 1)]);
+        assert_eq!(
+            n(5, 1),
+            vec![
+                (4, 0),
+                (5, 0),
+                (6, 0),
+                (4, 1),
+                (6, 1),
+                (4, 2),
+                (5, 2),
+                (6, 2)
+            ]
+        );
+        assert_eq!(n(9, 9), vec![(8, 8), (9, 8), (8, 9)]);
+    }
+
+    #[test]
+    fn test_positions() {
+        let grid = Grid::from_cells(2, vec!['a', 'b', 'c', 'd']);
+        assert_eq!(
+            grid.positions().collect::<Vec<_>>(),
+            vec![(0, 0), (1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(grid.enumerate().nth(2), Some(((0, 1), &'c')));
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b'], &['c', 'd']]
+        );
+    }
+
+    #[test]
+    fn test_view() {
+        let grid = Grid::parse_digits(DIGITS).unwrap();
+        let view = grid.view(1, 1, 3, 5);
+        assert_eq!((view.width(), view.height()), (3, 2));
+        assert_eq!(view.get(0, 0), Some(&9));
+        assert_eq!(view.get(2, 1), Some(&6));
+        assert_eq!(view.get(3, 0), None);
+        assert_eq!(view.render(|d| (b'0' + d) as char), "987\n856\n");
+        assert_eq!(view.to_grid(), Grid::from_cells(3, vec![9, 8, 7, 8, 5, 6]));
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::from_cells(3, vec![true, false, true, false, true, false]);
+        assert_eq!(grid.render(|&on| if on { '#' } else { '.' }), "#.#\n.#.\n");
+        assert_eq!(grid.map(|&on| on as u8).to_string(), "101\n010\n");
+    }
+}
@@ -0,0 +1,297 @@
+// Pictures of grids and simulations, written as binary PPM (colour) or PGM (grey levels)
+// images which most image viewers open and ffmpeg turns into videos, e.g.
+//
+//     ffmpeg -framerate 10 -i frame%04d.ppm octopi.gif
+
+use crate::grid::Grid;
+use crate::simulation::Simulation;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(255, 0, 0);
+
+    // perceived brightness, used for grey level images
+    pub fn grey(self) -> u8 {
+        let Rgb(r, g, b) = self;
+        ((299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b)) / 1000) as u8
+    }
+
+    // colour at `t` between this one (0) and the other (1)
+    pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+        let channel =
+            |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+        Rgb(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+// Colours of the values of cells, values past the last colour get the last colour
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    colors: Vec<Rgb>,
+}
+
+impl Palette {
+    pub fn new(colors: Vec<Rgb>) -> Self {
+        assert!(!colors.is_empty(), "A palette needs at least one colour");
+        Palette { colors }
+    }
+
+    // `len` colours going evenly from one colour to the other
+    pub fn gradient(from: Rgb, to: Rgb, len: usize) -> Self {
+        let steps = len.saturating_sub(1).max(1) as f64;
+        Palette::new((0..len).map(|i| from.mix(to, i as f64 / steps)).collect())
+    }
+
+    pub fn color(&self, value: usize) -> Rgb {
+        self.colors[value.min(self.colors.len() - 1)]
+    }
+
+    // image of a grid with a pixel per cell, coloured by its value
+    pub fn paint<T: Copy + Into<usize>>(&self, grid: &Grid<T>) -> Image {
+        Image::from_grid(grid, |&cell| self.color(cell.into()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Ppm,
+    Pgm,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Pgm => "pgm",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pixels: Grid<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Image {
+            pixels: Grid::new(width, height, background),
+        }
+    }
+
+    pub fn from_grid<T, F: FnMut(&T) -> Rgb>(grid: &Grid<T>, color: F) -> Self {
+        Image {
+            pixels: grid.map(color),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        self.pixels.get(x, y).copied()
+    }
+
+    // colours a pixel, pixels outside of the image are ignored
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        if let Some(pixel) = self.pixels.get_mut(x, y) {
+            *pixel = color;
+        }
+    }
+
+    // image with each pixel turned into a square of factor x factor pixels, for grids too
+    // small to be seen
+    pub fn scaled(&self, factor: usize) -> Image {
+        let width = self.width() * factor;
+        let cells = (0..self.height() * factor)
+            .flat_map(|y| (0..width).map(move |x| self.pixels[(x / factor, y / factor)]))
+            .collect();
+        Image {
+            pixels: Grid::from_cells(width, cells),
+        }
+    }
+
+    pub fn write(&self, format: Format, output: &mut impl Write) -> io::Result<()> {
+        let (magic, bytes): (&str, Vec<u8>) = match format {
+            Format::Ppm => (
+                "P6",
+                self.pixels
+                    .iter()
+                    .flat_map(|&Rgb(r, g, b)| [r, g, b])
+                    .collect(),
+            ),
+            Format::Pgm => ("P5", self.pixels.iter().map(|c| c.grey()).collect()),
+        };
+        write!(
+            output,
+            "{}\n{} {}\n255\n",
+            magic,
+            self.width(),
+            self.height()
+        )?;
+        output.write_all(&bytes)
+    }
+
+    pub fn save(&self, format: Format, path: &Path) -> io::Result<()> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+        self.write(format, &mut file)?;
+        file.flush()
+    }
+}
+
+// Sequence of images, the states of a simulation or the stages of a solution
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Animation {
+    pub frames: Vec<Image>,
+}
+
+impl Animation {
+    pub fn new() -> Self {
+        Animation::default()
+    }
+
+    pub fn still(image: Image) -> Self {
+        Animation {
+            frames: vec![image],
+        }
+    }
+
+    pub fn push(&mut self, frame: Image) {
+        self.frames.push(frame);
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    // the initial state of the simulation, then its state every `every` steps until
+    // `steps` steps were run
+    pub fn record<S, F>(simulation: &mut S, steps: usize, every: usize, mut render: F) -> Self
+    where
+        S: Simulation,
+        F: FnMut(&S) -> Image,
+    {
+        let every = every.max(1);
+        let mut animation = Animation::still(render(simulation));
+        for step in 1..=steps {
+            simulation.step();
+            if step % every == 0 || step == steps {
+                animation.push(render(simulation));
+            }
+        }
+        animation
+    }
+
+    pub fn scaled(&self, factor: usize) -> Animation {
+        Animation {
+            frames: self.frames.iter().map(|f| f.scaled(factor)).collect(),
+        }
+    }
+
+    // writes the frames to the directory, created if needed, as frame0000.ppm,
+    // frame0001.ppm, ... and returns their paths
+    pub fn save(&self, format: Format, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        self.frames
+            .iter()
+            .enumerate()
+            .map(|(i, frame)| {
+                let path = dir.join(format!("frame{:04}.{}", i, format.extension()));
+                frame.save(format, &path).map(|_| path)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette() {
+        let palette = Palette::gradient(Rgb::BLACK, Rgb(200, 100, 0), 3);
+        assert_eq!(palette.color(0), Rgb::BLACK);
+        assert_eq!(palette.color(1), Rgb(100, 50, 0));
+        assert_eq!(palette.color(2), Rgb(200, 100, 0));
+        assert_eq!(palette.color(9), Rgb(200, 100, 0));
+        let image = palette.paint(&Grid::from_cells(2, vec![0u8, 2]));
+        assert_eq!(image.get(1, 0), Some(Rgb(200, 100, 0)));
+        assert_eq!(Rgb::WHITE.grey(), 255);
+        assert_eq!(Rgb::RED.grey(), 76);
+    }
+
+    #[test]
+    fn test_write() {
+        let mut image = Image::from_grid(&Grid::from_cells(2, vec![true, false]), |&on| {
+            if on {
+                Rgb::WHITE
+            } else {
+                Rgb::BLACK
+            }
+        });
+        image.set(1, 0, Rgb(1, 2, 3));
+        image.set(5, 5, Rgb::RED);
+        let mut ppm = vec![];
+        image.write(Format::Ppm, &mut ppm).unwrap();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xff\xff\xff\x01\x02\x03");
+        let mut pgm = vec![];
+        image.scaled(2).write(Format::Pgm, &mut pgm).unwrap();
+        assert_eq!(pgm, b"P5\n4 2\n255\n\xff\xff\x01\x01\xff\xff\x01\x01");
+    }
+
+    // one more lit cell at each step
+    struct Fill(Grid<bool>, usize);
+
+    impl Simulation for Fill {
+        fn step(&mut self) {
+            self.0.cells_mut()[self.1] = true;
+            self.1 += 1;
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut fill = Fill(Grid::new(5, 1, false), 0);
+        let palette = Palette::new(vec![Rgb::BLACK, Rgb::WHITE]);
+        let animation = Animation::record(&mut fill, 5, 2, |fill| palette.paint(&fill.0));
+        // initial state, steps 2, 4 and the last one
+        assert_eq!(animation.len(), 4);
+        let lit = |frame: &Image| {
+            (0..5)
+                .filter(|&x| frame.get(x, 0) == Some(Rgb::WHITE))
+                .count()
+        };
+        assert_eq!(
+            animation.frames.iter().map(lit).collect::<Vec<_>>(),
+            [0, 2, 4, 5]
+        );
+        let dir = std::env::temp_dir().join(format!("aoc-image-{}", std::process::id()));
+        let paths = animation.save(Format::Pgm, &dir).unwrap();
+        assert_eq!(paths[3], dir.join("frame0003.pgm"));
+        assert_eq!(
+            fs::read(&paths[1]).unwrap(),
+            b"P5\n5 1\n255\n\xff\xff\0\0\0"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
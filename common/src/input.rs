@@ -0,0 +1,254 @@
+use flate2::bufread::MultiGzDecoder;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+// root of the repository, holding aoc.toml and, unless it sets another inputs directory,
+// the inputs next to each day's crate
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+// first bytes of gzip data
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+// the reader itself, or its decompressed data when it starts like gzip data
+fn decompressed<'a>(mut reader: impl BufRead + 'a) -> io::Result<Box<dyn BufRead + 'a>> {
+    if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+// Where a day's puzzle input is read from
+#[derive(Debug, PartialEq)]
+pub enum Input<'a> {
+    File(PathBuf),
+    // "-" on the command line
+    Stdin,
+    // sample input from the puzzle description
+    Example(&'a str),
+}
+
+impl<'a> Input<'a> {
+    // a path given on the command line, "-" meaning stdin
+    pub fn from_arg(arg: &str) -> Input<'a> {
+        if arg == "-" {
+            Input::Stdin
+        } else {
+            Input::File(PathBuf::from(arg))
+        }
+    }
+
+    // the input as it is read, decompressed if it is gzipped
+    pub fn reader(&self) -> Result<Box<dyn BufRead + 'a>, String> {
+        match self {
+            Input::File(path) => File::open(path)
+                .and_then(|file| decompressed(BufReader::new(file)))
+                .map_err(|e| format!("cannot read {}: {}", path.display(), e)),
+            Input::Stdin => decompressed(BufReader::new(io::stdin()))
+                .map_err(|e| format!("cannot read stdin: {}", e)),
+            Input::Example(example) => Ok(Box::new(example.as_bytes())),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        let mut input = String::new();
+        self.reader()?
+            .read_to_string(&mut input)
+            .map_err(|e| format!("cannot read {}: {}", self, e))?;
+        Ok(input)
+    }
+}
+
+// name of the input in error messages
+impl fmt::Display for Input<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Example(_) => write!(f, "<example>"),
+        }
+    }
+}
+
+// where the input of a day is cached
+pub fn input_path(inputs: &Path, year: u16, day: u8) -> PathBuf {
+    inputs
+        .join(year.to_string())
+        .join(format!("day{}", day))
+        .join("input.txt")
+}
+
+// the inputs directory set in the aoc.toml at `config`, relative to it, or `default`
+// when the file doesn't set one
+pub fn inputs_dir(config: &Path, default: &Path) -> Result<PathBuf, String> {
+    let text = match std::fs::read_to_string(config) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(default.to_path_buf()),
+        Err(e) => return Err(format!("cannot read {}: {}", config.display(), e)),
+    };
+    let invalid = |e: String| format!("invalid {}: {}", config.display(), e);
+    let table = text
+        .parse::<toml::Table>()
+        .map_err(|e| invalid(e.message().to_string()))?;
+    match table.get("inputs") {
+        None => Ok(default.to_path_buf()),
+        Some(toml::Value::String(inputs)) => Ok(config
+            .parent()
+            .map_or_else(|| PathBuf::from(inputs), |dir| dir.join(inputs))),
+        Some(_) => Err(invalid("inputs must be a path".to_string())),
+    }
+}
+
+// year and day of a binary named after its crate, like aoc2021-day7
+fn year_and_day(name: &str) -> Option<(u16, u8)> {
+    let (year, day) = name.strip_prefix("aoc")?.split_once("-day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+// the input of the running day's binary: the one cached in the inputs directory of
+// aoc.toml, the same the runner uses, or input.txt in the current directory for a binary
+// that isn't named after its day
+pub fn default_input() -> Result<PathBuf, String> {
+    let exe = std::env::current_exe().map_err(|e| format!("cannot find the binary: {}", e))?;
+    let name = exe.file_stem().and_then(|name| name.to_str()).unwrap_or("");
+    match year_and_day(name) {
+        Some((year, day)) => {
+            let root = Path::new(ROOT);
+            let inputs = inputs_dir(&root.join("aoc.toml"), root)?;
+            Ok(input_path(&inputs, year, day))
+        }
+        None => Ok(PathBuf::from("input.txt")),
+    }
+}
+
+pub const USAGE: &str = "usage: [--example | INPUT]
+
+Solves both parts of the puzzle for INPUT, or for the standard input if INPUT is \"-\".
+The day's input.txt is read by default, from the inputs directory set in aoc.toml when
+there is one. With --example, the sample input from the puzzle description is used
+instead.";
+
+// input chosen by the arguments of a day's binary, `default` when none is given
+pub fn parse_args<'a, I>(args: I, example: &'a str, default: &Path) -> Result<Input<'a>, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut input = None;
+    for arg in args {
+        let next = match arg.as_str() {
+            "--example" => Input::Example(example),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            flag if flag.starts_with("--") => {
+                return Err(format!("unknown option {}\n\n{}", flag, USAGE))
+            }
+            path => Input::from_arg(path),
+        };
+        if input.replace(next).is_some() {
+            return Err(format!("only one input can be given\n\n{}", USAGE));
+        }
+    }
+    Ok(input.unwrap_or_else(|| Input::File(default.to_path_buf())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parse_args = |args, example| parse_args(args, example, Path::new("input.txt"));
+        assert_eq!(
+            parse_args(args(&[]), "1"),
+            Ok(Input::File(PathBuf::from("input.txt")))
+        );
+        assert_eq!(
+            parse_args(args(&["../other.txt"]), "1"),
+            Ok(Input::File(PathBuf::from("../other.txt")))
+        );
+        assert_eq!(parse_args(args(&["-"]), "1"), Ok(Input::Stdin));
+        assert_eq!(
+            parse_args(args(&["--example"]), "1"),
+            Ok(Input::Example("1"))
+        );
+        assert!(parse_args(args(&["--example", "input.txt"]), "1")
+            .unwrap_err()
+            .starts_with("only one input can be given"));
+        assert!(parse_args(args(&["--exemple"]), "1")
+            .unwrap_err()
+            .starts_with("unknown option --exemple"));
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert_eq!(
+            input_path(Path::new("/aoc"), 2021, 7),
+            PathBuf::from("/aoc/2021/day7/input.txt")
+        );
+    }
+
+    #[test]
+    fn test_inputs_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config = dir.join("aoc.toml");
+        let root = Path::new("/root");
+        assert_eq!(inputs_dir(&config, root), Ok(root.to_path_buf()));
+        std::fs::write(&config, "session = \"abc\"\n").unwrap();
+        assert_eq!(inputs_dir(&config, root), Ok(root.to_path_buf()));
+        std::fs::write(&config, "inputs = \"cache\"\n").unwrap();
+        assert_eq!(inputs_dir(&config, root), Ok(dir.join("cache")));
+        std::fs::write(&config, "inputs = 3\n").unwrap();
+        assert!(inputs_dir(&config, root)
+            .unwrap_err()
+            .ends_with("inputs must be a path"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_year_and_day() {
+        assert_eq!(year_and_day("aoc2021-day7"), Some((2021, 7)));
+        assert_eq!(year_and_day("aoc2015-day25"), Some((2015, 25)));
+        assert_eq!(year_and_day("aoc"), None);
+        assert_eq!(year_and_day("aoc2015-dayx"), None);
+    }
+
+    #[test]
+    fn test_decompressed() {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(b"1\n2\n").unwrap();
+        let gzipped = encoder.finish().unwrap();
+        let mut text = String::new();
+        decompressed(&gzipped[..])
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "1\n2\n");
+        text.clear();
+        decompressed(&b"\x1f plain"[..])
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "\x1f plain");
+    }
+
+    #[test]
+    fn test_read() {
+        assert_eq!(Input::Example("1\n2").read(), Ok("1\n2".to_string()));
+        let missing = Input::from_arg("does/not/exist.txt");
+        assert_eq!(missing.to_string(), "does/not/exist.txt");
+        assert!(missing
+            .read()
+            .unwrap_err()
+            .starts_with("cannot read does/not/exist.txt: "));
+    }
+}
@@ -0,0 +1,195 @@
+// Pieces shared by the solutions of every day and by the runner
+
+mod answer;
+mod error;
+mod graph;
+mod grid;
+mod image;
+mod input;
+mod output;
+mod params;
+mod point;
+mod simulation;
+mod stream;
+mod timing;
+
+pub use answer::{Answer, Unsolved};
+pub use error::{parse_lines, parse_lines_from, parse_token, ParseError};
+pub use graph::{Graph, Mode, NodeId, Path, Weight};
+pub use grid::{Grid, GridView, Neighbours};
+pub use image::{Animation, Format, Image, Palette, Rgb};
+pub use input::{default_input, input_path, inputs_dir, parse_args, Input};
+pub use output::Output;
+pub use params::{check_params, solve_configured, Configurable, Kind, Param, Params};
+pub use point::{BoundingBox, Direction, Direction8, Point};
+pub use simulation::{Cycle, Simulation};
+pub use stream::{solve_stream, solve_stream_from, Streaming};
+pub use timing::{measure, time, Measure, Timings, TrackingAllocator};
+
+// re-exported for the generators of random inputs
+pub use rand;
+pub use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// A day's puzzle: the input is parsed once and then handed to each part, whose answers
+// can be displayed, compared or recorded without going through stdout. Parts return
+// anything that converts into an Answer, or into an Output to report extra details.
+pub trait Solution {
+    type Input;
+    type Part1: Into<Output>;
+    type Part2: Into<Output>;
+
+    // sample input from the puzzle description, used with --example
+    const EXAMPLE: &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+// A puzzle that can be pictured: the frames of its input being solved, or a single one
+// for a still picture
+pub trait Visual: Solution {
+    fn frames(input: &Self::Input) -> Animation;
+}
+
+// parses the input and draws the frames of the puzzle
+pub fn animate<V: Visual>(input: &str) -> Result<Animation, ParseError> {
+    Ok(V::frames(&V::parse(input)?))
+}
+
+// A puzzle whose inputs can be made up, to try the solution on more than the example
+// and the one real input. Inputs must be valid and of a size growing with `size`, e.g.
+// its number of lines, and within the limits the puzzle relies on
+pub trait Generate: Solution {
+    fn random_input(rng: &mut StdRng, size: usize) -> String;
+}
+
+// random input for the puzzle, always the same for the same seed and size
+pub fn generate<G: Generate>(seed: u64, size: usize) -> String {
+    G::random_input(&mut StdRng::seed_from_u64(seed), size)
+}
+
+// parses the input once and returns the answer of each of the requested parts
+pub fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<Answer>, ParseError> {
+    let outputs = solve_outputs::<S>(input, parts)?;
+    Ok(outputs.into_iter().map(|o| o.answer).collect())
+}
+
+// same as solve, keeping the extra details of each part
+pub fn solve_outputs<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<Output>, ParseError> {
+    let parsed = S::parse(input)?;
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part1(&parsed).into(),
+            2 => S::part2(&parsed).into(),
+            _ => Output::from(Unsolved),
+        })
+        .collect())
+}
+
+// entry point of each day's binary: solves both parts for the input given on the command
+// line, the day's cached input by default
+pub fn main<S: Solution>() {
+    run_main(S::EXAMPLE, |input| {
+        let text = input.read()?;
+        solve::<S>(&text, &[1, 2]).map_err(|e| format!("invalid input {}: {}", input, e))
+    });
+}
+
+// same as main for the days that solve their input while reading it
+pub fn main_streaming<S: Streaming>() {
+    run_main(S::EXAMPLE, |input| {
+        solve_stream::<S>(&mut input.reader()?, &[1, 2])
+            .map_err(|e| format!("invalid input {}: {}", input, e))
+    });
+}
+
+fn run_main<F>(example: &str, solve: F)
+where
+    F: FnOnce(&Input) -> Result<Vec<Answer>, String>,
+{
+    let default = default_input().unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    });
+    let input = match parse_args(std::env::args().skip(1), example, &default) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    let answers = match solve(&input) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    };
+    for (i, answer) in answers.into_iter().enumerate() {
+        if answer.is_multiline() {
+            print!("Part {}:\n{}", i + 1, answer);
+        } else {
+            println!("Part {}: {}", i + 1, answer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Part1 = i32;
+        type Part2 = Unsolved;
+
+        const EXAMPLE: &'static str = "1\n2\n3\n";
+
+        fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+            parse_lines(input, |l| parse_token(l, l, "not a number"))
+        }
+
+        fn part1(input: &Vec<i32>) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part2(_input: &Vec<i32>) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    impl Generate for Sum {
+        fn random_input(rng: &mut StdRng, size: usize) -> String {
+            use rand::Rng;
+            (0..size)
+                .map(|_| format!("{}\n", rng.gen_range(-100..100)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Sum>(7, 20);
+        assert_eq!(input, generate::<Sum>(7, 20));
+        assert_ne!(input, generate::<Sum>(8, 20));
+        assert_eq!(Sum::parse(&input).unwrap().len(), 20);
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve::<Sum>(Sum::EXAMPLE, &[1, 2]),
+            Ok(vec![Answer::Number(6), Answer::Unsolved])
+        );
+        assert_eq!(solve::<Sum>("1\n2\n3\n", &[2]), Ok(vec![Answer::Unsolved]));
+        assert_eq!(
+            solve::<Sum>("1\ntwo\n", &[1]).unwrap_err().to_string(),
+            "line 2, column 1: not a number \"two\""
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// The answer to a part together with optional day-specific details about how it was
+// found (e.g. the route taken), for the runner's JSON output
+
+use crate::Answer;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Output {
+    pub answer: Answer,
+    // a JSON object, None for the parts that have nothing to add to their answer
+    pub extra: Option<Value>,
+}
+
+impl Output {
+    pub fn with_extra<A: Into<Answer>, E: Serialize>(answer: A, extra: &E) -> Self {
+        Output {
+            answer: answer.into(),
+            // serializing plain data structures doesn't fail
+            extra: Some(serde_json::to_value(extra).expect("Extra fields must serialize")),
+        }
+    }
+}
+
+impl<T: Into<Answer>> From<T> for Output {
+    fn from(answer: T) -> Self {
+        Output {
+            answer: answer.into(),
+            extra: None,
+        }
+    }
+}
+
+// numbers as JSON numbers, text as strings and unsolved parts as null
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_i64(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Route {
+        nodes: Vec<&'static str>,
+    }
+
+    #[test]
+    fn test_from() {
+        assert_eq!(
+            Output::from(42u8),
+            Output {
+                answer: Answer::Number(42),
+                extra: None
+            }
+        );
+        let output = Output::with_extra(
+            605,
+            &Route {
+                nodes: vec!["London", "Dublin", "Belfast"],
+            },
+        );
+        assert_eq!(output.answer, Answer::Number(605));
+        assert_eq!(
+            output.extra.unwrap().to_string(),
+            r#"{"nodes":["London","Dublin","Belfast"]}"#
+        );
+    }
+
+    #[test]
+    fn test_serialize_answer() {
+        let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+        assert_eq!(json(Answer::Number(-3)), "-3");
+        assert_eq!(
+            json(Answer::Text("# #\n###".to_string())),
+            r##""# #\n###""##
+        );
+        assert_eq!(json(Answer::Unsolved), "null");
+    }
+}
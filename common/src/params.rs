@@ -0,0 +1,291 @@
+// Parameters of a puzzle that are fixed by its description but worth changing, like the
+// number of steps of a part, set from the runner's configuration
+
+use crate::{solve_outputs, Output, ParseError, Solution, Unsolved};
+use std::collections::BTreeMap;
+use std::fmt;
+
+// What the value of a parameter must be
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    // a non-negative integer
+    Count,
+    Text,
+    Flag,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Kind::Count => write!(f, "a non-negative integer"),
+            Kind::Text => write!(f, "a string"),
+            Kind::Flag => write!(f, "true or false"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Param {
+    Count(u64),
+    Text(String),
+    Flag(bool),
+}
+
+impl Param {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Param::Count(_) => Kind::Count,
+            Param::Text(_) => Kind::Text,
+            Param::Flag(_) => Kind::Flag,
+        }
+    }
+}
+
+// The parameters set for a puzzle, by name
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, Param>);
+
+impl Params {
+    pub fn set(&mut self, name: &str, value: Param) {
+        self.0.insert(name.to_string(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // the value of a parameter of kind Count, if set
+    pub fn count(&self, name: &str) -> Option<u64> {
+        match self.0.get(name) {
+            Some(Param::Count(n)) => Some(*n),
+            _ => None,
+        }
+    }
+
+    // the value of a parameter of kind Text, if set
+    pub fn text(&self, name: &str) -> Option<&str> {
+        match self.0.get(name) {
+            Some(Param::Text(text)) => Some(text),
+            _ => None,
+        }
+    }
+
+    // the value of a parameter of kind Flag, if set
+    pub fn flag(&self, name: &str) -> Option<bool> {
+        match self.0.get(name) {
+            Some(Param::Flag(flag)) => Some(*flag),
+            _ => None,
+        }
+    }
+
+    // every parameter set must be one of `expected` and of its kind
+    pub fn check(&self, expected: &[(&str, Kind)]) -> Result<(), String> {
+        for (name, value) in &self.0 {
+            match expected.iter().find(|(n, _)| n == name) {
+                Some((_, kind)) if *kind == value.kind() => {}
+                Some((_, kind)) => return Err(format!("{} must be {}", name, kind)),
+                None if expected.is_empty() => {
+                    return Err(format!("unknown parameter {}, there are none", name))
+                }
+                None => {
+                    let names: Vec<&str> = expected.iter().map(|(n, _)| *n).collect();
+                    return Err(format!(
+                        "unknown parameter {}, expected one of {}",
+                        name,
+                        names.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// A puzzle whose parts depend on parameters with default values. Its Solution parts are
+// expected to be the parts with the default settings.
+pub trait Configurable: Solution {
+    type Settings: Default;
+
+    // names of the parameters that can be set, with the kind of their values
+    const PARAMS: &'static [(&'static str, Kind)];
+
+    // settings for the parameters, already checked against PARAMS; those not set keep
+    // their default values. Values the parts can't work with, like a number of steps
+    // overflowing the answer, are errors of the configuration.
+    fn settings(params: &Params) -> Result<Self::Settings, String>;
+    fn part1_with(input: &Self::Input, settings: &Self::Settings) -> Self::Part1;
+    fn part2_with(input: &Self::Input, settings: &Self::Settings) -> Self::Part2;
+
+    // for settings making the input invalid, like a stricter parsing
+    fn check(_input: &Self::Input, _settings: &Self::Settings) -> Result<(), ParseError> {
+        Ok(())
+    }
+}
+
+// the parameters must be those of the puzzle, with values it accepts
+pub fn check_params<C: Configurable>(params: &Params) -> Result<(), String> {
+    params.check(C::PARAMS)?;
+    C::settings(params).map(|_| ())
+}
+
+// same as solve_outputs, with the parts run with the given parameters, which must have
+// been checked beforehand
+pub fn solve_configured<C: Configurable>(
+    input: &str,
+    parts: &[u8],
+    params: &Params,
+) -> Result<Vec<Output>, ParseError> {
+    if params.is_empty() {
+        return solve_outputs::<C>(input, parts);
+    }
+    let settings = C::settings(params).expect("Parameters must be checked beforehand");
+    let parsed = C::parse(input)?;
+    C::check(&parsed, &settings)?;
+    Ok(parts
+        .iter()
+        .map(|part| match part {
+            1 => C::part1_with(&parsed, &settings).into(),
+            2 => C::part2_with(&parsed, &settings).into(),
+            _ => Output::from(Unsolved),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_lines, parse_token, Answer};
+
+    // the numbers times a factor, 1 by default, with a unit and only positive numbers
+    // when checked
+    struct Scaled;
+
+    type Settings = (u64, String, bool);
+
+    impl Solution for Scaled {
+        type Input = Vec<u64>;
+        type Part1 = u64;
+        type Part2 = String;
+
+        const EXAMPLE: &'static str = "1\n2";
+
+        fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+            parse_lines(input, |l| parse_token(l, l, "not a number"))
+        }
+
+        fn part1(input: &Vec<u64>) -> u64 {
+            Scaled::part1_with(input, &(1, String::new(), false))
+        }
+
+        fn part2(input: &Vec<u64>) -> String {
+            Scaled::part2_with(input, &(1, String::new(), false))
+        }
+    }
+
+    impl Configurable for Scaled {
+        type Settings = Settings;
+
+        const PARAMS: &'static [(&'static str, Kind)] = &[
+            ("factor", Kind::Count),
+            ("unit", Kind::Text),
+            ("positive", Kind::Flag),
+        ];
+
+        fn settings(params: &Params) -> Result<Settings, String> {
+            let unit = params.text("unit").unwrap_or_default();
+            if unit.contains(char::is_numeric) {
+                return Err("unit must not have digits".to_string());
+            }
+            Ok((
+                params.count("factor").unwrap_or(1),
+                unit.to_string(),
+                params.flag("positive").unwrap_or(false),
+            ))
+        }
+
+        fn check(input: &Vec<u64>, (_, _, positive): &Settings) -> Result<(), ParseError> {
+            if *positive && input.contains(&0) {
+                return Err(ParseError::input("zero among the numbers"));
+            }
+            Ok(())
+        }
+
+        fn part1_with(input: &Vec<u64>, (factor, _, _): &Settings) -> u64 {
+            input.iter().sum::<u64>() * factor
+        }
+
+        fn part2_with(input: &Vec<u64>, (factor, unit, _): &Settings) -> String {
+            format!("{}{}", input.len() as u64 * factor, unit)
+        }
+    }
+
+    fn params(values: &[(&str, Param)]) -> Params {
+        let mut params = Params::default();
+        for (name, value) in values {
+            params.set(name, value.clone());
+        }
+        params
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = Scaled::PARAMS;
+        assert_eq!(params(&[]).check(expected), Ok(()));
+        assert_eq!(
+            params(&[("factor", Param::Count(2))]).check(expected),
+            Ok(())
+        );
+        assert_eq!(
+            params(&[("factor", Param::Text("2".to_string()))]).check(expected),
+            Err("factor must be a non-negative integer".to_string())
+        );
+        assert_eq!(
+            params(&[("facto", Param::Count(2))]).check(expected),
+            Err("unknown parameter facto, expected one of factor, unit, positive".to_string())
+        );
+        assert_eq!(
+            params(&[("facto", Param::Count(2))]).check(&[]),
+            Err("unknown parameter facto, there are none".to_string())
+        );
+        assert_eq!(
+            check_params::<Scaled>(&params(&[("unit", Param::Text("m".to_string()))])),
+            Ok(())
+        );
+        assert_eq!(
+            check_params::<Scaled>(&params(&[("unit", Param::Text("m2".to_string()))])),
+            Err("unit must not have digits".to_string())
+        );
+        assert_eq!(
+            check_params::<Scaled>(&params(&[("unit", Param::Flag(true))])),
+            Err("unit must be a string".to_string())
+        );
+    }
+
+    #[test]
+    fn test_solve_configured() {
+        let answers = |params: &Params| -> Vec<Answer> {
+            solve_configured::<Scaled>("1\n2", &[1, 2], params)
+                .unwrap()
+                .into_iter()
+                .map(|o| o.answer)
+                .collect()
+        };
+        assert_eq!(
+            answers(&params(&[])),
+            vec![Answer::Number(3), Answer::Text("2".to_string())]
+        );
+        let scaled = params(&[
+            ("factor", Param::Count(3)),
+            ("unit", Param::Text("m".into())),
+        ]);
+        assert_eq!(
+            answers(&scaled),
+            vec![Answer::Number(9), Answer::Text("6m".to_string())]
+        );
+        let checked = params(&[("positive", Param::Flag(true))]);
+        assert_eq!(
+            solve_configured::<Scaled>("1\n0", &[1], &checked).unwrap_err(),
+            ParseError::input("zero among the numbers")
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use crate::error::{parse_token, ParseError};
+use serde::Serialize;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+// Position or vector on a 2D plane, with y growing downwards like the rows of a Grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    // number of orthogonal steps between the two points
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // number of steps between the two points when moving diagonally is allowed
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    // vector with each coordinate reduced to -1, 0 or 1, the step from a point towards
+    // another along a horizontal, vertical or diagonal line
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    // points above, right, below and left of this one
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    // points around this one, diagonals included, clockwise from the one above
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Direction8::ALL.into_iter().map(move |d| self + d.offset())
+    }
+
+    // (x, y) position of the point in a Grid, None when a coordinate is negative
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    // parses `token`, a slice of `text` in the form "x,y", errors are reported relative
+    // to the whole text
+    pub fn parse(text: &str, token: &str) -> Result<Point, ParseError> {
+        token
+            .parse()
+            .map_err(|e: ParseError| e.shifted(text, token))
+    }
+}
+
+impl From<(i64, i64)> for Point {
+    fn from((x, y): (i64, i64)) -> Self {
+        Point { x, y }
+    }
+}
+
+// "x,y", spaces around the coordinates are ignored
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(s, s.trim(), "Invalid point, expected x,y"))?;
+        Ok(Point {
+            x: parse_token(s, x.trim(), "Invalid coordinate, expected an integer")?,
+            y: parse_token(s, y.trim(), "Invalid coordinate, expected an integer")?,
+        })
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+// The 4 orthogonal directions, clockwise from up
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    // vector of a single step in the direction
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    // direction of an arrow: ^, >, v or <
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+// The 8 directions including diagonals, clockwise from up
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction8::Up => Point::new(0, -1),
+            Direction8::UpRight => Point::new(1, -1),
+            Direction8::Right => Point::new(1, 0),
+            Direction8::DownRight => Point::new(1, 1),
+            Direction8::Down => Point::new(0, 1),
+            Direction8::DownLeft => Point::new(-1, 1),
+            Direction8::Left => Point::new(-1, 0),
+            Direction8::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+// Smallest rectangle, borders included, containing a set of points
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    // rectangle between two opposite corners, in any order
+    pub fn new(a: Point, b: Point) -> Self {
+        BoundingBox {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    // None when there are no points
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = BoundingBox::new(first, first);
+        points.for_each(|p| bounds.include(p));
+        Some(bounds)
+    }
+
+    // grows the rectangle to contain the point
+    pub fn include(&mut self, p: Point) {
+        *self = BoundingBox::new(
+            Point::new(self.min.x.min(p.x), self.min.y.min(p.y)),
+            Point::new(self.max.x.max(p.x), self.max.y.max(p.y)),
+        );
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    // every point of the rectangle, row after row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let p = Point::new(3, -4);
+        assert_eq!(p + Point::new(1, 1), Point::new(4, -3));
+        assert_eq!(p - Point::new(5, 5), Point::new(-2, -9));
+        assert_eq!(p * 2, Point::new(6, -8));
+        assert_eq!(-p, Point::new(-3, 4));
+        assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+        let mut q = Point::ORIGIN;
+        q += Direction::Left.offset();
+        q -= Direction::Up.offset();
+        assert_eq!(q, Point::new(-1, 1));
+        assert_eq!(p.to_position(), None);
+        assert_eq!(Point::new(3, 4).to_position(), Some((3, 4)));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, 2), Point::new(-3, 5));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::from_arrow('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_arrow('x'), None);
+        assert_eq!(Direction8::from(Direction::Down).offset(), Point::new(0, 1));
+        assert_eq!(
+            Point::ORIGIN.neighbours4().collect::<Vec<_>>(),
+            vec![
+                Point::new(0, -1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(-1, 0)
+            ]
+        );
+        assert_eq!(Point::new(5, 5).neighbours8().count(), 8);
+        assert!(Point::ORIGIN
+            .neighbours8()
+            .all(|p| p.chebyshev(Point::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("12,-34".parse(), Ok(Point::new(12, -34)));
+        assert_eq!(" 5, 6 ".parse(), Ok(Point::new(5, 6)));
+        let line = "0,9 -> 5,y";
+        assert_eq!(
+            Point::parse(line, &line[7..]).unwrap_err().to_string(),
+            "column 10: Invalid coordinate, expected an integer \"y\""
+        );
+        assert_eq!(
+            "12;34".parse::<Point>().unwrap_err().to_string(),
+            "column 1: Invalid point, expected x,y \"12;34\""
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point::new(2, 3), Point::new(-1, 5), Point::new(0, 4)];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(
+            bounds,
+            BoundingBox::new(Point::new(2, 5), Point::new(-1, 3))
+        );
+        assert_eq!((bounds.width(), bounds.height()), (4, 3));
+        assert!(bounds.contains(Point::new(-1, 3)) && bounds.contains(Point::new(2, 5)));
+        assert!(!bounds.contains(Point::new(3, 4)));
+        assert_eq!(bounds.points().count(), 12);
+        assert_eq!(bounds.points().nth(4), Some(Point::new(-1, 4)));
+        assert_eq!(BoundingBox::from_points([]), None);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// States repeating every `period` steps from step `start` on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+// A system evolving step by step, like a school of fish or a cavern of octopi. Only
+// step needs to be implemented, the rest drives it
+pub trait Simulation {
+    // advances the state by one step
+    fn step(&mut self);
+
+    fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    // steps until the state after a step satisfies the predicate, returns the number of
+    // steps taken
+    fn run_until<F>(&mut self, mut done: F) -> usize
+    where
+        F: FnMut(&Self) -> bool,
+        Self: Sized,
+    {
+        let mut steps = 0;
+        loop {
+            self.step();
+            steps += 1;
+            if done(self) {
+                return steps;
+            }
+        }
+    }
+
+    // runs the given number of steps, returns the initial state followed by the state
+    // after each step
+    fn history(&mut self, steps: usize) -> Vec<Self>
+    where
+        Self: Clone,
+    {
+        let mut states = Vec::with_capacity(steps + 1);
+        states.push(self.clone());
+        for _ in 0..steps {
+            self.step();
+            states.push(self.clone());
+        }
+        states
+    }
+
+    // steps until a state comes back within the given number of steps, keeping every
+    // state seen so that states with the same hash are told apart. The simulation is left
+    // at the start of the second round of the cycle
+    fn find_cycle(&mut self, max_steps: usize) -> Option<Cycle>
+    where
+        Self: Clone + Eq + Hash,
+    {
+        let mut seen = HashMap::from([(self.clone(), 0)]);
+        for steps in 1..=max_steps {
+            self.step();
+            if let Some(start) = seen.insert(self.clone(), steps) {
+                return Some(Cycle {
+                    start,
+                    period: steps - start,
+                });
+            }
+        }
+        None
+    }
+
+    // same state as run(steps), skipping the rounds of a cycle once one is found, for
+    // step counts too large to simulate one by one
+    fn fast_forward(&mut self, steps: usize)
+    where
+        Self: Clone + Eq + Hash,
+    {
+        // without a cycle, the steps were all taken looking for one
+        if let Some(Cycle { start, period }) = self.find_cycle(steps) {
+            self.run((steps - start - period) % period);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // x -> x * x + 1 modulo 10, starting from 2: 2, 5, 6, 7, 0, 1, 2, ...
+    #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+    struct Square(u32);
+
+    // a counter whose states all have the same hash
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    struct Colliding(u32);
+
+    impl Hash for Colliding {
+        fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+    }
+
+    impl Simulation for Colliding {
+        fn step(&mut self) {
+            self.0 = (self.0 + 1) % 5;
+        }
+    }
+
+    impl Simulation for Square {
+        fn step(&mut self) {
+            self.0 = (self.0 * self.0 + 1) % 10;
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let mut square = Square(2);
+        square.run(3);
+        assert_eq!(square, Square(7));
+        assert_eq!(square.run_until(|s| s.0 == 2), 3);
+        assert_eq!(
+            Square(2).history(4),
+            [Square(2), Square(5), Square(6), Square(7), Square(0)]
+        );
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let mut square = Square(3);
+        // 3, 0, 1, 2, 5, 6, 7, 0
+        assert_eq!(
+            square.find_cycle(100),
+            Some(Cycle {
+                start: 1,
+                period: 6
+            })
+        );
+        assert_eq!(square, Square(0));
+        assert_eq!(Square(3).find_cycle(6), None);
+        // states are compared, not only their hashes
+        assert_eq!(
+            Colliding(0).find_cycle(100),
+            Some(Cycle {
+                start: 0,
+                period: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_fast_forward() {
+        for steps in [0, 1, 6, 7, 8, 13, 100] {
+            let mut expected = Square(3);
+            expected.run(steps);
+            let mut square = Square(3);
+            square.fast_forward(steps);
+            assert_eq!(square, expected, "after {} steps", steps);
+        }
+        let mut square = Square(3);
+        // the state at step n > 0 is the ((n - 1) % 6)th of 0, 1, 2, 5, 6, 7
+        square.fast_forward(1_000_000_000_000);
+        assert_eq!(square, Square(5));
+    }
+}
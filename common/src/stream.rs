@@ -0,0 +1,242 @@
+// Solving while reading: puzzles whose answers only need one line of the input at a
+// time can be fed any BufRead, like stdin or a decompressed file, and solve inputs of
+// any size without holding them whole. Most keep a constant amount of memory, a few
+// (2021 day 10) keep something for some of the lines.
+
+use crate::{Answer, Output, ParseError, Solution, Unsolved};
+use std::io::BufRead;
+
+// A puzzle worked out from the lines of its input in order, keeping only what the
+// answers need of the lines read so far
+pub trait Streaming: Solution {
+    type State: Default;
+
+    // false for puzzles that can be fed any piece of a line, so that an input made of a
+    // single long line isn't read whole either
+    const WHOLE_LINES: bool = true;
+
+    // a line without its line ending, or a piece of one
+    fn feed(state: &mut Self::State, line: &str) -> Result<(), ParseError>;
+
+    // the answer of each part once the whole input is read, only worked out for the
+    // parts asked for
+    fn answer1(state: &Self::State) -> Self::Part1;
+    fn answer2(state: &Self::State) -> Self::Part2;
+}
+
+// reads the input to its end and returns the answer of each of the requested parts, the
+// same as `solve` on the whole input
+pub fn solve_stream<S: Streaming>(
+    reader: &mut dyn BufRead,
+    parts: &[u8],
+) -> Result<Vec<Answer>, ParseError> {
+    solve_stream_from::<S>(reader, S::State::default(), parts)
+}
+
+// same as solve_stream, starting from the given state, e.g. one holding settings other
+// than the puzzle's
+pub fn solve_stream_from<S: Streaming>(
+    reader: &mut dyn BufRead,
+    mut state: S::State,
+    parts: &[u8],
+) -> Result<Vec<Answer>, ParseError> {
+    if S::WHOLE_LINES {
+        feed_lines::<S>(reader, &mut state)?;
+    } else {
+        feed_pieces::<S>(reader, &mut state)?;
+    }
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let output: Output = match part {
+                1 => S::answer1(&state).into(),
+                2 => S::answer2(&state).into(),
+                _ => Unsolved.into(),
+            };
+            output.answer
+        })
+        .collect())
+}
+
+fn read_error(e: std::io::Error) -> ParseError {
+    ParseError::input(format!("cannot read the input: {}", e))
+}
+
+// one line at a time, reusing the same buffer, with the line numbers added to errors
+fn feed_lines<S: Streaming>(
+    reader: &mut dyn BufRead,
+    state: &mut S::State,
+) -> Result<(), ParseError> {
+    let mut line = String::new();
+    for number in 1.. {
+        line.clear();
+        if reader
+            .read_line(&mut line)
+            .map_err(|e| read_error(e).at_line(number))?
+            == 0
+        {
+            break;
+        }
+        let text = line.strip_suffix('\n').unwrap_or(&line);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        S::feed(state, text).map_err(|e| e.at_line(number))?;
+    }
+    Ok(())
+}
+
+// whatever the reader has buffered, less the bytes of a character cut in two, which are
+// kept for the next piece
+fn feed_pieces<S: Streaming>(
+    reader: &mut dyn BufRead,
+    state: &mut S::State,
+) -> Result<(), ParseError> {
+    let mut pending = Vec::new();
+    loop {
+        let buffer = reader.fill_buf().map_err(read_error)?;
+        if buffer.is_empty() {
+            break;
+        }
+        let read = buffer.len();
+        pending.extend_from_slice(buffer);
+        reader.consume(read);
+        let valid = match std::str::from_utf8(&pending) {
+            Ok(text) => text.len(),
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => return Err(ParseError::input("the input is not valid UTF-8")),
+        };
+        // valid up to `valid` as checked above
+        S::feed(state, std::str::from_utf8(&pending[..valid]).unwrap())?;
+        pending.drain(..valid);
+    }
+    if !pending.is_empty() {
+        return Err(ParseError::input("the input is not valid UTF-8"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_token;
+    use std::io::{BufReader, Cursor, Read};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Part1 = i32;
+        type Part2 = usize;
+
+        const EXAMPLE: &'static str = "1\n2";
+
+        fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+            crate::parse_lines(input, |l| parse_token(l, l, "not a number"))
+        }
+
+        fn part1(input: &Vec<i32>) -> i32 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<i32>) -> usize {
+            input.len()
+        }
+    }
+
+    impl Streaming for Sum {
+        type State = (i32, usize);
+
+        fn feed(state: &mut (i32, usize), line: &str) -> Result<(), ParseError> {
+            state.0 += parse_token::<i32>(line, line, "not a number")?;
+            state.1 += 1;
+            Ok(())
+        }
+
+        fn answer1(state: &(i32, usize)) -> i32 {
+            state.0
+        }
+
+        fn answer2(state: &(i32, usize)) -> usize {
+            state.1
+        }
+    }
+
+    // counts the "é" of an input of one line, fed in pieces
+    struct Accents;
+
+    impl Solution for Accents {
+        type Input = String;
+        type Part1 = usize;
+        type Part2 = Unsolved;
+
+        const EXAMPLE: &'static str = "";
+
+        fn parse(input: &str) -> Result<String, ParseError> {
+            Ok(input.to_string())
+        }
+
+        fn part1(input: &String) -> usize {
+            input.matches('é').count()
+        }
+
+        fn part2(_input: &String) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    impl Streaming for Accents {
+        type State = usize;
+
+        const WHOLE_LINES: bool = false;
+
+        fn feed(count: &mut usize, piece: &str) -> Result<(), ParseError> {
+            *count += piece.matches('é').count();
+            Ok(())
+        }
+
+        fn answer1(count: &usize) -> usize {
+            *count
+        }
+
+        fn answer2(_count: &usize) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        assert_eq!(
+            solve_stream::<Sum>(&mut Cursor::new("1\r\n2\n3\n"), &[2, 1]),
+            Ok(vec![Answer::Number(3), Answer::Number(6)])
+        );
+        assert_eq!(
+            solve_stream::<Sum>(&mut Cursor::new("1\r\n2\n\n3\n"), &[1])
+                .unwrap_err()
+                .to_string(),
+            "line 3, column 1: not a number"
+        );
+        assert_eq!(
+            solve_stream::<Sum>(&mut Cursor::new(""), &[1, 2, 3]),
+            Ok(vec![Answer::Number(0), Answer::Number(0), Answer::Unsolved])
+        );
+    }
+
+    #[test]
+    fn test_solve_stream_in_pieces() {
+        // a buffer of 3 bytes cuts every other "é" in two
+        let text = "aéébéé".repeat(100);
+        let mut reader = BufReader::with_capacity(3, Cursor::new(text));
+        assert_eq!(
+            solve_stream::<Accents>(&mut reader, &[1]),
+            Ok(vec![Answer::Number(400)])
+        );
+        let invalid = Cursor::new(b"a\xc3".to_vec()).chain(Cursor::new(b"b".to_vec()));
+        assert_eq!(
+            solve_stream::<Accents>(&mut BufReader::new(invalid), &[1])
+                .unwrap_err()
+                .to_string(),
+            "the input is not valid UTF-8"
+        );
+        let cut = Cursor::new(b"a\xc3".to_vec());
+        assert!(solve_stream::<Accents>(&mut BufReader::new(cut), &[1]).is_err());
+    }
+}
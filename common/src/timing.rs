@@ -0,0 +1,147 @@
+// Time and memory taken by each phase of a day's solution
+//
+// Peak allocation is only known in binaries that install the tracking allocator:
+//
+//     #[global_allocator]
+//     static ALLOCATOR: aoc_common::TrackingAllocator = aoc_common::TrackingAllocator;
+//
+// otherwise it is always reported as 0.
+
+use crate::{ParseError, Solution};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+// System allocator keeping track of the bytes allocated and of their peak
+pub struct TrackingAllocator;
+
+fn allocated(size: usize) {
+    let now = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(now, Ordering::Relaxed);
+}
+
+fn freed(size: usize) {
+    ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+// Duration of a phase and the most memory it had allocated at once, not counting what
+// was already allocated when it started
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Measure {
+    pub time: Duration,
+    pub peak_bytes: usize,
+}
+
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Measure) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    PEAK.store(before, Ordering::Relaxed);
+    let start = Instant::now();
+    let result = f();
+    let time = start.elapsed();
+    let peak_bytes = PEAK.load(Ordering::Relaxed).saturating_sub(before);
+    (result, Measure { time, peak_bytes })
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timings {
+    pub parse: Measure,
+    pub part1: Measure,
+    pub part2: Measure,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse.time + self.part1.time + self.part2.time
+    }
+
+    // upper bound of the day's peak: the parsed input, which is at most the peak of the
+    // parsing, stays allocated while the parts run
+    pub fn peak_bytes(&self) -> usize {
+        self.parse.peak_bytes + self.part1.peak_bytes.max(self.part2.peak_bytes)
+    }
+}
+
+// parses the input and solves both parts, measuring each of them. The input and the
+// results go through black_box so that the optimiser can't skip parts whose answers
+// are thrown away.
+pub fn time<S: Solution>(input: &str) -> Result<Timings, ParseError> {
+    let (parsed, parse) = measure(|| black_box(S::parse(black_box(input))));
+    let parsed = parsed?;
+    let (_, part1) = measure(|| black_box(S::part1(black_box(&parsed))));
+    let (_, part2) = measure(|| black_box(S::part2(black_box(&parsed))));
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (value, measure) = measure(|| 6 * 7);
+        assert_eq!(value, 42);
+        // the test binary doesn't use the tracking allocator
+        assert_eq!(measure.peak_bytes, 0);
+    }
+
+    #[test]
+    fn test_peak_bytes() {
+        let ms = Duration::from_millis;
+        let timings = Timings {
+            parse: Measure {
+                time: ms(1),
+                peak_bytes: 100,
+            },
+            part1: Measure {
+                time: ms(2),
+                peak_bytes: 50,
+            },
+            part2: Measure {
+                time: ms(3),
+                peak_bytes: 20,
+            },
+        };
+        assert_eq!(timings.total(), ms(6));
+        assert_eq!(timings.peak_bytes(), 150);
+    }
+}